@@ -1,3 +1,9 @@
+use crate::{
+    constants::*,
+    math::{scale_component, wrap_coord_2d},
+    Axis, BoundsError,
+};
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A 2D bounding box. Essentially a rectangle.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -12,10 +18,65 @@ impl Bounds2D {
     /// Create a new [Bounds2D] from an inclusive min and exclusive max.
     /// If you don't know the min/max bounds, you can use `from_bounds`
     /// to create a [Bounds2D] from arbitrary coordinates.
-    pub fn new(min: (i32, i32), max: (i32, i32)) -> Self {
+    pub const fn new(min: (i32, i32), max: (i32, i32)) -> Self {
         Self { min: min, max: max }
     }
 
+    /// Create a new [Bounds2D] from an inclusive min and exclusive max.
+    #[cfg(feature = "glam")]
+    pub fn from_min_max(min: glam::IVec2, max: glam::IVec2) -> Self {
+        Self::new(min.into(), max.into())
+    }
+
+    /// Create a new [Bounds2D] from an inclusive min and exclusive max,
+    /// returning an error if `min` is greater than `max` on either axis.
+    pub const fn try_new(min: (i32, i32), max: (i32, i32)) -> Result<Self, BoundsError> {
+        if min.0 > max.0 {
+            return Err(BoundsError::InvertedX);
+        }
+        if min.1 > max.1 {
+            return Err(BoundsError::InvertedY);
+        }
+        Ok(Self { min, max })
+    }
+
+    /// Create a new [Bounds2D] of `size` centered at `center`.
+    ///
+    /// For even sizes, `center` is the cell just past the middle (see [Bounds2D::center]),
+    /// so `Bounds2D::from_center_size(bounds.center(), (bounds.width(), bounds.height()))`
+    /// is always `bounds`.
+    ///
+    /// Panics if either bound would overflow.
+    pub fn from_center_size(center: (i32, i32), size: (u32, u32)) -> Self {
+        let axis = |center: i32, size: u32, min_msg: &str, max_msg: &str| {
+            let min = center as i64 - (size / 2) as i64;
+            let max = min + size as i64;
+            if min < i32::MIN as i64 {
+                panic!("{min_msg}");
+            }
+            if max > i32::MAX as i64 {
+                panic!("{max_msg}");
+            }
+            (min as i32, max as i32)
+        };
+        let (x_min, x_max) = axis(
+            center.0,
+            size.0,
+            X_MIN_EXCEEDS_MINIMUM,
+            X_MAX_EXCEEDS_MAXIMUM,
+        );
+        let (y_min, y_max) = axis(
+            center.1,
+            size.1,
+            Y_MIN_EXCEEDS_MINIMUM,
+            Y_MAX_EXCEEDS_MAXIMUM,
+        );
+        Self {
+            min: (x_min, y_min),
+            max: (x_max, y_max),
+        }
+    }
+
     /// Create a new [Bounds2D] by resolving the inclusive min and exclusive max from two coordinates.
     pub fn from_bounds(a: (i32, i32), b: (i32, i32)) -> Self {
         let (ax, ay) = a;
@@ -25,14 +86,20 @@ impl Bounds2D {
         Self { min, max }
     }
 
-    /// The size along the X axis.
+    /// The size along the X axis. This is `0` if the bounds are inverted.
     pub fn width(&self) -> u32 {
-        (self.max.0 as i64 - self.min.0 as i64) as u32
+        (self.max.0 as i64 - self.min.0 as i64).max(0) as u32
     }
 
-    /// The size along the Y axis.
+    /// The size along the Y axis. This is `0` if the bounds are inverted.
     pub fn height(&self) -> u32 {
-        (self.max.1 as i64 - self.min.1 as i64) as u32
+        (self.max.1 as i64 - self.min.1 as i64).max(0) as u32
+    }
+
+    /// Returns true if the [Bounds2D] contains no cells, which is the case when `min`
+    /// is greater than or equal to `max` on either axis.
+    pub fn is_empty(&self) -> bool {
+        self.min.0 >= self.max.0 || self.min.1 >= self.max.1
     }
 
     /// `width` * `height`.
@@ -40,6 +107,17 @@ impl Bounds2D {
         self.width() as i64 * self.height() as i64
     }
 
+    /// The center cell of the [Bounds2D].
+    ///
+    /// For even sizes there is no single middle cell, so this rounds toward the maximum
+    /// (a width of `4` starting at `0` has its center at `2`). For empty bounds, this is `min`.
+    pub fn center(&self) -> (i32, i32) {
+        (
+            (self.min.0 as i64 + (self.width() / 2) as i64) as i32,
+            (self.min.1 as i64 + (self.height() / 2) as i64) as i32,
+        )
+    }
+
     /// The minimum bound on the X axis.
     pub fn x_min(&self) -> i32 {
         self.min.0
@@ -69,56 +147,1219 @@ impl Bounds2D {
         ax_min < bx_max && bx_min < ax_max && ay_min < by_max && by_min < ay_max
     }
 
+    /// Get the overlapping region of two [Bounds2D], or `None` if they do not intersect.
+    pub fn intersection(self, other: Bounds2D) -> Option<Bounds2D> {
+        if !self.intersects(other) {
+            return None;
+        }
+        Some(Bounds2D {
+            min: (self.min.0.max(other.min.0), self.min.1.max(other.min.1)),
+            max: (self.max.0.min(other.max.0), self.max.1.min(other.max.1)),
+        })
+    }
+
+    /// Get the disjoint rectangles that cover the area of `self` that is not in `other`.
+    ///
+    /// At most four rectangles are yielded: the full-height left and right strips,
+    /// and the bottom and top strips between them. Empty rectangles are skipped.
+    pub fn difference(self, other: Bounds2D) -> impl Iterator<Item = Bounds2D> {
+        let regions = match self.intersection(other) {
+            None => [Some(self), None, None, None],
+            Some(inner) => [
+                // left
+                Some(Bounds2D::new(self.min, (inner.min.0, self.max.1))),
+                // right
+                Some(Bounds2D::new((inner.max.0, self.min.1), self.max)),
+                // bottom
+                Some(Bounds2D::new(
+                    (inner.min.0, self.min.1),
+                    (inner.max.0, inner.min.1),
+                )),
+                // top
+                Some(Bounds2D::new(
+                    (inner.min.0, inner.max.1),
+                    (inner.max.0, self.max.1),
+                )),
+            ],
+        };
+        regions
+            .into_iter()
+            .flatten()
+            .filter(|region| region.area() > 0)
+    }
+
+    /// Split the [Bounds2D] into the halves to the left and right of `x`.
+    ///
+    /// The left half is `min.0..x` and the right half is `x..max.0`, so together they
+    /// tile `self` exactly. `x` is clamped to `min.0..=max.0`, so splitting outside of
+    /// the bounds results in one of the halves being empty.
+    pub fn split_x(self, x: i32) -> (Bounds2D, Bounds2D) {
+        let x = x.clamp(self.min.0, self.max.0.max(self.min.0));
+        (
+            Bounds2D::new(self.min, (x, self.max.1)),
+            Bounds2D::new((x, self.min.1), self.max),
+        )
+    }
+
+    /// Split the [Bounds2D] into the halves below and above `y`.
+    ///
+    /// The lower half is `min.1..y` and the upper half is `y..max.1`, so together they
+    /// tile `self` exactly. `y` is clamped to `min.1..=max.1`, so splitting outside of
+    /// the bounds results in one of the halves being empty.
+    pub fn split_y(self, y: i32) -> (Bounds2D, Bounds2D) {
+        let y = y.clamp(self.min.1, self.max.1.max(self.min.1));
+        (
+            Bounds2D::new(self.min, (self.max.0, y)),
+            Bounds2D::new((self.min.0, y), self.max),
+        )
+    }
+
+    /// Get the smallest [Bounds2D] that contains both `self` and `other`.
+    pub fn union(self, other: Bounds2D) -> Bounds2D {
+        Bounds2D {
+            min: (self.min.0.min(other.min.0), self.min.1.min(other.min.1)),
+            max: (self.max.0.max(other.max.0), self.max.1.max(other.max.1)),
+        }
+    }
+
     /// Determine if a point is within the [Bounds2D].
     pub fn contains(self, point: (i32, i32)) -> bool {
         point.0 >= self.min.0
-            && point.1 >= self.min.0
+            && point.1 >= self.min.1
             && point.0 < self.max.0
             && point.1 < self.max.1
     }
 
+    /// Map `point` into the [Bounds2D] by wrapping each component modulo the size of the
+    /// bounds, as if the bounds were tiled infinitely. Points within the bounds are
+    /// unchanged.
+    ///
+    /// Panics if the bounds are empty.
+    pub fn wrap(self, point: (i32, i32)) -> (i32, i32) {
+        wrap_coord_2d(point, self.min, (self.width(), self.height()))
+    }
+
+    /// Get the cell in the [Bounds2D] that is closest to `point`, which is `point` itself
+    /// if it is within the bounds.
+    ///
+    /// Each component is clamped to `min..=max - 1`, so the result is always a cell within
+    /// the bounds. Empty bounds have no cells, so each empty axis clamps to `min`.
+    pub fn clamp_point(self, point: (i32, i32)) -> (i32, i32) {
+        (
+            point
+                .0
+                .clamp(self.min.0, self.max.0.saturating_sub(1).max(self.min.0)),
+            point
+                .1
+                .clamp(self.min.1, self.max.1.saturating_sub(1).max(self.min.1)),
+        )
+    }
+
+    /// Get the squared distance from `point` to the closest cell in the [Bounds2D]
+    /// (see [clamp_point](Self::clamp_point)). This is `0` for points within the bounds.
+    ///
+    /// The result saturates at [u64::MAX].
+    pub fn distance_squared_to(self, point: (i32, i32)) -> u64 {
+        let closest = self.clamp_point(point);
+        let dx = (point.0 as i64 - closest.0 as i64).unsigned_abs();
+        let dy = (point.1 as i64 - closest.1 as i64).unsigned_abs();
+        (dx * dx).saturating_add(dy * dy)
+    }
+
+    /// Grow the [Bounds2D] by subtracting `margin` from `min` and adding it to `max`.
+    ///
+    /// A negative margin shrinks the bounds instead. If it would shrink an axis past zero
+    /// size, that axis collapses to an empty range at its middle rather than inverting.
+    ///
+    /// Panics if the result would overflow.
+    pub fn inflate(self, margin: (i32, i32)) -> Bounds2D {
+        let axis = |min: i32, max: i32, margin: i32| {
+            let min = min as i64 - margin as i64;
+            let max = max as i64 + margin as i64;
+            if min > max {
+                let middle = (min + max).div_euclid(2) as i32;
+                return (middle, middle);
+            }
+            let fit = |value: i64| i32::try_from(value).expect(INFLATE_OVERFLOW);
+            (fit(min), fit(max))
+        };
+        let (x_min, x_max) = axis(self.min.0, self.max.0, margin.0);
+        let (y_min, y_max) = axis(self.min.1, self.max.1, margin.1);
+        Bounds2D {
+            min: (x_min, y_min),
+            max: (x_max, y_max),
+        }
+    }
+
+    /// Shrink the [Bounds2D] by adding `margin` to `min` and subtracting it from `max`.
+    ///
+    /// Returns `None` if the result would have no area (`min >= max` on either axis)
+    /// or if the result would overflow.
+    pub fn deflate(self, margin: (i32, i32)) -> Option<Bounds2D> {
+        let min = (
+            self.min.0.checked_add(margin.0)?,
+            self.min.1.checked_add(margin.1)?,
+        );
+        let max = (
+            self.max.0.checked_sub(margin.0)?,
+            self.max.1.checked_sub(margin.1)?,
+        );
+        if min.0 >= max.0 || min.1 >= max.1 {
+            return None;
+        }
+        Some(Bounds2D { min, max })
+    }
+
+    /// Get the [Bounds2D] shifted by `offset`.
+    ///
+    /// Panics if either bound would overflow on any axis.
+    pub fn translated(self, offset: (i32, i32)) -> Self {
+        let shift = |min: i32, max: i32, offset: i32, min_msg: &str, max_msg: &str| match (
+            min.checked_add(offset),
+            max.checked_add(offset),
+        ) {
+            (Some(min), Some(max)) => (min, max),
+            _ if offset < 0 => panic!("{min_msg}"),
+            _ => panic!("{max_msg}"),
+        };
+        let (x_min, x_max) = shift(
+            self.min.0,
+            self.max.0,
+            offset.0,
+            X_MIN_EXCEEDS_MINIMUM,
+            X_MAX_EXCEEDS_MAXIMUM,
+        );
+        let (y_min, y_max) = shift(
+            self.min.1,
+            self.max.1,
+            offset.1,
+            Y_MIN_EXCEEDS_MINIMUM,
+            Y_MAX_EXCEEDS_MAXIMUM,
+        );
+        Self {
+            min: (x_min, y_min),
+            max: (x_max, y_max),
+        }
+    }
+
+    /// Shift the [Bounds2D] by `offset` in place.
+    ///
+    /// Panics if either bound would overflow on any axis.
+    pub fn translate(&mut self, offset: (i32, i32)) {
+        *self = self.translated(offset);
+    }
+
+    /// Get the four corner cells of the [Bounds2D], using `max - 1` for the maximum corners.
+    ///
+    /// The corner at index `i` is on the maximum side along X if bit `0` of `i` is set, and
+    /// along Y if bit `1` is set, so the order is `min`, `+X`, `+Y`, `+X+Y`.
+    /// A bounds with a size of `1` on an axis has the same coordinate on both sides of it.
+    /// Empty bounds have no cells, so their corners are clamped like
+    /// [clamp_point](Self::clamp_point).
+    pub fn corners(self) -> [(i32, i32); 4] {
+        let (lo, hi) = (self.min, self.clamp_point(self.max));
+        [(lo.0, lo.1), (hi.0, lo.1), (lo.0, hi.1), (hi.0, hi.1)]
+    }
+
+    /// Iterate the rows of the [Bounds2D] from `min.1` to `max.1`, yielding each row's
+    /// `y` coordinate along with the range of `x` coordinates it covers.
+    ///
+    /// Empty bounds yield nothing.
+    pub fn iter_rows(self) -> impl Iterator<Item = (i32, std::ops::Range<i32>)> {
+        let x_range = self.min.0..self.max.0;
+        let y_range = if x_range.is_empty() {
+            0..0
+        } else {
+            self.min.1..self.max.1
+        };
+        y_range.map(move |y| (y, x_range.clone()))
+    }
+
+    /// Iterate the cells on the perimeter of the [Bounds2D] without visiting the interior.
+    /// Each cell is yielded exactly once.
+    pub fn iter_border(self) -> impl Iterator<Item = (i32, i32)> {
+        let (x_min, y_min) = self.min;
+        let (x_max, y_max) = if self.area() > 0 { self.max } else { self.min };
+        let wide = self.width() > 1;
+        let tall = self.height() > 1;
+        let bottom = (x_min..x_max).map(move |x| (x, y_min));
+        let sides = (y_min.saturating_add(1)..y_max.saturating_sub(1)).flat_map(move |y| {
+            [Some((x_min, y)), wide.then_some((x_max - 1, y))]
+                .into_iter()
+                .flatten()
+        });
+        let top = (x_min..x_max)
+            .filter(move |_| tall)
+            .map(move |x| (x, y_max - 1));
+        bottom.chain(sides).chain(top)
+    }
+
+    /// Iterate the sub-bounds produced by clipping the [Bounds2D] against a grid of
+    /// `chunk_size` cells aligned to the origin. Partial chunks at the edges are clipped
+    /// to `self`.
+    ///
+    /// Panics if either dimension of `chunk_size` is zero.
+    pub fn iter_chunks(self, chunk_size: (u32, u32)) -> impl Iterator<Item = Bounds2D> {
+        if chunk_size.0 == 0 || chunk_size.1 == 0 {
+            panic!("{AREA_IS_ZERO}");
+        }
+        let chunks = if self.area() > 0 {
+            Some(chunk_bounds_for_world(self, chunk_size))
+        } else {
+            None
+        };
+        let (width, height) = (chunk_size.0 as i64, chunk_size.1 as i64);
+        chunks
+            .into_iter()
+            .flat_map(Bounds2D::iter)
+            .map(move |(chunk_x, chunk_y)| {
+                let (x, y) = (chunk_x as i64 * width, chunk_y as i64 * height);
+                Bounds2D {
+                    min: (
+                        x.max(self.min.0 as i64) as i32,
+                        y.max(self.min.1 as i64) as i32,
+                    ),
+                    max: (
+                        (x + width).min(self.max.0 as i64) as i32,
+                        (y + height).min(self.max.1 as i64) as i32,
+                    ),
+                }
+            })
+    }
+
+    /// Multiply both bounds by `factor`, for example to convert chunk coordinates into
+    /// cell coordinates.
+    ///
+    /// Panics if either bound would overflow.
+    pub fn scaled_up(self, factor: (u32, u32)) -> Bounds2D {
+        let x = |value| scale_component(value, factor.0, Axis::X);
+        let y = |value| scale_component(value, factor.1, Axis::Y);
+        Bounds2D {
+            min: (x(self.min.0), y(self.min.1)),
+            max: (x(self.max.0), y(self.max.1)),
+        }
+    }
+
+    /// Divide both bounds by `factor` using floor division, so negative coordinates
+    /// round toward negative infinity. This is the inverse of [scaled_up](Self::scaled_up).
+    ///
+    /// Unaligned maximum bounds are floored as well, so the result may not cover all of
+    /// `self`. Use [to_chunk_coords](Self::to_chunk_coords) to get the covering chunks.
+    ///
+    /// Panics if either dimension of `factor` is zero.
+    pub fn scaled_down(self, factor: (u32, u32)) -> Bounds2D {
+        if factor.0 == 0 || factor.1 == 0 {
+            panic!("{AREA_IS_ZERO}");
+        }
+        let axis = |value: i32, factor: u32| (value as i64).div_euclid(factor as i64) as i32;
+        Bounds2D {
+            min: (axis(self.min.0, factor.0), axis(self.min.1, factor.1)),
+            max: (axis(self.max.0, factor.0), axis(self.max.1, factor.1)),
+        }
+    }
+
+    /// Get the bounds (in chunk coordinates) of the chunks of `chunk_size` cells that
+    /// cover the [Bounds2D]. This is the same as [chunk_bounds_for_world].
+    ///
+    /// Panics if either dimension of `chunk_size` is zero.
+    pub fn to_chunk_coords(self, chunk_size: (u32, u32)) -> Bounds2D {
+        chunk_bounds_for_world(self, chunk_size)
+    }
+
+    /// Grow the [Bounds2D] to the smallest enclosing box that is aligned to a grid of
+    /// `chunk_size` cells. Empty bounds remain empty.
+    ///
+    /// Panics if either dimension of `chunk_size` is zero, or if the aligned bounds
+    /// would overflow.
+    pub fn aligned_outward(self, chunk_size: (u32, u32)) -> Bounds2D {
+        self.to_chunk_coords(chunk_size).scaled_up(chunk_size)
+    }
+
+    /// Shrink the [Bounds2D] to the largest box that is aligned to a grid of `chunk_size`
+    /// cells and fully covered by `self`.
+    ///
+    /// If no aligned chunk fits within `self`, this returns empty bounds at `min`.
+    ///
+    /// Panics if either dimension of `chunk_size` is zero.
+    pub fn aligned_inward(self, chunk_size: (u32, u32)) -> Bounds2D {
+        if chunk_size.0 == 0 || chunk_size.1 == 0 {
+            panic!("{AREA_IS_ZERO}");
+        }
+        let axis = |min: i32, max: i32, size: u32| {
+            let size = size as i64;
+            let min = (min as i64).div_euclid(size) * size
+                + if (min as i64).rem_euclid(size) == 0 {
+                    0
+                } else {
+                    size
+                };
+            let max = (max as i64).div_euclid(size) * size;
+            (min, max)
+        };
+        let (x_min, x_max) = axis(self.min.0, self.max.0, chunk_size.0);
+        let (y_min, y_max) = axis(self.min.1, self.max.1, chunk_size.1);
+        if x_min >= x_max || y_min >= y_max {
+            return Bounds2D::new(self.min, self.min);
+        }
+        Bounds2D::new((x_min as i32, y_min as i32), (x_max as i32, y_max as i32))
+    }
+
     /// Iterate the coordinates in the [Bounds2D].
     pub fn iter(self) -> Bounds2DIter {
+        let remaining = self.area() as u64;
         Bounds2DIter {
             bounds: self,
-            current: self.min,
+            front: self.min,
+            back: if remaining > 0 {
+                (self.max.0 - 1, self.max.1 - 1)
+            } else {
+                self.min
+            },
+            remaining,
         }
     }
 }
 
+impl std::fmt::Display for Bounds2D {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}..{:?}", self.min, self.max)
+    }
+}
+
 /// Iterator for all points within a [Bounds2D].
+///
+/// Points are yielded in row-major order (x, then y).
 pub struct Bounds2DIter {
     bounds: Bounds2D,
-    current: (i32, i32),
+    /// The next point yielded by `next`.
+    front: (i32, i32),
+    /// The next point yielded by `next_back`.
+    back: (i32, i32),
+    /// The number of points between `front` and `back` (inclusive).
+    remaining: u64,
 }
 
 impl Iterator for Bounds2DIter {
     type Item = (i32, i32);
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        if self.current.1 == self.bounds.max.1 {
-            return (0, Some(0));
+        match usize::try_from(self.remaining) {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
         }
-        let (x, y) = (
-            self.current.0 - self.bounds.min.0,
-            self.current.1 - self.bounds.min.1,
-        );
-        let width = self.bounds.max.0 - self.bounds.min.0;
-        let height = self.bounds.max.1 - self.bounds.min.1;
-        let size = (width * height) as usize;
-        let index = (y * width + x) as usize;
-        (size - index, Some(size - index))
     }
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current.1 == self.bounds.max.1 {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let result = self.front;
+        if self.remaining == 0 {
+            return Some(result);
+        }
+        self.front = if result.0 + 1 == self.bounds.max.0 {
+            (self.bounds.min.0, result.1 + 1)
+        } else {
+            (result.0 + 1, result.1)
+        };
+        Some(result)
+    }
+}
+
+impl DoubleEndedIterator for Bounds2DIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
             return None;
         }
-        let result = self.current;
-        self.current = (result.0 + 1, result.1);
-        if self.current.0 == self.bounds.max.0 {
-            self.current = (self.bounds.min.0, result.1 + 1);
+        self.remaining -= 1;
+        let result = self.back;
+        if self.remaining == 0 {
+            return Some(result);
         }
+        self.back = if result.0 == self.bounds.min.0 {
+            (self.bounds.max.0 - 1, result.1 - 1)
+        } else {
+            (result.0 - 1, result.1)
+        };
         Some(result)
     }
 }
+
+impl std::iter::FusedIterator for Bounds2DIter {}
+
+/// Convert a world coordinate into the coordinate of the chunk that contains it.
+///
+/// This uses floor division, so negative coordinates map to negative chunks
+/// (`-1` with a chunk size of `16` is in chunk `-1`, not chunk `0`).
+pub fn world_to_chunk(world: (i32, i32), chunk_size: (u32, u32)) -> (i32, i32) {
+    if chunk_size.0 == 0 || chunk_size.1 == 0 {
+        panic!("{AREA_IS_ZERO}");
+    }
+    (
+        (world.0 as i64).div_euclid(chunk_size.0 as i64) as i32,
+        (world.1 as i64).div_euclid(chunk_size.1 as i64) as i32,
+    )
+}
+
+/// Get the bounds (in chunk coordinates) of the chunks that cover `world_bounds`.
+///
+/// Empty world bounds result in empty chunk bounds.
+pub fn chunk_bounds_for_world(world_bounds: Bounds2D, chunk_size: (u32, u32)) -> Bounds2D {
+    let min = world_to_chunk(world_bounds.min, chunk_size);
+    let (max_x, max_y) = world_to_chunk(
+        (
+            world_bounds.max.0.saturating_sub(1),
+            world_bounds.max.1.saturating_sub(1),
+        ),
+        chunk_size,
+    );
+    let max_x = if world_bounds.width() == 0 {
+        min.0
+    } else {
+        max_x + 1
+    };
+    let max_y = if world_bounds.height() == 0 {
+        min.1
+    } else {
+        max_y + 1
+    };
+    Bounds2D::new(min, (max_x, max_y))
+}
+
+impl ExactSizeIterator for Bounds2DIter {}
+
+/// Iterate the coordinates in the [Bounds2D], which allows using it directly in a `for` loop.
+///
+/// ```
+/// use rollgrid::bounds2d::Bounds2D;
+///
+/// let bounds = Bounds2D::new((0, 0), (2, 2));
+/// let mut visited = Vec::new();
+/// for pos in bounds {
+///     visited.push(pos);
+/// }
+/// assert_eq!(visited, [(0, 0), (1, 0), (0, 1), (1, 1)]);
+///
+/// fn count(positions: impl IntoIterator<Item = (i32, i32)>) -> usize {
+///     positions.into_iter().count()
+/// }
+/// assert_eq!(count(bounds), 4);
+/// assert_eq!(count(&bounds), 4);
+/// assert_eq!(count(visited), 4);
+/// ```
+impl IntoIterator for Bounds2D {
+    type Item = <Bounds2DIter as Iterator>::Item;
+    type IntoIter = Bounds2DIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &Bounds2D {
+    type Item = <Bounds2DIter as Iterator>::Item;
+    type IntoIter = Bounds2DIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn world_to_chunk_test() {
+        assert_eq!(world_to_chunk((0, 0), (16, 16)), (0, 0));
+        assert_eq!(world_to_chunk((15, 16), (16, 16)), (0, 1));
+        assert_eq!(world_to_chunk((-1, -16), (16, 16)), (-1, -1));
+        assert_eq!(world_to_chunk((-17, -33), (16, 8)), (-2, -5));
+        assert_eq!(
+            world_to_chunk((i32::MIN, i32::MAX), (u32::MAX, 1)),
+            (-1, i32::MAX)
+        );
+    }
+
+    #[test]
+    fn chunk_bounds_for_world_test() {
+        let world = Bounds2D::new((-17, -1), (16, 1));
+        assert_eq!(
+            chunk_bounds_for_world(world, (16, 16)),
+            Bounds2D::new((-2, -1), (1, 1))
+        );
+        let world = Bounds2D::new((-16, 0), (0, 32));
+        assert_eq!(
+            chunk_bounds_for_world(world, (16, 16)),
+            Bounds2D::new((-1, 0), (0, 2))
+        );
+        let empty = Bounds2D::new((-5, -5), (-5, -5));
+        assert_eq!(chunk_bounds_for_world(empty, (16, 16)).area(), 0);
+    }
+
+    #[test]
+    fn try_new_is_empty_test() {
+        assert_eq!(
+            Bounds2D::try_new((0, 0), (2, 3)),
+            Ok(Bounds2D::new((0, 0), (2, 3)))
+        );
+        assert_eq!(
+            Bounds2D::try_new((3, 0), (2, 3)),
+            Err(BoundsError::InvertedX)
+        );
+        assert_eq!(
+            Bounds2D::try_new((0, 4), (2, 3)),
+            Err(BoundsError::InvertedY)
+        );
+        assert!(!Bounds2D::new((0, 0), (1, 1)).is_empty());
+        assert!(Bounds2D::new((0, 0), (0, 1)).is_empty());
+        assert!(Bounds2D::new((0, 5), (4, 1)).is_empty());
+        assert_eq!(Bounds2D::new((0, 5), (4, 1)).height(), 0);
+        assert_eq!(Bounds2D::new((0, 5), (4, 1)).area(), 0);
+    }
+
+    #[test]
+    fn inverted_iter_fuzz_test() {
+        let mut state = 0x2545F4914F6CDD1Du64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % 17) as i32 - 8
+        };
+        for _ in 0..500 {
+            let bounds = Bounds2D::new((next(), next()), (next(), next()));
+            let expected = if bounds.max.0 > bounds.min.0 && bounds.max.1 > bounds.min.1 {
+                ((bounds.max.0 - bounds.min.0) * (bounds.max.1 - bounds.min.1)) as usize
+            } else {
+                0
+            };
+            assert_eq!(bounds.is_empty(), expected == 0);
+            assert_eq!(
+                Bounds2D::try_new(bounds.min, bounds.max).is_ok(),
+                bounds.min.0 <= bounds.max.0 && bounds.min.1 <= bounds.max.1
+            );
+            assert_eq!(bounds.iter().len(), expected);
+            assert_eq!(bounds.iter().count(), expected);
+            assert_eq!(bounds.iter().rev().count(), expected);
+            assert_eq!(
+                bounds.iter_rows().map(|(_, xs)| xs.len()).sum::<usize>(),
+                expected
+            );
+            assert!(bounds.iter().all(|pos| bounds.contains(pos)));
+        }
+    }
+
+    #[test]
+    fn try_new_test() {
+        assert_eq!(
+            Bounds2D::try_new((3, 0), (2, 3)),
+            Err(BoundsError::InvertedX)
+        );
+        assert_eq!(
+            Bounds2D::try_new((0, 3), (2, 2)),
+            Err(BoundsError::InvertedY)
+        );
+        assert_eq!(
+            Bounds2D::try_new((1, 1), (1, 1)),
+            Ok(Bounds2D::new((1, 1), (1, 1)))
+        );
+        assert_eq!(
+            Bounds2D::from_bounds((3, 4), (2, 3)),
+            Bounds2D::new((2, 3), (3, 4))
+        );
+        const BOUNDS: Bounds2D = Bounds2D::new((-1, -1), (1, 1));
+        assert_eq!(BOUNDS.area(), 4);
+    }
+
+    #[test]
+    fn contains_test() {
+        let bounds = Bounds2D::new((0, 5), (4, 9));
+        assert!(!bounds.contains((0, 4)));
+        assert!(bounds.contains((0, 5)));
+        assert!(bounds.contains((3, 8)));
+        assert!(!bounds.contains((4, 8)));
+        assert!(!bounds.contains((3, 9)));
+        assert!(!bounds.contains((-1, 5)));
+    }
+
+    #[test]
+    fn intersection_test() {
+        let a = Bounds2D::new((0, 0), (4, 4));
+        assert_eq!(
+            a.intersection(Bounds2D::new((2, -1), (6, 3))),
+            Some(Bounds2D::new((2, 0), (4, 3)))
+        );
+        assert_eq!(
+            a.intersection(Bounds2D::new((1, 1), (2, 2))),
+            Some(Bounds2D::new((1, 1), (2, 2)))
+        );
+        // touching edges do not intersect.
+        assert_eq!(a.intersection(Bounds2D::new((4, 0), (6, 4))), None);
+        assert_eq!(a.intersection(Bounds2D::new((5, 5), (6, 6))), None);
+    }
+
+    #[test]
+    fn difference_test() {
+        // Simple xorshift so that the test is deterministic without dependencies.
+        let mut state = 0x2545F491u32;
+        let mut next = |range: i32| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            (state % range as u32) as i32 - range / 2
+        };
+        for _ in 0..500 {
+            let a = Bounds2D::from_bounds((next(12), next(12)), (next(12), next(12)));
+            let b = Bounds2D::from_bounds((next(12), next(12)), (next(12), next(12)));
+            let regions = a.difference(b).collect::<Vec<_>>();
+            assert!(regions.len() <= 4);
+            let overlap = a.intersection(b).map(|inner| inner.area()).unwrap_or(0);
+            let total: i64 = regions.iter().map(|region| region.area()).sum();
+            assert_eq!(total, a.area() - overlap);
+            for (i, region) in regions.iter().enumerate() {
+                assert!(region.area() > 0);
+                for other in regions.iter().skip(i + 1) {
+                    assert!(!region.intersects(*other));
+                }
+            }
+            for y in a.y_min()..a.y_max() {
+                for x in a.x_min()..a.x_max() {
+                    let covered = regions.iter().filter(|region| region.contains((x, y)));
+                    assert_eq!(covered.count() == 1, !b.contains((x, y)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn exact_size_empty_test() {
+        for bounds in [
+            Bounds2D::new((0, 0), (0, 4)),
+            Bounds2D::new((0, 0), (4, 0)),
+            Bounds2D::new((2, 2), (2, 2)),
+        ] {
+            let mut iter = bounds.iter();
+            assert_eq!(iter.len(), 0);
+            assert_eq!(iter.next(), None);
+        }
+    }
+
+    #[test]
+    fn exact_size_test() {
+        let bounds = Bounds2D::new((-2, 3), (3, 7));
+        let mut iter = bounds.iter();
+        let mut remaining = 20;
+        assert_eq!(iter.len(), remaining);
+        while iter.next().is_some() {
+            remaining -= 1;
+            assert_eq!(iter.len(), remaining);
+        }
+        assert_eq!(remaining, 0);
+        let mut count = 0;
+        for pos in bounds {
+            assert!(bounds.contains(pos));
+            count += 1;
+        }
+        assert_eq!(count, 20);
+        assert_eq!(
+            bounds.into_iter().collect::<Vec<_>>(),
+            bounds.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn double_ended_test() {
+        let bounds = Bounds2D::new((-1, 2), (2, 5));
+        let forward = bounds.iter().collect::<Vec<_>>();
+        assert_eq!(forward.len(), 9);
+        assert_eq!(forward[..4], [(-1, 2), (0, 2), (1, 2), (-1, 3)]);
+        let mut backward = bounds.iter().rev().collect::<Vec<_>>();
+        backward.reverse();
+        assert_eq!(forward, backward);
+        let mut iter = bounds.iter();
+        assert_eq!(iter.next_back(), Some((1, 4)));
+        assert_eq!(iter.next_back(), Some((0, 4)));
+        assert_eq!(iter.next_back(), Some((-1, 4)));
+        assert_eq!(iter.next_back(), Some((1, 3)));
+        assert_eq!(iter.len(), 5);
+    }
+
+    #[test]
+    fn double_ended_interleaved_test() {
+        // Alternate between the ends in every pattern for a few sizes and make sure
+        // every point is yielded exactly once.
+        for (width, height) in [(1, 1), (1, 4), (4, 1), (2, 2), (3, 4), (5, 3)] {
+            let bounds = Bounds2D::new((3, -2), (3 + width, height - 2));
+            let expected = bounds.iter().collect::<Vec<_>>();
+            for pattern in 0u32..64 {
+                let mut iter = bounds.iter();
+                let mut front = Vec::new();
+                let mut back = Vec::new();
+                let mut step = 0;
+                loop {
+                    assert_eq!(iter.len(), expected.len() - front.len() - back.len());
+                    let next = if pattern & (1 << (step % 6)) == 0 {
+                        iter.next().map(|pos| front.push(pos))
+                    } else {
+                        iter.next_back().map(|pos| back.push(pos))
+                    };
+                    if next.is_none() {
+                        break;
+                    }
+                    step += 1;
+                }
+                assert_eq!(iter.next(), None);
+                assert_eq!(iter.next_back(), None);
+                back.reverse();
+                front.extend(back);
+                assert_eq!(front, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn scaled_test() {
+        let bounds = Bounds2D::new((-2, 0), (3, 4));
+        let scaled = bounds.scaled_up((16, 16));
+        assert_eq!(scaled, Bounds2D::new((-32, 0), (48, 64)));
+        assert_eq!(scaled.scaled_down((16, 16)), bounds);
+        assert_eq!(bounds.scaled_up((2, 3)).scaled_down((2, 3)), bounds);
+        // unaligned negative bounds floor toward negative infinity
+        let bounds = Bounds2D::new((-17, -1), (-3, 15));
+        assert_eq!(
+            bounds.scaled_down((16, 16)),
+            Bounds2D::new((-2, -1), (-1, 0))
+        );
+        assert_eq!(
+            Bounds2D::new((5, 5), (9, 9)).scaled_down((1, 1)),
+            Bounds2D::new((5, 5), (9, 9))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Y maximum bound exceeds i32::MAX")]
+    fn scaled_up_overflow_test() {
+        Bounds2D::new((0, 0), (1, i32::MAX / 16 + 1)).scaled_up((16, 16));
+    }
+
+    #[test]
+    fn chunk_alignment_test() {
+        // negative, zero, and positive offsets that are not multiples of the chunk size
+        let bounds = Bounds2D::new((-17, -1), (5, 33));
+        assert_eq!(
+            bounds.to_chunk_coords((16, 16)),
+            Bounds2D::new((-2, -1), (1, 3))
+        );
+        assert_eq!(
+            bounds.aligned_outward((16, 16)),
+            Bounds2D::new((-32, -16), (16, 48))
+        );
+        assert_eq!(
+            bounds.aligned_inward((16, 16)),
+            Bounds2D::new((-16, 0), (0, 32))
+        );
+        let bounds = Bounds2D::new((0, 0), (32, 20));
+        assert_eq!(
+            bounds.to_chunk_coords((16, 16)),
+            Bounds2D::new((0, 0), (2, 2))
+        );
+        assert_eq!(
+            bounds.aligned_outward((16, 16)),
+            Bounds2D::new((0, 0), (32, 32))
+        );
+        assert_eq!(
+            bounds.aligned_inward((16, 16)),
+            Bounds2D::new((0, 0), (32, 16))
+        );
+        let bounds = Bounds2D::new((3, 17), (40, 50));
+        assert_eq!(
+            bounds.to_chunk_coords((16, 8)),
+            Bounds2D::new((0, 2), (3, 7))
+        );
+        assert_eq!(
+            bounds.aligned_outward((16, 8)),
+            Bounds2D::new((0, 16), (48, 56))
+        );
+        assert_eq!(
+            bounds.aligned_inward((16, 8)),
+            Bounds2D::new((16, 24), (32, 48))
+        );
+        // already aligned bounds are unchanged
+        let aligned = Bounds2D::new((-32, 16), (0, 48));
+        assert_eq!(aligned.aligned_outward((16, 16)), aligned);
+        assert_eq!(aligned.aligned_inward((16, 16)), aligned);
+        // chunk sizes of 1 are the identity
+        let bounds = Bounds2D::new((-7, -3), (5, 9));
+        assert_eq!(bounds.to_chunk_coords((1, 1)), bounds);
+        assert_eq!(bounds.aligned_outward((1, 1)), bounds);
+        assert_eq!(bounds.aligned_inward((1, 1)), bounds);
+        // no chunk fits inside
+        let bounds = Bounds2D::new((-3, 1), (5, 15));
+        assert_eq!(
+            bounds.aligned_inward((16, 16)),
+            Bounds2D::new((-3, 1), (-3, 1))
+        );
+        assert_eq!(
+            bounds.aligned_outward((16, 16)),
+            Bounds2D::new((-16, 0), (16, 16))
+        );
+        // outward alignment always contains the bounds, inward is always contained
+        let mut state = 0x9E3779B97F4A7C15u64;
+        let mut next = |range: i32| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % (range as u64 * 2)) as i32 - range
+        };
+        for _ in 0..200 {
+            let bounds = Bounds2D::from_bounds((next(100), next(100)), (next(100), next(100)));
+            let size = (next(8).unsigned_abs() + 1, next(8).unsigned_abs() + 1);
+            let outward = bounds.aligned_outward(size);
+            let inward = bounds.aligned_inward(size);
+            assert_eq!(outward.union(bounds), outward);
+            if inward.area() > 0 {
+                assert_eq!(inward.intersection(bounds), Some(inward));
+                assert_eq!(inward.aligned_inward(size), inward);
+            }
+            assert_eq!(outward.aligned_outward(size), outward);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "X minimum bound exceeds i32::MIN")]
+    fn aligned_outward_overflow_test() {
+        Bounds2D::new((i32::MIN + 1, 0), (0, 1)).aligned_outward((3, 1));
+    }
+
+    #[test]
+    fn split_test() {
+        let bounds = Bounds2D::new((-2, 3), (5, 7));
+        let (left, right) = bounds.split_x(1);
+        assert_eq!(left, Bounds2D::new((-2, 3), (1, 7)));
+        assert_eq!(right, Bounds2D::new((1, 3), (5, 7)));
+        assert_eq!(left.area() + right.area(), bounds.area());
+        assert!(!left.intersects(right));
+        assert!(bounds
+            .iter()
+            .all(|pos| left.contains(pos) != right.contains(pos)));
+        let (bottom, top) = bounds.split_y(4);
+        assert_eq!(bottom, Bounds2D::new((-2, 3), (5, 4)));
+        assert_eq!(top, Bounds2D::new((-2, 4), (5, 7)));
+        assert!(bottom.iter().chain(top.iter()).eq(bounds.iter()));
+        // first and last valid coordinates
+        assert_eq!(
+            bounds.split_x(-2),
+            (Bounds2D::new((-2, 3), (-2, 7)), bounds)
+        );
+        assert_eq!(bounds.split_x(5), (bounds, Bounds2D::new((5, 3), (5, 7))));
+        assert_eq!(bounds.split_y(3), (Bounds2D::new((-2, 3), (5, 3)), bounds));
+        assert_eq!(bounds.split_y(7), (bounds, Bounds2D::new((-2, 7), (5, 7))));
+        // out of range splits are clamped
+        assert_eq!(bounds.split_x(i32::MIN), bounds.split_x(-2));
+        assert_eq!(bounds.split_x(100), bounds.split_x(5));
+        assert_eq!(bounds.split_y(-100), bounds.split_y(3));
+        assert_eq!(bounds.split_y(i32::MAX), bounds.split_y(7));
+        let (empty, rest) = bounds.split_y(-100);
+        assert_eq!(empty.iter().count(), 0);
+        assert_eq!(rest, bounds);
+    }
+
+    #[test]
+    fn union_test() {
+        let a = Bounds2D::new((0, 0), (2, 2));
+        let b = Bounds2D::new((3, 3), (5, 5));
+        assert_eq!(a.union(b), Bounds2D::new((0, 0), (5, 5)));
+        assert_eq!(b.union(a), Bounds2D::new((0, 0), (5, 5)));
+        let inner = Bounds2D::new((1, 1), (1, 1));
+        assert_eq!(a.union(inner), a);
+        let c = Bounds2D::new((-4, 1), (1, 8));
+        assert_eq!(a.union(c), Bounds2D::new((-4, 0), (2, 8)));
+    }
+
+    #[test]
+    fn display_test() {
+        let bounds = Bounds2D::new((-3, 0), (4, 12));
+        assert_eq!(bounds.to_string(), "(-3, 0)..(4, 12)");
+    }
+
+    #[test]
+    fn inflate_test() {
+        let bounds = Bounds2D::new((-2, 3), (4, 5));
+        assert_eq!(bounds.inflate((1, 2)), Bounds2D::new((-3, 1), (5, 7)));
+        assert_eq!(bounds.inflate((0, 0)), bounds);
+        assert_eq!(bounds.inflate((3, 1)).deflate((3, 1)), Some(bounds));
+        // negative margins shrink, collapsing an axis at its middle instead of inverting.
+        assert_eq!(bounds.inflate((-1, 0)), Bounds2D::new((-1, 3), (3, 5)));
+        assert_eq!(bounds.inflate((-3, -1)), Bounds2D::new((1, 4), (1, 4)));
+        let collapsed = bounds.inflate((-100, i32::MIN));
+        assert_eq!(collapsed, Bounds2D::new((1, 4), (1, 4)));
+        assert!(collapsed.is_empty());
+        assert_eq!(
+            Bounds2D::new((i32::MIN, 0), (i32::MAX, 1)).inflate((i32::MIN, 0)),
+            Bounds2D::new((-1, 0), (-1, 1))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Inflate operation results in integer overflow")]
+    fn inflate_overflow_test() {
+        Bounds2D::new((0, 0), (i32::MAX, 4)).inflate((1, 1));
+    }
+
+    #[test]
+    fn deflate_test() {
+        let bounds = Bounds2D::new((-2, 3), (4, 9));
+        assert_eq!(bounds.deflate((1, 2)), Some(Bounds2D::new((-1, 5), (3, 7))));
+        assert_eq!(bounds.deflate((2, 2)), Some(Bounds2D::new((0, 5), (2, 7))));
+        // width would be 0.
+        assert_eq!(bounds.deflate((3, 0)), None);
+        // height would be negative.
+        assert_eq!(bounds.deflate((0, 4)), None);
+        assert_eq!(
+            Bounds2D::new((i32::MAX - 1, 0), (i32::MAX, 1)).deflate((i32::MAX, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn clamp_point_test() {
+        let bounds = Bounds2D::new((-2, 3), (4, 7));
+        // contained points are unchanged
+        assert_eq!(bounds.clamp_point((0, 5)), (0, 5));
+        assert_eq!(bounds.distance_squared_to((0, 5)), 0);
+        // corners
+        assert_eq!(bounds.clamp_point((-10, -10)), (-2, 3));
+        assert_eq!(bounds.clamp_point((10, -10)), (3, 3));
+        assert_eq!(bounds.clamp_point((-10, 10)), (-2, 6));
+        assert_eq!(bounds.clamp_point((10, 10)), (3, 6));
+        assert_eq!(bounds.clamp_point((4, 7)), (3, 6));
+        assert_eq!(bounds.distance_squared_to((-5, 1)), 9 + 4);
+        assert_eq!(bounds.distance_squared_to((4, 7)), 2);
+        // edges
+        assert_eq!(bounds.clamp_point((-3, 4)), (-2, 4));
+        assert_eq!(bounds.clamp_point((4, 4)), (3, 4));
+        assert_eq!(bounds.clamp_point((1, 2)), (1, 3));
+        assert_eq!(bounds.clamp_point((1, 7)), (1, 6));
+        assert_eq!(bounds.distance_squared_to((-3, 4)), 1);
+        assert_eq!(bounds.distance_squared_to((6, 4)), 9);
+        assert_eq!(bounds.distance_squared_to((1, 0)), 9);
+        assert_eq!(bounds.distance_squared_to((1, 7)), 1);
+        // the clamped point is always within the bounds
+        for point in [(i32::MIN, i32::MAX), (4, 7), (3, 6), (-2, 3), (100, -100)] {
+            assert!(bounds.contains(bounds.clamp_point(point)));
+        }
+        let bounds = Bounds2D::new((i32::MIN, i32::MIN), (i32::MIN + 1, i32::MIN + 1));
+        assert_eq!(
+            bounds.clamp_point((i32::MAX, i32::MAX)),
+            (i32::MIN, i32::MIN)
+        );
+        assert_eq!(bounds.distance_squared_to((i32::MAX, i32::MAX)), u64::MAX);
+    }
+
+    #[test]
+    fn center_test() {
+        // odd
+        assert_eq!(Bounds2D::new((0, -2), (5, 1)).center(), (2, -1));
+        // even
+        assert_eq!(Bounds2D::new((0, -2), (4, 2)).center(), (2, 0));
+        assert_eq!(Bounds2D::new((-3, -3), (-3, -3)).center(), (-3, -3));
+        assert_eq!(
+            Bounds2D::new((i32::MIN, i32::MIN), (i32::MAX, i32::MAX)).center(),
+            (-1, -1)
+        );
+    }
+
+    #[test]
+    fn from_center_size_test() {
+        assert_eq!(
+            Bounds2D::from_center_size((2, -1), (5, 3)),
+            Bounds2D::new((0, -2), (5, 1))
+        );
+        assert_eq!(
+            Bounds2D::from_center_size((2, 0), (4, 4)),
+            Bounds2D::new((0, -2), (4, 2))
+        );
+        for (width, height) in [(1, 1), (2, 3), (4, 4), (7, 2)] {
+            let bounds = Bounds2D::from_center_size((-5, 9), (width, height));
+            assert_eq!((bounds.width(), bounds.height()), (width, height));
+            assert_eq!(bounds.center(), (-5, 9));
+        }
+        // near the limits
+        let bounds = Bounds2D::from_center_size((i32::MAX - 2, i32::MIN + 2), (4, 5));
+        assert_eq!(
+            bounds,
+            Bounds2D::new((i32::MAX - 4, i32::MIN), (i32::MAX, i32::MIN + 5))
+        );
+        assert_eq!(bounds.center(), (i32::MAX - 2, i32::MIN + 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "X maximum bound exceeds i32::MAX")]
+    fn from_center_size_overflow_test() {
+        Bounds2D::from_center_size((i32::MAX - 1, 0), (4, 4));
+    }
+
+    #[test]
+    #[should_panic(expected = "Y minimum bound exceeds i32::MIN")]
+    fn from_center_size_underflow_test() {
+        Bounds2D::from_center_size((0, i32::MIN + 1), (4, 5));
+    }
+
+    #[test]
+    fn translated_test() {
+        let bounds = Bounds2D::new((-2, 3), (4, 5));
+        assert_eq!(bounds.translated((3, -4)), Bounds2D::new((1, -1), (7, 1)));
+        let mut to_max = bounds;
+        to_max.translate((i32::MAX - 4, i32::MAX - 5));
+        assert_eq!(to_max.max, (i32::MAX, i32::MAX));
+        let to_min = bounds.translated((i32::MIN + 2, i32::MIN));
+        assert_eq!(to_min.min, (i32::MIN, i32::MIN + 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "X maximum bound exceeds i32::MAX")]
+    fn translated_x_overflow_test() {
+        Bounds2D::new((0, 0), (4, 4)).translated((i32::MAX - 3, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Y maximum bound exceeds i32::MAX")]
+    fn translated_y_overflow_test() {
+        Bounds2D::new((0, 0), (4, 4)).translated((0, i32::MAX - 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "Y minimum bound exceeds i32::MIN")]
+    fn translated_y_underflow_test() {
+        Bounds2D::new((0, -1), (4, 4)).translated((0, i32::MIN));
+    }
+
+    #[test]
+    fn corners_test() {
+        let bounds = Bounds2D::new((-2, 3), (4, 7));
+        assert_eq!(bounds.corners(), [(-2, 3), (3, 3), (-2, 6), (3, 6)]);
+        assert!(bounds
+            .corners()
+            .iter()
+            .all(|corner| bounds.contains(*corner)));
+        let single = Bounds2D::new((5, -5), (6, -4));
+        assert_eq!(single.corners(), [(5, -5); 4]);
+    }
+
+    #[test]
+    fn iter_rows_test() {
+        let bounds = Bounds2D::new((-2, 3), (4, 6));
+        let rows: Vec<_> = bounds.iter_rows().collect();
+        assert_eq!(rows, vec![(3, -2..4), (4, -2..4), (5, -2..4)]);
+        assert_eq!(
+            rows.iter().map(|(_, xs)| xs.len() as i64).sum::<i64>(),
+            bounds.area()
+        );
+        assert!(bounds
+            .iter_rows()
+            .flat_map(|(y, xs)| xs.map(move |x| (x, y)))
+            .eq(bounds.iter()));
+        assert_eq!(Bounds2D::new((0, 0), (0, 4)).iter_rows().count(), 0);
+        assert_eq!(Bounds2D::new((0, 0), (4, 0)).iter_rows().count(), 0);
+    }
+
+    #[test]
+    fn iter_border_test() {
+        use std::collections::HashSet;
+        for height in 1..=6 {
+            for width in 1..=6 {
+                let bounds = Bounds2D::new((-2, 3), (width - 2, height + 3));
+                let on_edge = |&(x, y): &(i32, i32)| {
+                    x == bounds.x_min()
+                        || y == bounds.y_min()
+                        || x == bounds.x_max() - 1
+                        || y == bounds.y_max() - 1
+                };
+                let border = bounds.iter_border().collect::<Vec<_>>();
+                let unique = border.iter().copied().collect::<HashSet<_>>();
+                assert_eq!(border.len(), unique.len(), "{bounds}");
+                let expected = bounds.iter().filter(on_edge).collect::<HashSet<_>>();
+                assert_eq!(unique, expected, "{bounds}");
+            }
+        }
+        let empty = Bounds2D::new((0, 0), (0, 4));
+        assert_eq!(empty.iter_border().count(), 0);
+        let empty = Bounds2D::new((i32::MIN, i32::MIN), (i32::MIN, i32::MIN));
+        assert_eq!(empty.iter_border().count(), 0);
+    }
+
+    #[test]
+    fn iter_chunks_test() {
+        let cases = [
+            (Bounds2D::new((-20, -3), (37, 16)), (16, 16)),
+            (Bounds2D::new((0, 0), (32, 16)), (16, 16)),
+            (Bounds2D::new((5, 5), (6, 6)), (16, 16)),
+            (Bounds2D::new((-7, 2), (9, 11)), (3, 5)),
+        ];
+        for (bounds, chunk_size) in cases {
+            let chunks = bounds.iter_chunks(chunk_size).collect::<Vec<_>>();
+            let total: i64 = chunks.iter().map(|chunk| chunk.area()).sum();
+            assert_eq!(total, bounds.area());
+            for (i, chunk) in chunks.iter().enumerate() {
+                assert!(chunk.area() > 0);
+                assert_eq!(chunk.intersection(bounds), Some(*chunk));
+                // every chunk lies within a single aligned chunk.
+                assert_eq!(
+                    world_to_chunk(chunk.min, chunk_size),
+                    world_to_chunk((chunk.max.0 - 1, chunk.max.1 - 1), chunk_size)
+                );
+                for other in chunks.iter().skip(i + 1) {
+                    assert!(!chunk.intersects(*other));
+                }
+            }
+        }
+        assert_eq!(
+            Bounds2D::new((0, 0), (32, 16))
+                .iter_chunks((16, 16))
+                .collect::<Vec<_>>(),
+            vec![
+                Bounds2D::new((0, 0), (16, 16)),
+                Bounds2D::new((16, 0), (32, 16))
+            ]
+        );
+        let empty = Bounds2D::new((3, 0), (3, 10));
+        assert_eq!(empty.iter_chunks((16, 16)).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Width/Height cannot be 0")]
+    fn iter_chunks_zero_size_test() {
+        let _ = Bounds2D::new((0, 0), (4, 4)).iter_chunks((16, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Width/Height cannot be 0")]
+    fn world_to_chunk_zero_size_test() {
+        world_to_chunk((0, 0), (0, 16));
+    }
+
+    #[test]
+    fn wrap_test() {
+        let bounds = Bounds2D::new((-2, 3), (2, 6));
+        assert_eq!(bounds.wrap((0, 4)), (0, 4));
+        assert_eq!(bounds.wrap((-3, 2)), (1, 5));
+        assert_eq!(bounds.wrap((10, -9)), (-2, 3));
+        assert!(bounds.iter().all(|pos| bounds.wrap(pos) == pos));
+    }
+
+    #[test]
+    #[should_panic(expected = "Width/Height cannot be 0")]
+    fn wrap_empty_test() {
+        Bounds2D::new((0, 0), (0, 4)).wrap((1, 1));
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn from_min_max_test() {
+        use glam::IVec2;
+        let bounds = Bounds2D::from_min_max(IVec2::new(-1, 2), IVec2::new(3, 4));
+        assert_eq!(bounds, Bounds2D::new((-1, 2), (3, 4)));
+    }
+}
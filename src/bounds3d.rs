@@ -1,3 +1,10 @@
+use crate::{
+    bounds2d::Bounds2D,
+    constants::*,
+    math::{scale_component, wrap_coord_3d},
+    Axis, BoundsError,
+};
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// A 3D bounding box.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -14,6 +21,70 @@ impl Bounds3D {
         Self { min, max }
     }
 
+    /// Create a new [Bounds3D] from an inclusive min and exclusive max.
+    #[cfg(feature = "glam")]
+    pub fn from_min_max(min: glam::IVec3, max: glam::IVec3) -> Self {
+        Self::new(min.into(), max.into())
+    }
+
+    /// Create a new [Bounds3D] with the specified minimum and maximum bounds,
+    /// returning an error if `min` is greater than `max` on any axis.
+    pub const fn try_new(min: (i32, i32, i32), max: (i32, i32, i32)) -> Result<Self, BoundsError> {
+        if min.0 > max.0 {
+            return Err(BoundsError::InvertedX);
+        }
+        if min.1 > max.1 {
+            return Err(BoundsError::InvertedY);
+        }
+        if min.2 > max.2 {
+            return Err(BoundsError::InvertedZ);
+        }
+        Ok(Self { min, max })
+    }
+
+    /// Create a new [Bounds3D] of `size` centered at `center`.
+    ///
+    /// For even sizes, `center` is the cell just past the middle (see [Bounds3D::center]),
+    /// so `Bounds3D::from_center_size(bounds.center(), (bounds.width(), bounds.height(), bounds.depth()))`
+    /// is always `bounds`.
+    ///
+    /// Panics if any bound would overflow.
+    pub fn from_center_size(center: (i32, i32, i32), size: (u32, u32, u32)) -> Self {
+        let axis = |center: i32, size: u32, min_msg: &str, max_msg: &str| {
+            let min = center as i64 - (size / 2) as i64;
+            let max = min + size as i64;
+            if min < i32::MIN as i64 {
+                panic!("{min_msg}");
+            }
+            if max > i32::MAX as i64 {
+                panic!("{max_msg}");
+            }
+            (min as i32, max as i32)
+        };
+        let (x_min, x_max) = axis(
+            center.0,
+            size.0,
+            X_MIN_EXCEEDS_MINIMUM,
+            X_MAX_EXCEEDS_MAXIMUM,
+        );
+        let (y_min, y_max) = axis(
+            center.1,
+            size.1,
+            Y_MIN_EXCEEDS_MINIMUM,
+            Y_MAX_EXCEEDS_MAXIMUM,
+        );
+        let (z_min, z_max) = axis(
+            center.2,
+            size.2,
+            Z_MIN_EXCEEDS_MINIMUM,
+            Z_MAX_EXCEEDS_MAXIMUM,
+        );
+        Self {
+            min: (x_min, y_min, z_min),
+            max: (x_max, y_max, z_max),
+        }
+    }
+
     /// Create a new [Bounds3D] from two unordered points.
     pub fn from_bounds(a: (i32, i32, i32), b: (i32, i32, i32)) -> Self {
         let x_min = a.0.min(b.0);
@@ -28,19 +99,25 @@ impl Bounds3D {
         }
     }
 
-    /// The size along the X axis.
+    /// The size along the X axis. This is `0` if the bounds are inverted.
     pub fn width(&self) -> u32 {
-        (self.max.0 as i64 - self.min.0 as i64) as u32
+        (self.max.0 as i64 - self.min.0 as i64).max(0) as u32
     }
 
-    /// The size along the Y axis.
+    /// The size along the Y axis. This is `0` if the bounds are inverted.
     pub fn height(&self) -> u32 {
-        (self.max.1 as i64 - self.min.1 as i64) as u32
+        (self.max.1 as i64 - self.min.1 as i64).max(0) as u32
     }
 
-    /// The size along the Z axis.
+    /// The size along the Z axis. This is `0` if the bounds are inverted.
     pub fn depth(&self) -> u32 {
-        (self.max.2 as i64 - self.min.2 as i64) as u32
+        (self.max.2 as i64 - self.min.2 as i64).max(0) as u32
+    }
+
+    /// Returns true if the [Bounds3D] contains no cells, which is the case when `min`
+    /// is greater than or equal to `max` on any axis.
+    pub fn is_empty(&self) -> bool {
+        self.min.0 >= self.max.0 || self.min.1 >= self.max.1 || self.min.2 >= self.max.2
     }
 
     /// The volume is `width * height * depth`.
@@ -48,6 +125,18 @@ impl Bounds3D {
         self.width() as i128 * self.height() as i128 * self.depth() as i128
     }
 
+    /// The center cell of the [Bounds3D].
+    ///
+    /// For even sizes there is no single middle cell, so this rounds toward the maximum
+    /// (a width of `4` starting at `0` has its center at `2`). For empty bounds, this is `min`.
+    pub fn center(&self) -> (i32, i32, i32) {
+        (
+            (self.min.0 as i64 + (self.width() / 2) as i64) as i32,
+            (self.min.1 as i64 + (self.height() / 2) as i64) as i32,
+            (self.min.2 as i64 + (self.depth() / 2) as i64) as i32,
+        )
+    }
+
     /// The minumum bound along the `X` axis.
     pub fn x_min(&self) -> i32 {
         self.min.0
@@ -94,6 +183,144 @@ impl Bounds3D {
             && bz_min < az_max
     }
 
+    /// Get the overlapping region of two [Bounds3D], or `None` if they do not intersect.
+    pub fn intersection(self, other: Bounds3D) -> Option<Bounds3D> {
+        if !self.intersects(other) {
+            return None;
+        }
+        Some(Bounds3D {
+            min: (
+                self.min.0.max(other.min.0),
+                self.min.1.max(other.min.1),
+                self.min.2.max(other.min.2),
+            ),
+            max: (
+                self.max.0.min(other.max.0),
+                self.max.1.min(other.max.1),
+                self.max.2.min(other.max.2),
+            ),
+        })
+    }
+
+    /// Get the disjoint boxes that cover the volume of `self` that is not in `other`.
+    ///
+    /// At most six boxes are yielded: the full Y slabs above and below, the Z rows
+    /// between them, and the X cubes between those. Empty boxes are skipped.
+    pub fn difference(self, other: Bounds3D) -> impl Iterator<Item = Bounds3D> {
+        let regions = match self.intersection(other) {
+            None => [Some(self), None, None, None, None, None],
+            Some(inner) => [
+                // Y+ region
+                Some(Bounds3D::new(
+                    (self.min.0, inner.max.1, self.min.2),
+                    self.max,
+                )),
+                // Y- region
+                Some(Bounds3D::new(
+                    self.min,
+                    (self.max.0, inner.min.1, self.max.2),
+                )),
+                // Z+ region (row)
+                Some(Bounds3D::new(
+                    (self.min.0, inner.min.1, inner.max.2),
+                    (self.max.0, inner.max.1, self.max.2),
+                )),
+                // Z- region (row)
+                Some(Bounds3D::new(
+                    (self.min.0, inner.min.1, self.min.2),
+                    (self.max.0, inner.max.1, inner.min.2),
+                )),
+                // X+ region (cube)
+                Some(Bounds3D::new(
+                    (inner.max.0, inner.min.1, inner.min.2),
+                    (self.max.0, inner.max.1, inner.max.2),
+                )),
+                // X- region (cube)
+                Some(Bounds3D::new(
+                    (self.min.0, inner.min.1, inner.min.2),
+                    (inner.min.0, inner.max.1, inner.max.2),
+                )),
+            ],
+        };
+        regions
+            .into_iter()
+            .flatten()
+            .filter(|region| region.volume() > 0)
+    }
+
+    /// Split the [Bounds3D] into the boxes on either side of `x`.
+    ///
+    /// The first box is `min.0..x` and the second is `x..max.0`, so together they
+    /// tile `self` exactly. `x` is clamped to `min.0..=max.0`, so splitting outside of
+    /// the bounds results in one of the boxes being empty.
+    pub fn split_x(self, x: i32) -> (Bounds3D, Bounds3D) {
+        let x = x.clamp(self.min.0, self.max.0.max(self.min.0));
+        (
+            Bounds3D::new(self.min, (x, self.max.1, self.max.2)),
+            Bounds3D::new((x, self.min.1, self.min.2), self.max),
+        )
+    }
+
+    /// Split the [Bounds3D] into the boxes on either side of `y`.
+    ///
+    /// See [split_x](Self::split_x).
+    pub fn split_y(self, y: i32) -> (Bounds3D, Bounds3D) {
+        let y = y.clamp(self.min.1, self.max.1.max(self.min.1));
+        (
+            Bounds3D::new(self.min, (self.max.0, y, self.max.2)),
+            Bounds3D::new((self.min.0, y, self.min.2), self.max),
+        )
+    }
+
+    /// Split the [Bounds3D] into the boxes on either side of `z`.
+    ///
+    /// See [split_x](Self::split_x).
+    pub fn split_z(self, z: i32) -> (Bounds3D, Bounds3D) {
+        let z = z.clamp(self.min.2, self.max.2.max(self.min.2));
+        (
+            Bounds3D::new(self.min, (self.max.0, self.max.1, z)),
+            Bounds3D::new((self.min.0, self.min.1, z), self.max),
+        )
+    }
+
+    /// Partition the [Bounds3D] into eight boxes around `pivot`.
+    ///
+    /// The octant at index `i` is on the maximum side of the pivot along X if bit `0`
+    /// of `i` is set, along Y if bit `1` is set, and along Z if bit `2` is set. This is
+    /// the same indexing as [corners](Self::corners).
+    /// The pivot is clamped to the bounds like in [split_x](Self::split_x), so when it
+    /// lies on a face, the octants on the far side are empty (zero volume) rather than
+    /// causing a panic.
+    pub fn octants(self, pivot: (i32, i32, i32)) -> [Bounds3D; 8] {
+        let (x_lo, x_hi) = self.split_x(pivot.0);
+        let x_halves = [x_lo, x_hi];
+        std::array::from_fn(|i| {
+            let (y_lo, y_hi) = x_halves[i & 1].split_y(pivot.1);
+            let (z_lo, z_hi) = if i & 2 == 0 { y_lo } else { y_hi }.split_z(pivot.2);
+            if i & 4 == 0 {
+                z_lo
+            } else {
+                z_hi
+            }
+        })
+    }
+
+    /// Get the smallest [Bounds3D] that contains both `self` and `other`.
+    pub fn union(self, other: Bounds3D) -> Bounds3D {
+        Bounds3D {
+            min: (
+                self.min.0.min(other.min.0),
+                self.min.1.min(other.min.1),
+                self.min.2.min(other.min.2),
+            ),
+            max: (
+                self.max.0.max(other.max.0),
+                self.max.1.max(other.max.1),
+                self.max.2.max(other.max.2),
+            ),
+        }
+    }
+
     /// Determine if a point is within the [Bounds3D].
     pub fn contains(self, point: (i32, i32, i32)) -> bool {
         point.0 >= self.min.0
@@ -104,55 +331,1214 @@ impl Bounds3D {
             && point.2 < self.max.2
     }
 
-    /// Iterate over the points in the [Bounds3D].
+    /// Map `point` into the [Bounds3D] by wrapping each component modulo the size of the
+    /// bounds, as if the bounds were tiled infinitely. Points within the bounds are
+    /// unchanged.
+    ///
+    /// Panics if the bounds are empty.
+    pub fn wrap(self, point: (i32, i32, i32)) -> (i32, i32, i32) {
+        wrap_coord_3d(point, self.min, (self.width(), self.height(), self.depth()))
+    }
+
+    /// Get the cell in the [Bounds3D] that is closest to `point`, which is `point` itself
+    /// if it is within the bounds.
+    ///
+    /// Each component is clamped to `min..=max - 1`, so the result is always a cell within
+    /// the bounds. Empty bounds have no cells, so each empty axis clamps to `min`.
+    pub fn clamp_point(self, point: (i32, i32, i32)) -> (i32, i32, i32) {
+        (
+            point
+                .0
+                .clamp(self.min.0, self.max.0.saturating_sub(1).max(self.min.0)),
+            point
+                .1
+                .clamp(self.min.1, self.max.1.saturating_sub(1).max(self.min.1)),
+            point
+                .2
+                .clamp(self.min.2, self.max.2.saturating_sub(1).max(self.min.2)),
+        )
+    }
+
+    /// Get the squared distance from `point` to the closest cell in the [Bounds3D]
+    /// (see [clamp_point](Self::clamp_point)). This is `0` for points within the bounds.
+    ///
+    /// The result saturates at [u64::MAX].
+    pub fn distance_squared_to(self, point: (i32, i32, i32)) -> u64 {
+        let closest = self.clamp_point(point);
+        let dx = (point.0 as i64 - closest.0 as i64).unsigned_abs();
+        let dy = (point.1 as i64 - closest.1 as i64).unsigned_abs();
+        let dz = (point.2 as i64 - closest.2 as i64).unsigned_abs();
+        (dx * dx).saturating_add(dy * dy).saturating_add(dz * dz)
+    }
+
+    /// Get the [Bounds3D] grown by `inflate` on each side of each axis.
+    ///
+    /// This gives the same bounds as [RollGrid3D::inflate_size](crate::rollgrid3d::RollGrid3D::inflate_size).
+    pub fn inflated(self, inflate: (u32, u32, u32)) -> Self {
+        let sub = |min: i32, amount: u32| min.checked_sub_unsigned(amount).expect(INFLATE_OVERFLOW);
+        let add = |max: i32, amount: u32| max.checked_add_unsigned(amount).expect(INFLATE_OVERFLOW);
+        Self {
+            min: (
+                sub(self.min.0, inflate.0),
+                sub(self.min.1, inflate.1),
+                sub(self.min.2, inflate.2),
+            ),
+            max: (
+                add(self.max.0, inflate.0),
+                add(self.max.1, inflate.1),
+                add(self.max.2, inflate.2),
+            ),
+        }
+    }
+
+    /// Get the [Bounds3D] shrunk by `deflate` on each side of each axis.
+    ///
+    /// This gives the same bounds as [RollGrid3D::deflate_size](crate::rollgrid3d::RollGrid3D::deflate_size).
+    ///
+    /// Panics if the result would have no volume.
+    pub fn deflated(self, deflate: (u32, u32, u32)) -> Self {
+        if deflate.0 as u64 * 2 >= self.width() as u64
+            || deflate.1 as u64 * 2 >= self.height() as u64
+            || deflate.2 as u64 * 2 >= self.depth() as u64
+        {
+            panic!("{VOLUME_IS_ZERO}");
+        }
+        // The checks above guarantee that these operations can't overflow.
+        let min = (
+            self.min.0 + deflate.0 as i32,
+            self.min.1 + deflate.1 as i32,
+            self.min.2 + deflate.2 as i32,
+        );
+        let max = (
+            self.max.0 - deflate.0 as i32,
+            self.max.1 - deflate.1 as i32,
+            self.max.2 - deflate.2 as i32,
+        );
+        Self { min, max }
+    }
+
+    /// Get the [Bounds3D] shifted by `offset`.
+    ///
+    /// Panics if either bound would overflow on any axis.
+    pub fn translated(self, offset: (i32, i32, i32)) -> Self {
+        let shift = |min: i32, max: i32, offset: i32, min_msg: &str, max_msg: &str| match (
+            min.checked_add(offset),
+            max.checked_add(offset),
+        ) {
+            (Some(min), Some(max)) => (min, max),
+            _ if offset < 0 => panic!("{min_msg}"),
+            _ => panic!("{max_msg}"),
+        };
+        let (x_min, x_max) = shift(
+            self.min.0,
+            self.max.0,
+            offset.0,
+            X_MIN_EXCEEDS_MINIMUM,
+            X_MAX_EXCEEDS_MAXIMUM,
+        );
+        let (y_min, y_max) = shift(
+            self.min.1,
+            self.max.1,
+            offset.1,
+            Y_MIN_EXCEEDS_MINIMUM,
+            Y_MAX_EXCEEDS_MAXIMUM,
+        );
+        let (z_min, z_max) = shift(
+            self.min.2,
+            self.max.2,
+            offset.2,
+            Z_MIN_EXCEEDS_MINIMUM,
+            Z_MAX_EXCEEDS_MAXIMUM,
+        );
+        Self {
+            min: (x_min, y_min, z_min),
+            max: (x_max, y_max, z_max),
+        }
+    }
+
+    /// Shift the [Bounds3D] by `offset` in place.
+    ///
+    /// Panics if either bound would overflow on any axis.
+    pub fn translate(&mut self, offset: (i32, i32, i32)) {
+        *self = self.translated(offset);
+    }
+
+    /// Project the [Bounds3D] onto the XZ plane, dropping the Y axis.
+    pub fn xz_footprint(self) -> Bounds2D {
+        Bounds2D::new((self.min.0, self.min.2), (self.max.0, self.max.2))
+    }
+
+    /// Multiply both bounds by `factor`, for example to convert chunk coordinates into
+    /// cell coordinates.
+    ///
+    /// Panics if any bound would overflow.
+    pub fn scaled_up(self, factor: (u32, u32, u32)) -> Bounds3D {
+        let x = |value| scale_component(value, factor.0, Axis::X);
+        let y = |value| scale_component(value, factor.1, Axis::Y);
+        let z = |value| scale_component(value, factor.2, Axis::Z);
+        Bounds3D {
+            min: (x(self.min.0), y(self.min.1), z(self.min.2)),
+            max: (x(self.max.0), y(self.max.1), z(self.max.2)),
+        }
+    }
+
+    /// Divide both bounds by `factor` using floor division, so negative coordinates
+    /// round toward negative infinity. This is the inverse of [scaled_up](Self::scaled_up).
+    ///
+    /// Unaligned maximum bounds are floored as well, so the result may not cover all of `self`.
+    ///
+    /// Panics if any dimension of `factor` is zero.
+    pub fn scaled_down(self, factor: (u32, u32, u32)) -> Bounds3D {
+        if factor.0 == 0 || factor.1 == 0 || factor.2 == 0 {
+            panic!("{VOLUME_IS_ZERO}");
+        }
+        let axis = |value: i32, factor: u32| (value as i64).div_euclid(factor as i64) as i32;
+        Bounds3D {
+            min: (
+                axis(self.min.0, factor.0),
+                axis(self.min.1, factor.1),
+                axis(self.min.2, factor.2),
+            ),
+            max: (
+                axis(self.max.0, factor.0),
+                axis(self.max.1, factor.1),
+                axis(self.max.2, factor.2),
+            ),
+        }
+    }
+
+    /// Get the eight corner cells of the [Bounds3D], using `max - 1` for the maximum corners.
+    ///
+    /// The corner at index `i` is on the maximum side along X if bit `0` of `i` is set,
+    /// along Y if bit `1` is set, and along Z if bit `2` is set, so the order starts with
+    /// `min`, `+X`, `+Y`, `+X+Y`, `+Z`. This is the same indexing as [octants](Self::octants).
+    /// A bounds with a size of `1` on an axis has the same coordinate on both sides of it.
+    /// Empty bounds have no cells, so their corners are clamped like
+    /// [clamp_point](Self::clamp_point).
+    pub fn corners(self) -> [(i32, i32, i32); 8] {
+        let (lo, hi) = (self.min, self.clamp_point(self.max));
+        std::array::from_fn(|i| {
+            (
+                if i & 1 == 0 { lo.0 } else { hi.0 },
+                if i & 2 == 0 { lo.1 } else { hi.1 },
+                if i & 4 == 0 { lo.2 } else { hi.2 },
+            )
+        })
+    }
+
+    /// Iterate the rows of the [Bounds3D] in `z -> y` order (matching the `x -> z -> y`
+    /// storage order of the grids), yielding each row's `(y, z)` coordinate along with
+    /// the range of `x` coordinates it covers.
+    ///
+    /// Empty bounds yield nothing.
+    pub fn iter_rows(self) -> impl Iterator<Item = ((i32, i32), std::ops::Range<i32>)> {
+        let x_range = self.min.0..self.max.0;
+        let z_range = self.min.2..self.max.2;
+        let y_range = if x_range.is_empty() {
+            0..0
+        } else {
+            self.min.1..self.max.1
+        };
+        y_range.flat_map(move |y| {
+            let x_range = x_range.clone();
+            z_range.clone().map(move |z| ((y, z), x_range.clone()))
+        })
+    }
+
+    /// Iterate the cells on the six faces of the [Bounds3D] without visiting the interior.
+    /// Each cell is yielded exactly once, including along shared edges and corners.
+    pub fn iter_shell(self) -> impl Iterator<Item = (i32, i32, i32)> {
+        let (y_min, y_max) = if self.volume() > 0 {
+            (self.min.1, self.max.1)
+        } else {
+            (self.min.1, self.min.1)
+        };
+        let plane = Bounds2D::new((self.min.0, self.min.2), (self.max.0, self.max.2));
+        (y_min..y_max).flat_map(move |y| {
+            // The bottom and top layers are whole faces, the layers between are rings.
+            let (face, ring) = if y == y_min || y == y_max - 1 {
+                (Some(plane.iter()), None)
+            } else {
+                (None, Some(plane.iter_border()))
+            };
+            face.into_iter()
+                .flatten()
+                .chain(ring.into_iter().flatten())
+                .map(move |(x, z)| (x, y, z))
+        })
+    }
+
+    /// Iterate the sub-bounds produced by clipping the [Bounds3D] against a grid of
+    /// `chunk_size` cells aligned to the origin. Partial chunks at the edges are clipped
+    /// to `self`.
+    ///
+    /// Panics if any dimension of `chunk_size` is zero.
+    pub fn iter_chunks(self, chunk_size: (u32, u32, u32)) -> impl Iterator<Item = Bounds3D> {
+        if chunk_size.0 == 0 || chunk_size.1 == 0 || chunk_size.2 == 0 {
+            panic!("{VOLUME_IS_ZERO}");
+        }
+        let size = (
+            chunk_size.0 as i64,
+            chunk_size.1 as i64,
+            chunk_size.2 as i64,
+        );
+        // Floor division so that negative coordinates map to negative chunks.
+        let chunk_min = |value: i32, size: i64| (value as i64).div_euclid(size) as i32;
+        let chunk_max = |value: i32, size: i64| ((value as i64 - 1).div_euclid(size) + 1) as i32;
+        let chunks = if self.volume() > 0 {
+            Some(Bounds3D::new(
+                (
+                    chunk_min(self.min.0, size.0),
+                    chunk_min(self.min.1, size.1),
+                    chunk_min(self.min.2, size.2),
+                ),
+                (
+                    chunk_max(self.max.0, size.0),
+                    chunk_max(self.max.1, size.1),
+                    chunk_max(self.max.2, size.2),
+                ),
+            ))
+        } else {
+            None
+        };
+        let clip = move |chunk: i32, size: i64, min: i32, max: i32| {
+            let start = chunk as i64 * size;
+            (
+                start.max(min as i64) as i32,
+                (start + size).min(max as i64) as i32,
+            )
+        };
+        chunks
+            .into_iter()
+            .flat_map(Bounds3D::iter)
+            .map(move |(chunk_x, chunk_y, chunk_z)| {
+                let (x_min, x_max) = clip(chunk_x, size.0, self.min.0, self.max.0);
+                let (y_min, y_max) = clip(chunk_y, size.1, self.min.1, self.max.1);
+                let (z_min, z_max) = clip(chunk_z, size.2, self.min.2, self.max.2);
+                Bounds3D {
+                    min: (x_min, y_min, z_min),
+                    max: (x_max, y_max, z_max),
+                }
+            })
+    }
+
+    /// Iterate over the points in the [Bounds3D] in x, then z, then y order.
+    /// This is the same order that grid cells are stored in.
     pub fn iter(self) -> Bounds3DIter {
-        Bounds3DIter {
-            bounds: self,
-            current: self.min,
+        self.iter_xzy()
+    }
+
+    /// Iterate over the points in the [Bounds3D] in x, then z, then y order.
+    /// This is the same order as [Bounds3D::iter].
+    pub fn iter_xzy(self) -> Bounds3DIter {
+        Bounds3DIter::new(self)
+    }
+
+    /// Iterate over the points in the [Bounds3D] in x, then y, then z order.
+    pub fn iter_xyz(self) -> Bounds3DIterXYZ {
+        Bounds3DIterXYZ::new(self)
+    }
+
+    /// Iterate over the points in the [Bounds3D] in z, then y, then x order.
+    pub fn iter_zyx(self) -> Bounds3DIterZYX {
+        Bounds3DIterZYX::new(self)
+    }
+}
+
+impl std::fmt::Display for Bounds3D {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}..{:?}", self.min, self.max)
+    }
+}
+
+/// Creates an iterator type over the points of a [Bounds3D] where `$fast` is
+/// the index of the axis that changes most often and `$slow` the least.
+macro_rules! bounds3d_iter {
+    ($(#[$attr:meta])* $name:ident => $fast:tt, $mid:tt, $slow:tt) => {
+        $(#[$attr])*
+        pub struct $name {
+            bounds: Bounds3D,
+            /// The next point yielded by `next`.
+            front: (i32, i32, i32),
+            /// The next point yielded by `next_back`.
+            back: (i32, i32, i32),
+            /// The number of points between `front` and `back` (inclusive).
+            remaining: u128,
+        }
+
+        impl $name {
+            fn new(bounds: Bounds3D) -> Self {
+                let remaining = bounds.volume() as u128;
+                Self {
+                    bounds,
+                    front: bounds.min,
+                    back: if remaining > 0 {
+                        (bounds.max.0 - 1, bounds.max.1 - 1, bounds.max.2 - 1)
+                    } else {
+                        bounds.min
+                    },
+                    remaining,
+                }
+            }
+        }
+
+        impl Iterator for $name {
+            type Item = (i32, i32, i32);
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match usize::try_from(self.remaining) {
+                    Ok(remaining) => (remaining, Some(remaining)),
+                    Err(_) => (usize::MAX, None),
+                }
+            }
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.remaining == 0 {
+                    return None;
+                }
+                self.remaining -= 1;
+                let result = self.front;
+                if self.remaining == 0 {
+                    return Some(result);
+                }
+                let (min, max) = (self.bounds.min, self.bounds.max);
+                if self.front.$fast + 1 < max.$fast {
+                    self.front.$fast += 1;
+                } else if self.front.$mid + 1 < max.$mid {
+                    self.front.$fast = min.$fast;
+                    self.front.$mid += 1;
+                } else {
+                    self.front.$fast = min.$fast;
+                    self.front.$mid = min.$mid;
+                    self.front.$slow += 1;
+                }
+                Some(result)
+            }
+        }
+
+        impl DoubleEndedIterator for $name {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.remaining == 0 {
+                    return None;
+                }
+                self.remaining -= 1;
+                let result = self.back;
+                if self.remaining == 0 {
+                    return Some(result);
+                }
+                let (min, max) = (self.bounds.min, self.bounds.max);
+                if self.back.$fast > min.$fast {
+                    self.back.$fast -= 1;
+                } else if self.back.$mid > min.$mid {
+                    self.back.$fast = max.$fast - 1;
+                    self.back.$mid -= 1;
+                } else {
+                    self.back.$fast = max.$fast - 1;
+                    self.back.$mid = max.$mid - 1;
+                    self.back.$slow -= 1;
+                }
+                Some(result)
+            }
         }
+
+        impl ExactSizeIterator for $name {}
+
+        impl std::iter::FusedIterator for $name {}
+    };
+}
+
+bounds3d_iter!(
+    /// Iterator for all points within a [Bounds3D].
+    ///
+    /// Points are yielded in x, then z, then y order.
+    Bounds3DIter => 0, 2, 1
+);
+
+bounds3d_iter!(
+    /// Iterator for all points within a [Bounds3D].
+    ///
+    /// Points are yielded in x, then y, then z order.
+    Bounds3DIterXYZ => 0, 1, 2
+);
+
+bounds3d_iter!(
+    /// Iterator for all points within a [Bounds3D].
+    ///
+    /// Points are yielded in z, then y, then x order.
+    Bounds3DIterZYX => 2, 1, 0
+);
+
+/// Iterate the coordinates in the [Bounds3D], which allows using it directly in a `for` loop.
+///
+/// ```
+/// use rollgrid::bounds3d::Bounds3D;
+///
+/// let bounds = Bounds3D::new((0, 0, 0), (2, 1, 2));
+/// let mut visited = Vec::new();
+/// for pos in &bounds {
+///     visited.push(pos);
+/// }
+/// assert_eq!(visited, [(0, 0, 0), (1, 0, 0), (0, 0, 1), (1, 0, 1)]);
+/// assert_eq!(bounds.into_iter().count(), 4);
+/// ```
+impl IntoIterator for Bounds3D {
+    type Item = <Bounds3DIter as Iterator>::Item;
+    type IntoIter = Bounds3DIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
 
-/// Iterator for all points within a [Bounds3D].
-pub struct Bounds3DIter {
-    bounds: Bounds3D,
-    current: (i32, i32, i32),
+impl IntoIterator for &Bounds3D {
+    type Item = <Bounds3DIter as Iterator>::Item;
+    type IntoIter = Bounds3DIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
 }
 
-impl Iterator for Bounds3DIter {
-    type Item = (i32, i32, i32);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cell_manager, rollgrid3d::RollGrid3D};
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        if self.current.2 == self.bounds.max.2 {
-            return (0, Some(0));
+    #[test]
+    fn try_new_is_empty_test() {
+        assert_eq!(
+            Bounds3D::try_new((0, 0, 0), (2, 3, 4)),
+            Ok(Bounds3D::new((0, 0, 0), (2, 3, 4)))
+        );
+        assert_eq!(
+            Bounds3D::try_new((3, 0, 0), (2, 3, 4)),
+            Err(BoundsError::InvertedX)
+        );
+        assert_eq!(
+            Bounds3D::try_new((0, 4, 0), (2, 3, 4)),
+            Err(BoundsError::InvertedY)
+        );
+        assert_eq!(
+            Bounds3D::try_new((0, 0, 5), (2, 3, 4)),
+            Err(BoundsError::InvertedZ)
+        );
+        assert!(!Bounds3D::new((0, 0, 0), (1, 1, 1)).is_empty());
+        assert!(Bounds3D::new((0, 0, 0), (1, 1, 0)).is_empty());
+        assert!(Bounds3D::new((0, 0, 5), (4, 4, 1)).is_empty());
+        assert_eq!(Bounds3D::new((0, 0, 5), (4, 4, 1)).volume(), 0);
+    }
+
+    #[test]
+    fn inverted_iter_fuzz_test() {
+        let mut state = 0x2545F4914F6CDD1Du64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % 9) as i32 - 4
+        };
+        for _ in 0..500 {
+            let bounds = Bounds3D::new((next(), next(), next()), (next(), next(), next()));
+            let (min, max) = (bounds.min, bounds.max);
+            let expected = if max.0 > min.0 && max.1 > min.1 && max.2 > min.2 {
+                ((max.0 - min.0) * (max.1 - min.1) * (max.2 - min.2)) as usize
+            } else {
+                0
+            };
+            assert_eq!(bounds.is_empty(), expected == 0);
+            assert_eq!(
+                Bounds3D::try_new(min, max).is_ok(),
+                min.0 <= max.0 && min.1 <= max.1 && min.2 <= max.2
+            );
+            assert_eq!(bounds.iter().len(), expected);
+            assert_eq!(bounds.iter().count(), expected);
+            assert_eq!(bounds.iter().rev().count(), expected);
+            assert_eq!(bounds.iter_xyz().count(), expected);
+            assert_eq!(bounds.iter_zyx().count(), expected);
+            assert!(bounds.iter().all(|pos| bounds.contains(pos)));
+        }
+    }
+
+    #[test]
+    fn inflated_test() {
+        let offsets = [
+            (0, 0, 0),
+            (-3, 5, -7),
+            (i32::MIN + 3, i32::MIN + 4, i32::MIN + 5),
+            (i32::MAX - 6, i32::MAX - 7, i32::MAX - 8),
+        ];
+        for offset in offsets {
+            for (size, inflate) in [((1, 1, 1), (1, 1, 1)), ((2, 3, 1), (0, 2, 1))] {
+                let mut grid = RollGrid3D::new(size.0, size.1, size.2, offset, |_| ());
+                let inflated = grid.bounds().inflated(inflate);
+                grid.inflate_size(
+                    (inflate.0 as usize, inflate.1 as usize, inflate.2 as usize),
+                    cell_manager(|_| (), |_, _| (), |_, _, _| ()),
+                );
+                assert_eq!(inflated, grid.bounds());
+            }
         }
-        let (x, y, z) = (
-            (self.current.0 - self.bounds.min.0) as usize,
-            (self.current.1 - self.bounds.min.1) as usize,
-            (self.current.2 - self.bounds.min.2) as usize,
+    }
+
+    #[test]
+    fn deflated_test() {
+        for offset in [(0, 0, 0), (-3, 5, -7), (i32::MIN, i32::MIN, i32::MIN)] {
+            let mut grid = RollGrid3D::new(5, 6, 7, offset, |_| ());
+            let deflated = grid.bounds().deflated((2, 1, 3));
+            grid.deflate_size((2, 1, 3), cell_manager(|_| (), |_, _| (), |_, _, _| ()));
+            assert_eq!(deflated, grid.bounds());
+        }
+    }
+
+    #[test]
+    fn intersection_test() {
+        let a = Bounds3D::new((0, 0, 0), (4, 4, 4));
+        let b = Bounds3D::new((2, -1, 3), (6, 3, 8));
+        assert_eq!(a.intersection(b), Some(Bounds3D::new((2, 0, 3), (4, 3, 4))));
+        assert_eq!(a.intersection(b), b.intersection(a));
+        // touching faces do not intersect.
+        assert_eq!(a.intersection(Bounds3D::new((0, 0, 4), (4, 4, 6))), None);
+        assert_eq!(a.intersection(Bounds3D::new((5, 5, 5), (6, 6, 6))), None);
+    }
+
+    #[test]
+    fn difference_test() {
+        use std::collections::HashSet;
+        let base = Bounds3D::new((0, 0, 0), (5, 4, 6));
+        let others = [
+            // partial overlaps
+            Bounds3D::new((2, -1, 3), (7, 3, 8)),
+            Bounds3D::new((-2, 1, -1), (3, 6, 2)),
+            // nested
+            Bounds3D::new((1, 1, 1), (3, 3, 4)),
+            // containing
+            Bounds3D::new((-1, -1, -1), (6, 5, 7)),
+            // touching faces
+            Bounds3D::new((5, 0, 0), (7, 4, 6)),
+            // disjoint
+            Bounds3D::new((10, 10, 10), (12, 12, 12)),
+            // equal
+            base,
+            // slab through the middle
+            Bounds3D::new((-3, 1, -3), (8, 2, 9)),
+        ];
+        for other in others {
+            for (a, b) in [(base, other), (other, base)] {
+                let regions = a.difference(b).collect::<Vec<_>>();
+                assert!(regions.len() <= 6);
+                let mut covered = HashSet::new();
+                for region in regions.iter() {
+                    assert!(region.volume() > 0);
+                    for pos in region.iter() {
+                        // each point must be covered by exactly one region.
+                        assert!(covered.insert(pos));
+                    }
+                }
+                let expected = a
+                    .iter()
+                    .filter(|&pos| !b.contains(pos))
+                    .collect::<HashSet<_>>();
+                assert_eq!(covered, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn iter_chunks_test() {
+        let cases = [
+            // straddling zero, unaligned on both ends
+            (Bounds3D::new((-20, -3, -17), (19, 16, 1)), (16, 16, 16)),
+            // aligned
+            (Bounds3D::new((-16, 0, 16), (16, 16, 48)), (16, 16, 16)),
+            // smaller than one chunk
+            (Bounds3D::new((-5, -5, -5), (-3, -4, -1)), (16, 16, 16)),
+            (Bounds3D::new((-7, 2, -11), (9, 11, 4)), (3, 5, 4)),
+        ];
+        for (bounds, chunk_size) in cases {
+            let chunks = bounds.iter_chunks(chunk_size).collect::<Vec<_>>();
+            let total: i128 = chunks.iter().map(|chunk| chunk.volume()).sum();
+            assert_eq!(total, bounds.volume());
+            let aligned = |value: i32, size: u32| value.div_euclid(size as i32);
+            for (i, chunk) in chunks.iter().enumerate() {
+                assert!(chunk.volume() > 0);
+                assert_eq!(chunk.intersection(bounds), Some(*chunk));
+                // every chunk lies within a single aligned chunk.
+                assert_eq!(
+                    aligned(chunk.min.0, chunk_size.0),
+                    aligned(chunk.max.0 - 1, chunk_size.0)
+                );
+                assert_eq!(
+                    aligned(chunk.min.1, chunk_size.1),
+                    aligned(chunk.max.1 - 1, chunk_size.1)
+                );
+                assert_eq!(
+                    aligned(chunk.min.2, chunk_size.2),
+                    aligned(chunk.max.2 - 1, chunk_size.2)
+                );
+                for other in chunks.iter().skip(i + 1) {
+                    assert!(!chunk.intersects(*other));
+                }
+            }
+        }
+        let small = Bounds3D::new((-5, -5, -5), (-3, -4, -1));
+        assert_eq!(
+            small.iter_chunks((16, 16, 16)).collect::<Vec<_>>(),
+            vec![small]
         );
-        let width = self.bounds.width() as usize;
-        let depth = self.bounds.depth() as usize;
-        let volume = self.bounds.volume() as usize;
-        let index = y * width * depth + z * width + x;
-        (volume - index, Some(volume - index))
+        let straddle = Bounds3D::new((-1, 0, 0), (1, 1, 1));
+        assert_eq!(
+            straddle.iter_chunks((16, 16, 16)).collect::<Vec<_>>(),
+            vec![
+                Bounds3D::new((-1, 0, 0), (0, 1, 1)),
+                Bounds3D::new((0, 0, 0), (1, 1, 1)),
+            ]
+        );
+        let empty = Bounds3D::new((3, 0, 0), (3, 10, 10));
+        assert_eq!(empty.iter_chunks((16, 16, 16)).count(), 0);
     }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.current.1 == self.bounds.max.1 {
-            return None;
+    #[test]
+    #[should_panic(expected = "Width/Height/Depth cannot be 0")]
+    fn iter_chunks_zero_size_test() {
+        let _ = Bounds3D::new((0, 0, 0), (4, 4, 4)).iter_chunks((16, 16, 0));
+    }
+
+    #[test]
+    fn exact_size_empty_test() {
+        for bounds in [
+            Bounds3D::new((0, 0, 0), (0, 4, 4)),
+            Bounds3D::new((0, 0, 0), (4, 0, 4)),
+            Bounds3D::new((0, 0, 0), (4, 4, 0)),
+        ] {
+            let mut iter = bounds.iter();
+            assert_eq!(iter.len(), 0);
+            assert_eq!(iter.next(), None);
         }
-        let result = self.current;
-        // inc x, then z, then y
-        self.current = if result.0 + 1 == self.bounds.max.0 {
-            if result.2 + 1 == self.bounds.max.2 {
-                (self.bounds.min.0, result.1 + 1, self.bounds.min.2)
+    }
+
+    #[test]
+    fn exact_size_test() {
+        let bounds = Bounds3D::new((-2, 3, 0), (3, 7, 2));
+        let mut iter = bounds.iter();
+        let mut remaining = 40;
+        assert_eq!(iter.len(), remaining);
+        while iter.next().is_some() {
+            remaining -= 1;
+            assert_eq!(iter.len(), remaining);
+        }
+        assert_eq!(remaining, 0);
+        let mut count = 0;
+        for pos in bounds {
+            assert!(bounds.contains(pos));
+            count += 1;
+        }
+        assert_eq!(count, 40);
+        assert_eq!(
+            bounds.into_iter().collect::<Vec<_>>(),
+            bounds.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn double_ended_test() {
+        let bounds = Bounds3D::new((0, 0, 0), (2, 3, 2));
+        let forward = bounds.iter().collect::<Vec<_>>();
+        let mut backward = bounds.iter().rev().collect::<Vec<_>>();
+        assert_eq!(forward.len(), 12);
+        assert_eq!(forward[1], (1, 0, 0));
+        assert_eq!(forward[2], (0, 0, 1));
+        backward.reverse();
+        assert_eq!(forward, backward);
+        // alternating ends never yields a point twice.
+        let mut iter = Bounds3D::new((-1, 2, 5), (2, 4, 7)).iter();
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+        while let Some(pos) = iter.next() {
+            front.push(pos);
+            let Some(pos) = iter.next_back() else {
+                break;
+            };
+            back.push(pos);
+        }
+        back.reverse();
+        front.extend(back);
+        assert_eq!(
+            front,
+            Bounds3D::new((-1, 2, 5), (2, 4, 7))
+                .iter()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn scaled_test() {
+        let bounds = Bounds3D::new((-2, 0, -1), (3, 4, 1));
+        let scaled = bounds.scaled_up((16, 16, 16));
+        assert_eq!(scaled, Bounds3D::new((-32, 0, -16), (48, 64, 16)));
+        assert_eq!(scaled.scaled_down((16, 16, 16)), bounds);
+        assert_eq!(bounds.scaled_up((2, 3, 4)).scaled_down((2, 3, 4)), bounds);
+        let bounds = Bounds3D::new((-17, -1, -16), (-3, 15, 17));
+        assert_eq!(
+            bounds.scaled_down((16, 16, 16)),
+            Bounds3D::new((-2, -1, -1), (-1, 0, 1))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Z minimum bound exceeds i32::MIN")]
+    fn scaled_up_overflow_test() {
+        Bounds3D::new((0, 0, i32::MIN / 16 - 1), (1, 1, 1)).scaled_up((16, 16, 16));
+    }
+
+    #[test]
+    fn xz_footprint_test() {
+        let bounds = Bounds3D::new((-2, 5, 3), (4, 9, 7));
+        assert_eq!(bounds.xz_footprint(), Bounds2D::new((-2, 3), (4, 7)));
+        assert_eq!(
+            bounds.xz_footprint().area() * bounds.height() as i64,
+            bounds.volume() as i64
+        );
+    }
+
+    #[test]
+    fn corners_test() {
+        let bounds = Bounds3D::new((-2, 3, 0), (4, 7, 5));
+        assert_eq!(
+            bounds.corners(),
+            [
+                (-2, 3, 0),
+                (3, 3, 0),
+                (-2, 6, 0),
+                (3, 6, 0),
+                (-2, 3, 4),
+                (3, 3, 4),
+                (-2, 6, 4),
+                (3, 6, 4),
+            ]
+        );
+        assert!(bounds
+            .corners()
+            .iter()
+            .all(|corner| bounds.contains(*corner)));
+        let single = Bounds3D::new((5, -5, 1), (6, -4, 2));
+        assert_eq!(single.corners(), [(5, -5, 1); 8]);
+    }
+
+    #[test]
+    fn corners_octants_test() {
+        let bounds = Bounds3D::new((-2, 3, 0), (4, 7, 5));
+        for pivot in [(0, 5, 2), (1, 4, 1), (3, 6, 4)] {
+            let octants = bounds.octants(pivot);
+            for (octant, corner) in octants.iter().zip(bounds.corners()) {
+                assert!(octant.contains(corner), "{octant} {corner:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn iter_rows_test() {
+        let bounds = Bounds3D::new((-2, 3, 0), (4, 5, 3));
+        let rows: Vec<_> = bounds.iter_rows().collect();
+        assert_eq!(rows.len(), 2 * 3);
+        assert_eq!(rows[0], ((3, 0), -2..4));
+        assert_eq!(rows[1], ((3, 1), -2..4));
+        assert_eq!(rows[3], ((4, 0), -2..4));
+        assert_eq!(
+            rows.iter().map(|(_, xs)| xs.len() as i128).sum::<i128>(),
+            bounds.volume()
+        );
+        assert!(bounds
+            .iter_rows()
+            .flat_map(|((y, z), xs)| xs.map(move |x| (x, y, z)))
+            .eq(bounds.iter()));
+        assert_eq!(Bounds3D::new((0, 0, 0), (0, 4, 4)).iter_rows().count(), 0);
+        assert_eq!(Bounds3D::new((0, 0, 0), (4, 0, 4)).iter_rows().count(), 0);
+        assert_eq!(Bounds3D::new((0, 0, 0), (4, 4, 0)).iter_rows().count(), 0);
+    }
+
+    #[test]
+    fn iter_shell_test() {
+        use std::collections::HashSet;
+        for (width, height, depth) in [
+            (1, 1, 1),
+            (2, 2, 2),
+            (1, 4, 3),
+            (3, 1, 5),
+            (4, 5, 2),
+            (5, 4, 6),
+        ] {
+            let bounds = Bounds3D::new((-2, 3, -1), (width - 2, height + 3, depth - 1));
+            let shell = bounds.iter_shell().collect::<Vec<_>>();
+            let unique = shell.iter().copied().collect::<HashSet<_>>();
+            assert_eq!(shell.len(), unique.len(), "{bounds}");
+            let inner_volume = (width - 2).max(0) * (height - 2).max(0) * (depth - 2).max(0);
+            assert_eq!(shell.len() as i128, bounds.volume() - inner_volume as i128);
+            if inner_volume > 0 {
+                let inner = bounds.deflated((1, 1, 1));
+                assert!(inner.iter().all(|pos| !unique.contains(&pos)));
+                let mut all = unique.clone();
+                all.extend(inner.iter());
+                assert_eq!(all, bounds.iter().collect::<HashSet<_>>());
             } else {
-                (self.bounds.min.0, result.1, result.2 + 1)
+                assert_eq!(unique, bounds.iter().collect::<HashSet<_>>());
             }
-        } else {
-            (result.0 + 1, result.1, result.2)
-        };
-        Some(result)
+        }
+        let empty = Bounds3D::new((0, 0, 0), (0, 5, 5));
+        assert_eq!(empty.iter_shell().count(), 0);
+    }
+
+    #[test]
+    fn size_hint_test() {
+        let boxes = [
+            Bounds3D::new((0, 0, 0), (1, 1, 1)),
+            Bounds3D::new((0, 0, 0), (2, 3, 4)),
+            Bounds3D::new((-3, 5, -1), (1, 6, 6)),
+            Bounds3D::new((4, -2, 7), (5, 3, 8)),
+            Bounds3D::new((-7, -5, -3), (-4, -1, 2)),
+        ];
+        for bounds in boxes {
+            let mut iter = bounds.iter();
+            let mut count = 0usize;
+            let expected = bounds.volume() as usize;
+            loop {
+                assert_eq!(iter.size_hint(), (expected - count, Some(expected - count)));
+                assert_eq!(iter.len(), expected - count);
+                let Some(pos) = iter.next() else {
+                    break;
+                };
+                assert!(bounds.contains(pos));
+                count += 1;
+            }
+            assert_eq!(count, expected);
+            // fused
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+            assert_eq!(iter.len(), 0);
+        }
+    }
+
+    #[test]
+    fn degenerate_iter_test() {
+        let degenerate = [
+            Bounds3D::new((0, 0, 0), (0, 5, 5)),
+            Bounds3D::new((0, 0, 0), (5, 0, 5)),
+            Bounds3D::new((0, 0, 0), (5, 5, 0)),
+            Bounds3D::new((3, 3, 3), (3, 3, 3)),
+            Bounds3D::new((i32::MIN, i32::MIN, i32::MIN), (i32::MIN, 0, 0)),
+        ];
+        for bounds in degenerate {
+            assert_eq!(bounds.volume(), 0);
+            let mut iter = bounds.iter();
+            assert_eq!(iter.len(), 0);
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+        }
+    }
+
+    #[test]
+    fn iter_order_test() {
+        let bounds = Bounds3D::new((0, 0, 0), (2, 3, 4));
+        let xzy = bounds.iter_xzy().collect::<Vec<_>>();
+        assert_eq!(xzy, bounds.iter().collect::<Vec<_>>());
+        assert_eq!(xzy[..3], [(0, 0, 0), (1, 0, 0), (0, 0, 1)]);
+        assert_eq!(xzy[8], (0, 1, 0));
+        let xyz = bounds.iter_xyz().collect::<Vec<_>>();
+        assert_eq!(xyz[..3], [(0, 0, 0), (1, 0, 0), (0, 1, 0)]);
+        assert_eq!(xyz[6], (0, 0, 1));
+        let zyx = bounds.iter_zyx().collect::<Vec<_>>();
+        assert_eq!(zyx[..3], [(0, 0, 0), (0, 0, 1), (0, 0, 2)]);
+        assert_eq!(zyx[4], (0, 1, 0));
+        assert_eq!(zyx[12], (1, 0, 0));
+        for order in [&xzy, &xyz, &zyx] {
+            assert_eq!(order.len(), 24);
+            assert_eq!(order.first(), Some(&(0, 0, 0)));
+            assert_eq!(order.last(), Some(&(1, 2, 3)));
+        }
+        let mut sorted = [xzy, xyz, zyx];
+        sorted.iter_mut().for_each(|order| order.sort());
+        assert_eq!(sorted[0], sorted[1]);
+        assert_eq!(sorted[0], sorted[2]);
+        let mut iter = bounds.iter_zyx();
+        assert_eq!(iter.len(), 24);
+        iter.nth(9);
+        assert_eq!(iter.size_hint(), (14, Some(14)));
+        assert_eq!(iter.next_back(), Some((1, 2, 3)));
+        assert_eq!(iter.next_back(), Some((1, 2, 2)));
+        assert_eq!(iter.len(), 12);
+        let mut iter = bounds.iter_xyz();
+        assert_eq!(iter.next_back(), Some((1, 2, 3)));
+        assert_eq!(iter.next_back(), Some((0, 2, 3)));
+        assert_eq!(iter.next_back(), Some((1, 1, 3)));
+    }
+
+    #[test]
+    fn split_test() {
+        let bounds = Bounds3D::new((-2, 3, 0), (5, 7, 4));
+        let (low, high) = bounds.split_x(1);
+        assert_eq!(low, Bounds3D::new((-2, 3, 0), (1, 7, 4)));
+        assert_eq!(high, Bounds3D::new((1, 3, 0), (5, 7, 4)));
+        let (low, high) = bounds.split_y(4);
+        assert_eq!(low, Bounds3D::new((-2, 3, 0), (5, 4, 4)));
+        assert_eq!(high, Bounds3D::new((-2, 4, 0), (5, 7, 4)));
+        let (low, high) = bounds.split_z(3);
+        assert_eq!(low, Bounds3D::new((-2, 3, 0), (5, 7, 3)));
+        assert_eq!(high, Bounds3D::new((-2, 3, 3), (5, 7, 4)));
+        assert_eq!(low.volume() + high.volume(), bounds.volume());
+        // splits at the edges and out of range splits are clamped
+        assert_eq!(bounds.split_x(-2).1, bounds);
+        assert_eq!(bounds.split_y(7).0, bounds);
+        assert_eq!(bounds.split_z(i32::MIN).0.volume(), 0);
+        assert_eq!(bounds.split_z(i32::MIN).1, bounds);
+        assert_eq!(bounds.split_x(100), bounds.split_x(5));
+    }
+
+    #[test]
+    fn octants_test() {
+        fn check(bounds: Bounds3D, pivot: (i32, i32, i32)) {
+            let octants = bounds.octants(pivot);
+            assert_eq!(
+                octants.iter().map(|octant| octant.volume()).sum::<i128>(),
+                bounds.volume()
+            );
+            for pos in bounds.iter() {
+                assert_eq!(
+                    octants.iter().filter(|octant| octant.contains(pos)).count(),
+                    1
+                );
+            }
+            for (i, a) in octants.iter().enumerate() {
+                for b in &octants[i + 1..] {
+                    assert!(!a.intersects(*b));
+                }
+            }
+        }
+        let bounds = Bounds3D::new((-2, 3, 0), (5, 7, 4));
+        check(bounds, (1, 5, 2));
+        check(bounds, (-2, 3, 0));
+        check(bounds, (5, 7, 4));
+        check(bounds, (100, -100, 2));
+        let octants = bounds.octants((1, 5, 2));
+        assert_eq!(octants[0], Bounds3D::new((-2, 3, 0), (1, 5, 2)));
+        assert_eq!(octants[1], Bounds3D::new((1, 3, 0), (5, 5, 2)));
+        assert_eq!(octants[2], Bounds3D::new((-2, 5, 0), (1, 7, 2)));
+        assert_eq!(octants[4], Bounds3D::new((-2, 3, 2), (1, 5, 4)));
+        assert_eq!(octants[7], Bounds3D::new((1, 5, 2), (5, 7, 4)));
+        // pivot on a face results in empty octants
+        let octants = bounds.octants((-2, 5, 2));
+        for i in [0, 2, 4, 6] {
+            assert_eq!(octants[i].volume(), 0);
+            assert_eq!(octants[i].iter().count(), 0);
+        }
+    }
+
+    #[test]
+    fn union_test() {
+        let a = Bounds3D::new((0, 0, 0), (4, 4, 4));
+        let b = Bounds3D::new((2, -1, 3), (6, 3, 8));
+        assert_eq!(a.union(b), Bounds3D::new((0, -1, 0), (6, 4, 8)));
+        let c = Bounds3D::new((10, 10, -10), (11, 12, -9));
+        assert_eq!(a.union(c), Bounds3D::new((0, 0, -10), (11, 12, 4)));
+        assert_eq!(a.union(c), c.union(a));
+    }
+
+    #[test]
+    fn display_test() {
+        let bounds = Bounds3D::new((-3, 0, 7), (4, 12, 8));
+        assert_eq!(bounds.to_string(), "(-3, 0, 7)..(4, 12, 8)");
+    }
+
+    #[test]
+    fn translated_test() {
+        let bounds = Bounds3D::new((-2, 3, 0), (4, 5, 1));
+        assert_eq!(
+            bounds.translated((3, -4, 7)),
+            Bounds3D::new((1, -1, 7), (7, 1, 8))
+        );
+        let mut to_max = bounds;
+        to_max.translate((i32::MAX - 4, i32::MAX - 5, i32::MAX - 1));
+        assert_eq!(to_max.max, (i32::MAX, i32::MAX, i32::MAX));
+        let to_min = bounds.translated((i32::MIN + 2, i32::MIN, i32::MIN));
+        assert_eq!(to_min.min, (i32::MIN, i32::MIN + 3, i32::MIN));
+    }
+
+    #[test]
+    #[should_panic(expected = "X maximum bound exceeds i32::MAX")]
+    fn translated_x_overflow_test() {
+        Bounds3D::new((0, 0, 0), (4, 4, 4)).translated((i32::MAX - 3, 0, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Y maximum bound exceeds i32::MAX")]
+    fn translated_y_overflow_test() {
+        Bounds3D::new((0, 0, 0), (4, 4, 4)).translated((0, i32::MAX - 3, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Z maximum bound exceeds i32::MAX")]
+    fn translated_z_overflow_test() {
+        Bounds3D::new((0, 0, 0), (4, 4, 4)).translated((0, 0, i32::MAX - 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "X minimum bound exceeds i32::MIN")]
+    fn translated_x_underflow_test() {
+        Bounds3D::new((-1, 0, 0), (4, 4, 4)).translated((i32::MIN, 0, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Y minimum bound exceeds i32::MIN")]
+    fn translated_y_underflow_test() {
+        Bounds3D::new((0, -1, 0), (4, 4, 4)).translated((0, i32::MIN, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Z minimum bound exceeds i32::MIN")]
+    fn translated_z_underflow_test() {
+        Bounds3D::new((0, 0, -1), (4, 4, 4)).translated((0, 0, i32::MIN));
+    }
+
+    #[test]
+    fn clamp_point_test() {
+        let bounds = Bounds3D::new((-2, 3, 0), (4, 7, 5));
+        assert_eq!(bounds.clamp_point((0, 5, 2)), (0, 5, 2));
+        assert_eq!(bounds.distance_squared_to((0, 5, 2)), 0);
+        // beyond each face
+        assert_eq!(bounds.clamp_point((-5, 5, 2)), (-2, 5, 2));
+        assert_eq!(bounds.clamp_point((4, 5, 2)), (3, 5, 2));
+        assert_eq!(bounds.clamp_point((0, 0, 2)), (0, 3, 2));
+        assert_eq!(bounds.clamp_point((0, 7, 2)), (0, 6, 2));
+        assert_eq!(bounds.clamp_point((0, 5, -1)), (0, 5, 0));
+        assert_eq!(bounds.clamp_point((0, 5, 5)), (0, 5, 4));
+        assert_eq!(bounds.distance_squared_to((-5, 5, 2)), 9);
+        assert_eq!(bounds.distance_squared_to((4, 5, 2)), 1);
+        assert_eq!(bounds.distance_squared_to((0, 0, 2)), 9);
+        assert_eq!(bounds.distance_squared_to((0, 7, 2)), 1);
+        assert_eq!(bounds.distance_squared_to((0, 5, -1)), 1);
+        assert_eq!(bounds.distance_squared_to((0, 5, 9)), 25);
+        // beyond each of the corners
+        for i in 0..8 {
+            let high = (i & 1 != 0, i & 2 != 0, i & 4 != 0);
+            let point = (
+                if high.0 { 10 } else { -10 },
+                if high.1 { 10 } else { -10 },
+                if high.2 { 10 } else { -10 },
+            );
+            let corner = (
+                if high.0 { 3 } else { -2 },
+                if high.1 { 6 } else { 3 },
+                if high.2 { 4 } else { 0 },
+            );
+            assert_eq!(bounds.clamp_point(point), corner);
+            assert!(bounds.contains(bounds.clamp_point(point)));
+            let (dx, dy, dz) = (
+                (point.0 - corner.0).unsigned_abs() as u64,
+                (point.1 - corner.1).unsigned_abs() as u64,
+                (point.2 - corner.2).unsigned_abs() as u64,
+            );
+            assert_eq!(
+                bounds.distance_squared_to(point),
+                dx * dx + dy * dy + dz * dz
+            );
+        }
+        // a dimension of size 1 clamps onto the single valid coordinate
+        let flat = Bounds3D::new((0, 8, 0), (4, 9, 4));
+        assert_eq!(flat.clamp_point((2, -100, 2)), (2, 8, 2));
+        assert_eq!(flat.clamp_point((2, 100, 2)), (2, 8, 2));
+        assert_eq!(flat.clamp_point((2, 9, 2)), (2, 8, 2));
+    }
+
+    #[test]
+    fn center_test() {
+        assert_eq!(Bounds3D::new((0, 0, 0), (1, 1, 1)).center(), (0, 0, 0));
+        assert_eq!(Bounds3D::new((0, -2, 3), (5, 1, 10)).center(), (2, -1, 6));
+        // even sizes round toward the maximum
+        assert_eq!(Bounds3D::new((0, -2, 1), (4, 2, 3)).center(), (2, 0, 2));
+        assert_eq!(
+            Bounds3D::new((-3, -3, -3), (-3, -3, -3)).center(),
+            (-3, -3, -3)
+        );
+        assert_eq!(
+            Bounds3D::new(
+                (i32::MIN, i32::MIN, i32::MIN),
+                (i32::MAX, i32::MAX, i32::MAX)
+            )
+            .center(),
+            (-1, -1, -1)
+        );
+    }
+
+    #[test]
+    fn from_center_size_test() {
+        assert_eq!(
+            Bounds3D::from_center_size((2, -1, 6), (5, 3, 7)),
+            Bounds3D::new((0, -2, 3), (5, 1, 10))
+        );
+        assert_eq!(
+            Bounds3D::from_center_size((2, 0, 2), (4, 4, 2)),
+            Bounds3D::new((0, -2, 1), (4, 2, 3))
+        );
+        // a (2r+1)^3 box around a chunk
+        let r = 3u32;
+        let bounds = Bounds3D::from_center_size((10, -4, 0), (2 * r + 1, 2 * r + 1, 2 * r + 1));
+        assert_eq!(bounds, Bounds3D::new((7, -7, -3), (14, 0, 4)));
+        for size in [(1, 1, 1), (2, 3, 4), (4, 4, 4), (7, 2, 5)] {
+            let bounds = Bounds3D::from_center_size((-5, 9, 0), size);
+            assert_eq!((bounds.width(), bounds.height(), bounds.depth()), size);
+            assert_eq!(bounds.center(), (-5, 9, 0));
+        }
+        // near the limits
+        let bounds = Bounds3D::from_center_size((i32::MAX - 2, i32::MIN + 2, 0), (4, 5, 6));
+        assert_eq!(
+            bounds,
+            Bounds3D::new((i32::MAX - 4, i32::MIN, -3), (i32::MAX, i32::MIN + 5, 3))
+        );
+        assert_eq!(bounds.center(), (i32::MAX - 2, i32::MIN + 2, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "X maximum bound exceeds i32::MAX")]
+    fn from_center_size_x_overflow_test() {
+        Bounds3D::from_center_size((i32::MAX - 1, 0, 0), (4, 4, 4));
+    }
+
+    #[test]
+    #[should_panic(expected = "Y minimum bound exceeds i32::MIN")]
+    fn from_center_size_y_underflow_test() {
+        Bounds3D::from_center_size((0, i32::MIN + 1, 0), (4, 5, 4));
+    }
+
+    #[test]
+    #[should_panic(expected = "Z maximum bound exceeds i32::MAX")]
+    fn from_center_size_z_overflow_test() {
+        Bounds3D::from_center_size((0, 0, i32::MAX - 1), (4, 4, 4));
+    }
+
+    #[test]
+    #[should_panic(expected = "Inflate operation results in integer overflow")]
+    fn inflated_overflow_test() {
+        Bounds3D::new((0, 0, i32::MAX - 1), (1, 1, i32::MAX)).inflated((0, 0, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Width/Height/Depth cannot be 0")]
+    fn deflated_zero_volume_test() {
+        Bounds3D::new((0, 0, 0), (4, 4, 4)).deflated((1, 2, 1));
+    }
+
+    #[test]
+    fn wrap_test() {
+        let bounds = Bounds3D::new((-2, 3, 0), (2, 6, 1));
+        assert_eq!(bounds.wrap((0, 4, 0)), (0, 4, 0));
+        assert_eq!(bounds.wrap((-3, 2, -1)), (1, 5, 0));
+        assert_eq!(bounds.wrap((10, -9, 7)), (-2, 3, 0));
+        assert!(bounds.iter().all(|pos| bounds.wrap(pos) == pos));
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn from_min_max_test() {
+        use glam::IVec3;
+        let bounds = Bounds3D::from_min_max(IVec3::new(-1, 2, 0), IVec3::new(3, 4, 5));
+        assert_eq!(bounds, Bounds3D::new((-1, 2, 0), (3, 4, 5)));
     }
 }
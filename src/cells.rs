@@ -1,5 +1,5 @@
-use crate::{bounds2d::Bounds2D, bounds3d::Bounds3D, constants::*};
-use std::{mem::ManuallyDrop, ptr::NonNull};
+use crate::{bounds2d::Bounds2D, bounds3d::Bounds3D, constants::*, math::OffsetSize};
+use std::{marker::PhantomData, mem::ManuallyDrop, ptr::NonNull};
 
 /// An array of type `T`.
 /// This is an abstraction over the memory meant to be used in rolling grid
@@ -13,6 +13,17 @@ pub struct FixedArray<T> {
 }
 
 impl<T> FixedArray<T> {
+    /// Allocates memory for `layout`. Zero-sized layouts (such as arrays of a zero-sized
+    /// type) get a dangling pointer instead, since allocating zero bytes is undefined behavior.
+    #[inline(always)]
+    unsafe fn allocate(layout: std::alloc::Layout) -> NonNull<T> {
+        if layout.size() == 0 {
+            NonNull::dangling()
+        } else {
+            NonNull::new(std::alloc::alloc(layout) as *mut T).expect("Null pointer.")
+        }
+    }
+
     #[inline(always)]
     fn prealloc_2d(size: (usize, usize), offset: (i32, i32)) -> (NonNull<T>, Bounds2D, usize) {
         let (width, height) = size;
@@ -23,16 +34,15 @@ impl<T> FixedArray<T> {
         if area > i32::MAX as usize {
             panic!("{}", SIZE_TOO_LARGE);
         }
-        if offset.0.checked_add(width as i32).is_none()
-            || offset.1.checked_add(height as i32).is_none()
-        {
-            panic!("{}", OFFSET_TOO_CLOSE_TO_MAX);
-        }
+        // The area check guarantees that each axis fits in an i32.
+        let max = offset
+            .add_size((width as u32, height as u32))
+            .expect(OFFSET_TOO_CLOSE_TO_MAX);
         unsafe {
             let layout = Self::make_layout(area).expect("Failed to create layout.");
             (
-                NonNull::new(std::alloc::alloc(layout) as *mut T).expect("Null pointer."),
-                Bounds2D::new(offset, (offset.0 + width as i32, offset.1 + height as i32)),
+                Self::allocate(layout),
+                Bounds2D::try_new(offset, max).expect(INVERTED_BOUNDS),
                 area,
             )
         }
@@ -55,24 +65,15 @@ impl<T> FixedArray<T> {
         if volume > i32::MAX as usize {
             panic!("{SIZE_TOO_LARGE}");
         }
-        if offset.0.checked_add(width as i32).is_none()
-            || offset.1.checked_add(height as i32).is_none()
-            || offset.2.checked_add(depth as i32).is_none()
-        {
-            panic!("{OFFSET_TOO_CLOSE_TO_MAX}");
-        }
+        // The volume check guarantees that each axis fits in an i32.
+        let max = offset
+            .add_size((width as u32, height as u32, depth as u32))
+            .expect(OFFSET_TOO_CLOSE_TO_MAX);
         unsafe {
             let layout = Self::make_layout(volume).expect("Failed to create layout.");
             (
-                NonNull::new(std::alloc::alloc(layout) as *mut T).expect("Null pointer."),
-                Bounds3D::new(
-                    offset,
-                    (
-                        offset.0 + width as i32,
-                        offset.1 + height as i32,
-                        offset.2 + depth as i32,
-                    ),
-                ),
+                Self::allocate(layout),
+                Bounds3D::try_new(offset, max).expect(INVERTED_BOUNDS),
                 volume,
             )
         }
@@ -118,13 +119,20 @@ impl<T> FixedArray<T> {
         mut init: F,
     ) -> Result<Self, E> {
         let (ptr, bounds, capacity) = Self::prealloc_2d(size, offset);
-        bounds.iter().enumerate().try_for_each(|(i, pos)| {
+        let mut initialized = 0;
+        let result = bounds.iter().try_for_each(|pos| {
             unsafe {
-                let item = ptr.add(i);
-                std::ptr::write(item.as_ptr(), init(pos)?);
+                std::ptr::write(ptr.add(initialized).as_ptr(), init(pos)?);
             }
+            initialized += 1;
             Ok(())
-        })?;
+        });
+        if let Err(err) = result {
+            unsafe {
+                Self::free_partial(ptr, initialized, capacity);
+            }
+            return Err(err);
+        }
         Ok(Self {
             ptr: Some(ptr),
             capacity,
@@ -179,19 +187,70 @@ impl<T> FixedArray<T> {
         mut init: F,
     ) -> Result<Self, E> {
         let (ptr, bounds, capacity) = Self::prealloc_3d(size, offset);
-        bounds.iter().enumerate().try_for_each(|(i, pos)| {
+        let mut initialized = 0;
+        let result = bounds.iter().try_for_each(|pos| {
             unsafe {
-                let item = ptr.add(i);
-                std::ptr::write(item.as_ptr(), init(pos)?);
+                std::ptr::write(ptr.add(initialized).as_ptr(), init(pos)?);
             }
+            initialized += 1;
             Ok(())
-        })?;
+        });
+        if let Err(err) = result {
+            unsafe {
+                Self::free_partial(ptr, initialized, capacity);
+            }
+            return Err(err);
+        }
         Ok(Self {
             ptr: Some(ptr),
             capacity,
         })
     }
 
+    /// Drop the first `initialized` items of a buffer allocated for `capacity` items, then
+    /// free the buffer. Used when initialization fails partway through.
+    unsafe fn free_partial(ptr: NonNull<T>, initialized: usize, capacity: usize) {
+        (0..initialized).for_each(|i| std::ptr::drop_in_place(ptr.add(i).as_ptr()));
+        let layout = Self::make_layout(capacity).expect("Failed to create layout.");
+        if layout.size() != 0 {
+            std::alloc::dealloc(ptr.as_ptr() as *mut u8, layout);
+        }
+    }
+
+    /// Create a [FixedArray] that has no buffer, the same as one that has been deallocated.
+    pub(crate) fn unallocated() -> Self {
+        Self {
+            ptr: None,
+            capacity: 0,
+        }
+    }
+
+    /// Create a [FixedArray] from a pointer to `capacity` initialized values.
+    ///
+    /// # Safety
+    /// `data` must have been allocated with the global allocator using the layout
+    /// of an array of `capacity` values of `T` (as with `Box<[T]>`), and every value
+    /// must be initialized. Ownership of the allocation is transferred to the [FixedArray].
+    pub unsafe fn from_raw(data: NonNull<T>, capacity: usize) -> Self {
+        Self {
+            ptr: Some(data),
+            capacity,
+        }
+    }
+
+    /// Create a [FixedArray] from a pointer to `capacity` initialized values,
+    /// returning an error if `capacity` does not produce a valid layout.
+    ///
+    /// # Safety
+    /// See [FixedArray::from_raw]. Only the layout is validated, not the allocation itself.
+    pub unsafe fn from_raw_checked(
+        data: NonNull<T>,
+        capacity: usize,
+    ) -> Result<Self, std::alloc::LayoutError> {
+        Self::make_layout(capacity)?;
+        Ok(Self::from_raw(data, capacity))
+    }
+
     /// Deallocates the internal buffer in this [FixedArray].
     pub unsafe fn dealloc(&mut self) {
         self.internal_dealloc(true);
@@ -208,7 +267,9 @@ impl<T> FixedArray<T> {
                     });
                 }
                 let layout = self.layout();
-                std::alloc::dealloc(ptr.as_ptr() as *mut u8, layout);
+                if layout.size() != 0 {
+                    std::alloc::dealloc(ptr.as_ptr() as *mut u8, layout);
+                }
             }
         }
     }
@@ -267,22 +328,54 @@ impl<T> FixedArray<T> {
         self.capacity
     }
 
+    /// Gets the number of elements the array has allocated space for.
+    ///
+    /// The array never over-allocates, so this is always equal to [len](Self::len).
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
     /// Returns the array as a slice.
+    ///
+    /// An unallocated array returns an empty slice.
     pub fn as_slice(&self) -> &[T] {
         let Some(ptr) = self.ptr else {
-            panic!("Not allocated.");
+            return &[];
         };
         unsafe { std::slice::from_raw_parts(ptr.as_ref(), self.capacity) }
     }
 
     /// Returns the array as a mutable slice.
+    ///
+    /// An unallocated array returns an empty slice.
     pub fn as_mut_slice(&mut self) -> &mut [T] {
         let Some(mut ptr) = self.ptr else {
-            panic!("Not allocated.");
+            return &mut [];
         };
         unsafe { std::slice::from_raw_parts_mut(ptr.as_mut(), self.capacity) }
     }
 
+    /// Splits `0..len` into `n` contiguous ranges of near-equal size.
+    ///
+    /// If `n` is greater than `len`, the trailing ranges are empty. If `n` is zero, no ranges
+    /// are returned.
+    pub fn work_ranges(&self, n: usize) -> Vec<std::ops::Range<usize>> {
+        if n == 0 {
+            return Vec::new();
+        }
+        let len = self.capacity;
+        let (base, extra) = (len / n, len % n);
+        let mut start = 0;
+        (0..n)
+            .map(|i| {
+                let end = start + base + (i < extra) as usize;
+                let range = start..end;
+                start = end;
+                range
+            })
+            .collect()
+    }
+
     /// Returns the internal pointer. This may return `null` if the buffer has already been deallocated.
     pub unsafe fn as_ptr(&self) -> *const T {
         self.ptr
@@ -298,7 +391,7 @@ impl<T> FixedArray<T> {
     /// Converts the array into a boxed slice.
     pub fn into_boxed_slice(self) -> Box<[T]> {
         let Some(ptr) = self.ptr else {
-            panic!("Not allocated.");
+            return Box::new([]);
         };
         unsafe {
             let slice_ptr = std::ptr::slice_from_raw_parts_mut(ptr.as_ptr(), self.capacity);
@@ -311,7 +404,7 @@ impl<T> FixedArray<T> {
     /// Converts the array into a `Vec<T>`.
     pub fn into_vec(self) -> Vec<T> {
         let Some(ptr) = self.ptr else {
-            panic!("Not allocated.");
+            return Vec::new();
         };
         unsafe {
             let result = Vec::from_raw_parts(ptr.as_ptr(), self.capacity, self.capacity);
@@ -326,6 +419,15 @@ impl<T> FixedArray<T> {
             index: 0,
         }
     }
+
+    pub fn iter_mut(&mut self) -> FixedArrayMutIterator<'_, T> {
+        FixedArrayMutIterator {
+            ptr: self.ptr,
+            capacity: self.capacity,
+            index: 0,
+            _phantom: PhantomData,
+        }
+    }
 }
 
 pub struct FixedArrayRefIterator<'a, T> {
@@ -346,6 +448,29 @@ impl<'a, T> Iterator for FixedArrayRefIterator<'a, T> {
     }
 }
 
+/// Iterator over mutable references to the items of a [FixedArray].
+pub struct FixedArrayMutIterator<'a, T> {
+    ptr: Option<NonNull<T>>,
+    capacity: usize,
+    index: usize,
+    _phantom: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for FixedArrayMutIterator<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ptr = self.ptr?;
+        if self.index == self.capacity {
+            return None;
+        }
+        // Each index is only yielded once, so the mutable references never alias.
+        let result = unsafe { Some(ptr.add(self.index).as_mut()) };
+        self.index += 1;
+        result
+    }
+}
+
 impl<T> IntoIterator for FixedArray<T> {
     type IntoIter = FixedArrayIterator<T>;
     type Item = T;
@@ -405,6 +530,68 @@ impl<T> From<FixedArray<T>> for Box<[T]> {
     }
 }
 
+impl FixedArray<()> {
+    /// Allocate a new [FixedArray] of unit values from a 3D size and offset.
+    ///
+    /// `()` has no bytes to write, so unlike [new_3d](Self::new_3d) this skips the
+    /// per-cell initialization entirely.
+    pub fn new_zst_3d(size: (usize, usize, usize), offset: (i32, i32, i32)) -> Self {
+        let (ptr, _, capacity) = Self::prealloc_3d(size, offset);
+        Self {
+            ptr: Some(ptr),
+            capacity,
+        }
+    }
+}
+
+impl<T: Clone> Clone for FixedArray<T> {
+    fn clone(&self) -> Self {
+        let Some(source) = self.ptr else {
+            return Self::unallocated();
+        };
+        /// Frees the cells written so far and the buffer if a clone panics.
+        struct PanicGuard<T> {
+            ptr: NonNull<T>,
+            written: usize,
+            capacity: usize,
+        }
+        impl<T> Drop for PanicGuard<T> {
+            fn drop(&mut self) {
+                unsafe { FixedArray::free_partial(self.ptr, self.written, self.capacity) }
+            }
+        }
+        unsafe {
+            let layout = Self::make_layout(self.capacity).expect("Failed to create layout.");
+            let mut guard = PanicGuard {
+                ptr: Self::allocate(layout),
+                written: 0,
+                capacity: self.capacity,
+            };
+            while guard.written < self.capacity {
+                let i = guard.written;
+                std::ptr::write(guard.ptr.add(i).as_ptr(), source.add(i).as_ref().clone());
+                guard.written += 1;
+            }
+            let ptr = guard.ptr;
+            std::mem::forget(guard);
+            Self {
+                ptr: Some(ptr),
+                capacity: self.capacity,
+            }
+        }
+    }
+
+    /// Clone `source` into `self`, reusing the existing buffer when both arrays have the
+    /// same capacity. Otherwise the buffer is replaced with a new allocation.
+    fn clone_from(&mut self, source: &Self) {
+        if self.ptr.is_some() && source.ptr.is_some() && self.capacity == source.capacity {
+            self.as_mut_slice().clone_from_slice(source.as_slice());
+        } else {
+            *self = source.clone();
+        }
+    }
+}
+
 impl<T> std::ops::Deref for FixedArray<T> {
     type Target = [T];
     fn deref(&self) -> &Self::Target {
@@ -455,3 +642,95 @@ impl<T> Drop for FixedArray<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clone_from_test() {
+        use std::rc::Rc;
+        let counter = Rc::new(());
+        let source = FixedArray::new_2d((3, 2), (0, 0), |pos| (pos, counter.clone()));
+        let mut same = FixedArray::new_2d((2, 3), (5, 5), |pos| (pos, counter.clone()));
+        let mut different = FixedArray::new_2d((2, 2), (5, 5), |pos| (pos, counter.clone()));
+        assert_eq!(Rc::strong_count(&counter), 17);
+        let same_ptr = unsafe { same.as_ptr() };
+        same.clone_from(&source);
+        assert_eq!(unsafe { same.as_ptr() }, same_ptr);
+        different.clone_from(&source);
+        assert_eq!(different.len(), 6);
+        assert_eq!(Rc::strong_count(&counter), 19);
+        for array in [&same, &different, &source.clone()] {
+            assert!(array
+                .iter()
+                .map(|(pos, _)| *pos)
+                .eq(source.iter().map(|(pos, _)| *pos)));
+        }
+        drop((source, same, different));
+        assert_eq!(Rc::strong_count(&counter), 1);
+        // a panicking clone drops the cells that were already cloned.
+        struct PanicClone(Rc<()>, bool);
+        impl Clone for PanicClone {
+            fn clone(&self) -> Self {
+                if self.1 {
+                    panic!("clone failed");
+                }
+                PanicClone(self.0.clone(), self.1)
+            }
+        }
+        let source = FixedArray::new_2d((3, 2), (0, 0), |pos| {
+            PanicClone(counter.clone(), pos == (1, 1))
+        });
+        assert_eq!(Rc::strong_count(&counter), 7);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| source.clone()));
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&counter), 7);
+        drop(source);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn from_raw_checked_test() {
+        let boxed = vec![1u32, 2, 3].into_boxed_slice();
+        let data = NonNull::new(Box::into_raw(boxed) as *mut u32).unwrap();
+        let array = unsafe { FixedArray::from_raw_checked(data, 3) }.expect("Valid layout");
+        assert_eq!(array.as_slice(), &[1, 2, 3]);
+        assert_eq!(array.into_vec(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn from_raw_checked_overflow_test() {
+        let mut value = 0u32;
+        let data = NonNull::from(&mut value);
+        let result = unsafe { FixedArray::from_raw_checked(data, usize::MAX) };
+        assert!(result.is_err());
+        let result = unsafe { FixedArray::from_raw_checked(data, isize::MAX as usize / 2) };
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn zst_test() {
+        let array = FixedArray::new_zst_3d((64, 32, 64), (-5, 0, 5));
+        assert_eq!(array.len(), 64 * 32 * 64);
+        assert_eq!(array.iter().count(), array.len());
+        let array = FixedArray::new_3d((4, 4, 4), (0, 0, 0), |_| ());
+        assert_eq!(array.into_vec().len(), 64);
+    }
+
+    #[test]
+    fn iter_mut_test() {
+        let mut array = FixedArray::new_2d((4, 3), (0, 0), |(x, y)| x + y * 4);
+        array.iter_mut().for_each(|item| *item *= 10);
+        assert_eq!(array.iter_mut().count(), 12);
+        assert_eq!(
+            array.as_slice(),
+            &(0..12).map(|i| i * 10).collect::<Vec<_>>()[..]
+        );
+        let mut array = FixedArray::new_2d((3, 1), (0, 0), |_| String::from("a"));
+        for (i, item) in array.iter_mut().enumerate() {
+            item.push_str(&i.to_string());
+        }
+        assert_eq!(array.into_vec(), vec!["a0", "a1", "a2"]);
+    }
+}
@@ -0,0 +1,395 @@
+use crate::{bounds2d::*, cells::FixedArray, constants::*, math::OffsetSize, GridPos2};
+
+/// A 2D grid of fixed size and offset. Unlike [RollGrid2D](crate::rollgrid2d::RollGrid2D),
+/// the cells are always stored in coordinate order (`x -> y`).
+pub struct Grid2D<T> {
+    cells: FixedArray<T>,
+    size: (usize, usize),
+    offset: (i32, i32),
+}
+
+impl<T: Default> Grid2D<T> {
+    /// Create a new [Grid2D] with all the cells set to the default for `T`.
+    pub fn new_default(width: usize, height: usize, offset: (i32, i32)) -> Self {
+        Self::new(width, height, offset, |_| T::default())
+    }
+}
+
+impl<T> Grid2D<T> {
+    /// Create a new [Grid2D] using an initialize function to initialize cells.
+    ///
+    /// The init function should take as input the coordinate that is being
+    /// initialized, and should return the desired value for the cell.
+    pub fn new<F: FnMut((i32, i32)) -> T>(
+        width: usize,
+        height: usize,
+        offset: (i32, i32),
+        init: F,
+    ) -> Self {
+        Self {
+            cells: FixedArray::new_2d((width, height), offset, init),
+            size: (width, height),
+            offset,
+        }
+    }
+
+    /// Try to create a new [Grid2D] with a fallible init function.
+    ///
+    /// The init function should take as input the coordinate that is being
+    /// initialized, and should return the desired value for the cell.
+    pub fn try_new<E, F: FnMut((i32, i32)) -> Result<T, E>>(
+        width: usize,
+        height: usize,
+        offset: (i32, i32),
+        init: F,
+    ) -> Result<Self, E> {
+        Ok(Self {
+            cells: FixedArray::try_new_2d((width, height), offset, init)?,
+            size: (width, height),
+            offset,
+        })
+    }
+
+    /// Find the index of a coordinate in the backing storage.
+    fn offset_index(&self, coord: impl GridPos2) -> Option<usize> {
+        let (x, y) = coord.into_tuple();
+        if !self.bounds().contains((x, y)) {
+            return None;
+        }
+        let nx = (x - self.offset.0) as usize;
+        let ny = (y - self.offset.1) as usize;
+        Some(ny * self.size.0 + nx)
+    }
+
+    /// Get a reference to the cell's value if it exists.
+    pub fn get(&self, coord: impl GridPos2) -> Option<&T> {
+        let index = self.offset_index(coord)?;
+        Some(&self.cells[index])
+    }
+
+    /// Get a mutable reference to the cell's value if it exists.
+    pub fn get_mut(&mut self, coord: impl GridPos2) -> Option<&mut T> {
+        let index = self.offset_index(coord)?;
+        Some(&mut self.cells[index])
+    }
+
+    /// Set the cell's value, returning the old value in the process.
+    pub fn set(&mut self, coord: impl GridPos2, value: T) -> Option<T> {
+        let index = self.offset_index(coord)?;
+        Some(std::mem::replace(&mut self.cells[index], value))
+    }
+
+    /// Get the dimensions of the grid.
+    pub fn size(&self) -> (usize, usize) {
+        self.size
+    }
+
+    /// The size along the X axis.
+    pub fn width(&self) -> usize {
+        self.size.0
+    }
+
+    /// The size along the Y axis.
+    pub fn height(&self) -> usize {
+        self.size.1
+    }
+
+    /// Get the offset of the grid.
+    pub fn offset(&self) -> (i32, i32) {
+        self.offset
+    }
+
+    /// Get the bounds of the grid.
+    pub fn bounds(&self) -> Bounds2D {
+        Bounds2D {
+            min: self.offset,
+            max: self
+                .offset
+                .add_size((self.size.0 as u32, self.size.1 as u32))
+                .expect(OFFSET_TOO_CLOSE_TO_MAX),
+        }
+    }
+
+    /// This is equivalent to the area (width * height).
+    pub fn len(&self) -> usize {
+        self.size.0 * self.size.1
+    }
+
+    /// Returns true if the grid has no cells.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the cells of the grid as a slice in `x -> y` order.
+    pub fn as_slice(&self) -> &[T] {
+        self.cells.as_slice()
+    }
+
+    /// Get the cells of the grid as a mutable slice in `x -> y` order.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.cells.as_mut_slice()
+    }
+
+    /// Get an iterator over the cells in the grid.
+    pub fn iter(&self) -> impl Iterator<Item = ((i32, i32), &T)> {
+        self.bounds().iter().zip(self.cells.iter())
+    }
+
+    /// Get a mutable iterator over the cells in the grid.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((i32, i32), &mut T)> {
+        self.bounds().iter().zip(self.cells.iter_mut())
+    }
+
+    /// Iterate the rows of the grid from the minimum `Y` to the maximum. Each row yields
+    /// its cells from the minimum `X` to the maximum.
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        self.as_slice()
+            .chunks(self.size.0.max(1))
+            .map(|row| row.iter())
+    }
+
+    /// Iterate the columns of the grid from the minimum `X` to the maximum. Each column
+    /// yields its cells from the minimum `Y` to the maximum.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (width, cells) = (self.size.0, self.as_slice());
+        (0..width).map(move |x| cells[x..].iter().step_by(width))
+    }
+}
+
+/// Rotations and flips treat the grid like an image: rows go from the minimum `Y` at the
+/// top to the maximum `Y` at the bottom. The resulting grids keep the original offset,
+/// so their minimum is the same as the original's minimum.
+///
+/// Because the offset is kept, the methods that swap the width and height
+/// ([rotate_cw](Self::rotate_cw), [rotate_ccw](Self::rotate_ccw) and
+/// [transpose](Self::transpose)) panic if the swapped size no longer fits between the
+/// offset and `i32::MAX`.
+impl<T: Clone> Grid2D<T> {
+    /// Get the cell at the local coordinate (relative to the offset).
+    fn get_local(&self, x: usize, y: usize) -> T {
+        self.cells[y * self.size.0 + x].clone()
+    }
+
+    /// Create a new grid with `size` at the same offset, using `source` to map each local
+    /// coordinate in the new grid to a local coordinate in this grid.
+    fn remap<F: Fn(usize, usize) -> (usize, usize)>(
+        &self,
+        size: (usize, usize),
+        source: F,
+    ) -> Self {
+        let (ox, oy) = self.offset;
+        Grid2D::new(size.0, size.1, self.offset, |(x, y)| {
+            let (sx, sy) = source((x - ox) as usize, (y - oy) as usize);
+            self.get_local(sx, sy)
+        })
+    }
+
+    /// Rotate the grid 90 degrees clockwise, swapping the width and height.
+    pub fn rotate_cw(&self) -> Self {
+        let (_, height) = self.size;
+        self.remap((self.size.1, self.size.0), |x, y| (y, height - 1 - x))
+    }
+
+    /// Rotate the grid 90 degrees counter-clockwise, swapping the width and height.
+    pub fn rotate_ccw(&self) -> Self {
+        let (width, _) = self.size;
+        self.remap((self.size.1, self.size.0), |x, y| (width - 1 - y, x))
+    }
+
+    /// Mirror the grid along the X axis, so that each row is reversed.
+    pub fn flip_x(&self) -> Self {
+        let (width, _) = self.size;
+        self.remap(self.size, |x, y| (width - 1 - x, y))
+    }
+
+    /// Mirror the grid along the Y axis, so that the order of the rows is reversed.
+    pub fn flip_y(&self) -> Self {
+        let (_, height) = self.size;
+        self.remap(self.size, |x, y| (x, height - 1 - y))
+    }
+
+    /// Swap the X and Y axes of the grid, swapping the width and height.
+    pub fn transpose(&self) -> Self {
+        self.remap((self.size.1, self.size.0), |x, y| (y, x))
+    }
+}
+
+/// Formats the size, offset, and cells of the grid, with the cells as a list of rows
+/// in coordinate order.
+impl<T: std::fmt::Debug> std::fmt::Debug for Grid2D<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows: Vec<Vec<&T>> = self
+            .bounds()
+            .iter_rows()
+            .map(|(y, xs)| xs.map(|x| self.get((x, y)).expect(OUT_OF_BOUNDS)).collect())
+            .collect();
+        f.debug_struct("Grid2D")
+            .field("size", &self.size)
+            .field("offset", &self.offset)
+            .field("cells", &rows)
+            .finish()
+    }
+}
+
+/// Grids are equal when they have the same size, offset, and cells at each coordinate.
+impl<T: PartialEq> PartialEq for Grid2D<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size
+            && self.offset == other.offset
+            && self.iter().zip(other.iter()).all(|((_, a), (_, b))| a == b)
+    }
+}
+
+impl<T: Eq> Eq for Grid2D<T> {}
+
+impl<T, C: GridPos2> std::ops::Index<C> for Grid2D<T> {
+    type Output = T;
+
+    /// Get a reference to the cell at `index`, panicking if it is out of bounds.
+    fn index(&self, index: C) -> &Self::Output {
+        let index = index.into_tuple();
+        self.get(index)
+            .unwrap_or_else(|| panic!("{OUT_OF_BOUNDS}: {index:?} is not in {}", self.bounds()))
+    }
+}
+
+impl<T, C: GridPos2> std::ops::IndexMut<C> for Grid2D<T> {
+    /// Get a mutable reference to the cell at `index`, panicking if it is out of bounds.
+    fn index_mut(&mut self, index: C) -> &mut Self::Output {
+        let index = index.into_tuple();
+        let cell_index = self
+            .offset_index(index)
+            .unwrap_or_else(|| panic!("{OUT_OF_BOUNDS}: {index:?} is not in {}", self.bounds()));
+        &mut self.cells[cell_index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid2d_test() {
+        let mut grid = Grid2D::new(3, 4, (-1, 5), |pos: (i32, i32)| pos);
+        assert_eq!(grid.len(), 12);
+        assert_eq!(grid.bounds(), Bounds2D::new((-1, 5), (2, 9)));
+        assert_eq!(grid.as_slice()[..4], [(-1, 5), (0, 5), (1, 5), (-1, 6)]);
+        grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+        assert_eq!(grid.get((1, 8)), Some(&(1, 8)));
+        assert_eq!(grid.get((2, 8)), None);
+        assert_eq!(grid.set((0, 6), (0, 0)), Some((0, 6)));
+        assert_eq!(grid[(0, 6)], (0, 0));
+        grid.iter_mut().for_each(|(pos, cell)| *cell = pos);
+        grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+    }
+
+    #[test]
+    fn grid_pos_test() {
+        let mut grid = Grid2D::new(2, 2, (1, 1), |pos: (i32, i32)| pos);
+        let (tuple, array) = ((2, 1), [1, 2]);
+        let (tuple_ref, array_ref) = (&tuple, &array);
+        assert_eq!(grid.get(tuple), Some(&(2, 1)));
+        assert_eq!(grid.get(array), Some(&(1, 2)));
+        assert_eq!(grid.get(tuple_ref), Some(&(2, 1)));
+        assert_eq!(grid.get(array_ref), Some(&(1, 2)));
+        *grid.get_mut([1, 2]).unwrap() = (0, 0);
+        assert_eq!(grid.set(array_ref, (5, 5)), Some((0, 0)));
+        assert_eq!(grid[[1, 2]], (5, 5));
+        grid[tuple_ref] = (6, 6);
+        assert_eq!(grid[(2, 1)], (6, 6));
+    }
+
+    #[test]
+    fn rows_columns_test() {
+        let grid = Grid2D::new(3, 2, (-1, 4), |(x, y)| x * 10 + y);
+        let rows: Vec<Vec<i32>> = grid.rows().map(|row| row.copied().collect()).collect();
+        assert_eq!(rows, vec![vec![-6, 4, 14], vec![-5, 5, 15]]);
+        let columns: Vec<Vec<i32>> = grid.columns().map(|col| col.copied().collect()).collect();
+        assert_eq!(columns, vec![vec![-6, -5], vec![4, 5], vec![14, 15]]);
+    }
+
+    #[test]
+    fn rotate_flip_test() {
+        let grid = Grid2D::new(3, 2, (-1, 4), |(x, y)| x * 10 + y);
+        let rows = |grid: &Grid2D<i32>| -> Vec<Vec<i32>> {
+            grid.rows().map(|row| row.copied().collect()).collect()
+        };
+        assert_eq!(rows(&grid), vec![vec![-6, 4, 14], vec![-5, 5, 15]]);
+        let cw = grid.rotate_cw();
+        assert_eq!(cw.size(), (2, 3));
+        assert_eq!(cw.offset(), (-1, 4));
+        assert_eq!(rows(&cw), vec![vec![-5, -6], vec![5, 4], vec![15, 14]]);
+        let ccw = grid.rotate_ccw();
+        assert_eq!(rows(&ccw), vec![vec![14, 15], vec![4, 5], vec![-6, -5]]);
+        assert_eq!(ccw.rotate_cw(), grid);
+        assert_eq!(grid.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), grid);
+        assert_eq!(
+            grid.rotate_ccw().rotate_ccw().rotate_ccw().rotate_ccw(),
+            grid
+        );
+        assert_eq!(grid.rotate_cw().rotate_cw(), grid.flip_x().flip_y());
+        assert_eq!(rows(&grid.flip_x()), vec![vec![14, 4, -6], vec![15, 5, -5]]);
+        assert_eq!(rows(&grid.flip_y()), vec![vec![-5, 5, 15], vec![-6, 4, 14]]);
+        assert_eq!(
+            rows(&grid.transpose()),
+            vec![vec![-6, -5], vec![4, 5], vec![14, 15]]
+        );
+        assert_eq!(grid.transpose().transpose(), grid);
+    }
+
+    #[test]
+    fn eq_test() {
+        let a = Grid2D::new(3, 2, (1, 1), |pos: (i32, i32)| pos);
+        let mut b = Grid2D::new(3, 2, (1, 1), |pos: (i32, i32)| pos);
+        assert_eq!(a, b);
+        b.set((2, 2), (0, 0));
+        assert_ne!(a, b);
+        assert_ne!(a, Grid2D::new(3, 2, (0, 1), |pos: (i32, i32)| pos));
+        assert_ne!(a, Grid2D::new(2, 3, (1, 1), |pos: (i32, i32)| pos));
+    }
+
+    #[test]
+    fn debug_test() {
+        let grid = Grid2D::new(2, 2, (1, -1), |(x, y)| x * 10 + y);
+        assert_eq!(
+            format!("{grid:?}"),
+            "Grid2D { size: (2, 2), offset: (1, -1), cells: [[9, 19], [10, 20]] }"
+        );
+    }
+
+    #[test]
+    fn max_bound_test() {
+        let grid = Grid2D::new(4, 3, (i32::MAX - 4, i32::MAX - 3), |pos: (i32, i32)| pos);
+        assert_eq!(grid.bounds().max, (i32::MAX, i32::MAX));
+        assert_eq!(grid.bounds().iter().count(), 12);
+        grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+    }
+
+    #[test]
+    #[should_panic(expected = "Offset is too close to maximum bound")]
+    fn offset_past_max_test() {
+        Grid2D::new(4, 3, (i32::MAX - 3, 0), |pos: (i32, i32)| pos);
+    }
+
+    #[test]
+    fn rotate_max_bound_test() {
+        let grid = Grid2D::new(2, 3, (i32::MAX - 3, i32::MAX - 3), |pos: (i32, i32)| pos);
+        let cw = grid.rotate_cw();
+        assert_eq!(cw.bounds().max, (i32::MAX, i32::MAX - 1));
+        assert_eq!(cw.transpose().bounds(), grid.bounds());
+    }
+
+    #[test]
+    #[should_panic(expected = "Offset is too close to maximum bound")]
+    fn rotate_past_max_test() {
+        let grid = Grid2D::new(1, 10, (i32::MAX - 1, 0), |pos: (i32, i32)| pos);
+        grid.rotate_cw();
+    }
+
+    #[test]
+    #[should_panic(expected = "Out of bounds")]
+    fn index_out_of_bounds_test() {
+        let grid = Grid2D::new(2, 2, (0, 0), |pos: (i32, i32)| pos);
+        let _ = grid[(0, 2)];
+    }
+}
@@ -0,0 +1,288 @@
+use crate::{bounds3d::*, cells::FixedArray, constants::*, math::OffsetSize, GridPos3};
+
+/// A 3D grid of fixed size and offset. Unlike [RollGrid3D](crate::rollgrid3d::RollGrid3D),
+/// the cells are always stored in coordinate order (`x -> z -> y`).
+pub struct Grid3D<T> {
+    cells: FixedArray<T>,
+    size: (usize, usize, usize),
+    offset: (i32, i32, i32),
+}
+
+impl<T: Default> Grid3D<T> {
+    /// Create a new [Grid3D] with all the cells set to the default for `T`.
+    pub fn new_default(width: usize, height: usize, depth: usize, offset: (i32, i32, i32)) -> Self {
+        Self::new(width, height, depth, offset, |_| T::default())
+    }
+}
+
+impl<T> Grid3D<T> {
+    /// Create a new [Grid3D] using an initialize function to initialize cells.
+    ///
+    /// The init function should take as input the coordinate that is being
+    /// initialized, and should return the desired value for the cell.
+    pub fn new<F: FnMut((i32, i32, i32)) -> T>(
+        width: usize,
+        height: usize,
+        depth: usize,
+        offset: (i32, i32, i32),
+        init: F,
+    ) -> Self {
+        Self {
+            cells: FixedArray::new_3d((width, height, depth), offset, init),
+            size: (width, height, depth),
+            offset,
+        }
+    }
+
+    /// Try to create a new [Grid3D] with a fallible init function.
+    ///
+    /// The init function should take as input the coordinate that is being
+    /// initialized, and should return the desired value for the cell.
+    pub fn try_new<E, F: FnMut((i32, i32, i32)) -> Result<T, E>>(
+        width: usize,
+        height: usize,
+        depth: usize,
+        offset: (i32, i32, i32),
+        init: F,
+    ) -> Result<Self, E> {
+        Ok(Self {
+            cells: FixedArray::try_new_3d((width, height, depth), offset, init)?,
+            size: (width, height, depth),
+            offset,
+        })
+    }
+
+    /// Find the index of a coordinate in the backing storage.
+    fn offset_index(&self, coord: impl GridPos3) -> Option<usize> {
+        let (x, y, z) = coord.into_tuple();
+        if !self.bounds().contains((x, y, z)) {
+            return None;
+        }
+        let nx = (x - self.offset.0) as usize;
+        let ny = (y - self.offset.1) as usize;
+        let nz = (z - self.offset.2) as usize;
+        let plane = self.size.0 * self.size.2;
+        Some(ny * plane + nz * self.size.0 + nx)
+    }
+
+    /// Get a reference to the cell's value if it exists.
+    pub fn get(&self, coord: impl GridPos3) -> Option<&T> {
+        let index = self.offset_index(coord)?;
+        Some(&self.cells[index])
+    }
+
+    /// Get a mutable reference to the cell's value if it exists.
+    pub fn get_mut(&mut self, coord: impl GridPos3) -> Option<&mut T> {
+        let index = self.offset_index(coord)?;
+        Some(&mut self.cells[index])
+    }
+
+    /// Set the cell's value, returning the old value in the process.
+    pub fn set(&mut self, coord: impl GridPos3, value: T) -> Option<T> {
+        let index = self.offset_index(coord)?;
+        Some(std::mem::replace(&mut self.cells[index], value))
+    }
+
+    /// Get the dimensions of the grid.
+    pub fn size(&self) -> (usize, usize, usize) {
+        self.size
+    }
+
+    /// The size along the X axis.
+    pub fn width(&self) -> usize {
+        self.size.0
+    }
+
+    /// The size along the Y axis.
+    pub fn height(&self) -> usize {
+        self.size.1
+    }
+
+    /// The size along the Z axis.
+    pub fn depth(&self) -> usize {
+        self.size.2
+    }
+
+    /// Get the offset of the grid.
+    pub fn offset(&self) -> (i32, i32, i32) {
+        self.offset
+    }
+
+    /// Get the bounds of the grid.
+    pub fn bounds(&self) -> Bounds3D {
+        Bounds3D {
+            min: self.offset,
+            max: self
+                .offset
+                .add_size((self.size.0 as u32, self.size.1 as u32, self.size.2 as u32))
+                .expect(OFFSET_TOO_CLOSE_TO_MAX),
+        }
+    }
+
+    /// This is equivalent to the volume (width * height * depth).
+    pub fn len(&self) -> usize {
+        self.size.0 * self.size.1 * self.size.2
+    }
+
+    /// Returns true if the grid has no cells.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the cells of the grid as a slice in `x -> z -> y` order.
+    pub fn as_slice(&self) -> &[T] {
+        self.cells.as_slice()
+    }
+
+    /// Get the cells of the grid as a mutable slice in `x -> z -> y` order.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.cells.as_mut_slice()
+    }
+
+    /// Get an iterator over the cells in the grid.
+    pub fn iter(&self) -> impl Iterator<Item = ((i32, i32, i32), &T)> {
+        self.bounds().iter().zip(self.cells.iter())
+    }
+
+    /// Get a mutable iterator over the cells in the grid.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((i32, i32, i32), &mut T)> {
+        self.bounds().iter().zip(self.cells.iter_mut())
+    }
+}
+
+/// Formats the size, offset, and cells of the grid, with the cells as a list of `Y`
+/// layers, each of which is a list of rows along the `X` axis in coordinate order.
+impl<T: std::fmt::Debug> std::fmt::Debug for Grid3D<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bounds = self.bounds();
+        let rows: Vec<Vec<&T>> = bounds
+            .iter_rows()
+            .map(|((y, z), xs)| {
+                xs.map(|x| self.get((x, y, z)).expect(OUT_OF_BOUNDS))
+                    .collect()
+            })
+            .collect();
+        let layers: Vec<&[Vec<&T>]> = rows.chunks(bounds.depth().max(1) as usize).collect();
+        f.debug_struct("Grid3D")
+            .field("size", &self.size)
+            .field("offset", &self.offset)
+            .field("cells", &layers)
+            .finish()
+    }
+}
+
+/// Grids are equal when they have the same size, offset, and cells at each coordinate.
+impl<T: PartialEq> PartialEq for Grid3D<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size
+            && self.offset == other.offset
+            && self.iter().zip(other.iter()).all(|((_, a), (_, b))| a == b)
+    }
+}
+
+impl<T: Eq> Eq for Grid3D<T> {}
+
+impl<T, C: GridPos3> std::ops::Index<C> for Grid3D<T> {
+    type Output = T;
+
+    /// Get a reference to the cell at `index`, panicking if it is out of bounds.
+    fn index(&self, index: C) -> &Self::Output {
+        let index = index.into_tuple();
+        self.get(index)
+            .unwrap_or_else(|| panic!("{OUT_OF_BOUNDS}: {index:?} is not in {}", self.bounds()))
+    }
+}
+
+impl<T, C: GridPos3> std::ops::IndexMut<C> for Grid3D<T> {
+    /// Get a mutable reference to the cell at `index`, panicking if it is out of bounds.
+    fn index_mut(&mut self, index: C) -> &mut Self::Output {
+        let index = index.into_tuple();
+        let cell_index = self
+            .offset_index(index)
+            .unwrap_or_else(|| panic!("{OUT_OF_BOUNDS}: {index:?} is not in {}", self.bounds()));
+        &mut self.cells[cell_index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid3d_test() {
+        let mut grid = Grid3D::new(3, 2, 4, (-1, 5, 2), |pos: (i32, i32, i32)| pos);
+        assert_eq!(grid.len(), 24);
+        assert_eq!(grid.bounds(), Bounds3D::new((-1, 5, 2), (2, 7, 6)));
+        assert_eq!(
+            grid.as_slice()[..4],
+            [(-1, 5, 2), (0, 5, 2), (1, 5, 2), (-1, 5, 3)]
+        );
+        grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+        assert_eq!(grid.get((1, 6, 5)), Some(&(1, 6, 5)));
+        assert_eq!(grid.get((2, 6, 5)), None);
+        assert_eq!(grid.set((0, 5, 3), (0, 0, 0)), Some((0, 5, 3)));
+        assert_eq!(grid[(0, 5, 3)], (0, 0, 0));
+        grid.iter_mut().for_each(|(pos, cell)| *cell = pos);
+        grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+    }
+
+    #[test]
+    fn grid_pos_test() {
+        let mut grid = Grid3D::new(2, 2, 2, (1, 1, 1), |pos: (i32, i32, i32)| pos);
+        let (tuple, array) = ((2, 1, 2), [1, 2, 1]);
+        let (tuple_ref, array_ref) = (&tuple, &array);
+        assert_eq!(grid.get(tuple), Some(&(2, 1, 2)));
+        assert_eq!(grid.get(array), Some(&(1, 2, 1)));
+        assert_eq!(grid.get(tuple_ref), Some(&(2, 1, 2)));
+        assert_eq!(grid.get(array_ref), Some(&(1, 2, 1)));
+        *grid.get_mut([1, 2, 1]).unwrap() = (0, 0, 0);
+        assert_eq!(grid.set(array_ref, (5, 5, 5)), Some((0, 0, 0)));
+        assert_eq!(grid[[1, 2, 1]], (5, 5, 5));
+        grid[tuple_ref] = (6, 6, 6);
+        assert_eq!(grid[(2, 1, 2)], (6, 6, 6));
+    }
+
+    #[test]
+    fn eq_test() {
+        let a = Grid3D::new(3, 2, 2, (1, 1, 1), |pos: (i32, i32, i32)| pos);
+        let mut b = Grid3D::new(3, 2, 2, (1, 1, 1), |pos: (i32, i32, i32)| pos);
+        assert_eq!(a, b);
+        b.set((2, 2, 2), (0, 0, 0));
+        assert_ne!(a, b);
+        assert_ne!(
+            a,
+            Grid3D::new(3, 2, 2, (1, 1, 0), |pos: (i32, i32, i32)| pos)
+        );
+    }
+
+    #[test]
+    fn debug_test() {
+        let grid = Grid3D::new(2, 2, 2, (0, 0, 0), |(x, y, z)| x + y * 100 + z * 10);
+        assert_eq!(
+            format!("{grid:?}"),
+            "Grid3D { size: (2, 2, 2), offset: (0, 0, 0), cells: [[[0, 1], [10, 11]], [[100, 101], [110, 111]]] }"
+        );
+    }
+
+    #[test]
+    fn max_bound_test() {
+        let offset = (i32::MAX - 2, i32::MAX - 3, i32::MAX - 4);
+        let grid = Grid3D::new(2, 3, 4, offset, |pos: (i32, i32, i32)| pos);
+        assert_eq!(grid.bounds().max, (i32::MAX, i32::MAX, i32::MAX));
+        assert_eq!(grid.bounds().iter().count(), 24);
+        grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+    }
+
+    #[test]
+    #[should_panic(expected = "Offset is too close to maximum bound")]
+    fn offset_past_max_test() {
+        Grid3D::new(2, 3, 4, (0, 0, i32::MAX - 3), |pos: (i32, i32, i32)| pos);
+    }
+
+    #[test]
+    #[should_panic(expected = "Out of bounds")]
+    fn index_out_of_bounds_test() {
+        let grid = Grid3D::new(2, 2, 2, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+        let _ = grid[(0, 0, 2)];
+    }
+}
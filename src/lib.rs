@@ -3,6 +3,9 @@ use std::marker::PhantomData;
 pub mod bounds2d;
 pub mod bounds3d;
 pub(crate) mod cells;
+pub mod grid2d;
+pub mod grid3d;
+pub mod math;
 pub mod rollgrid2d;
 pub mod rollgrid3d;
 
@@ -16,8 +19,97 @@ mod constants {
     pub const INFLATE_OVERFLOW: &'static str = "Inflate operation results in integer overflow";
     pub const DEFLATE_PAST_I32_MAX: &'static str = "Cannot deflate more than i32::MAX";
     pub const DEFLATE_OVERFLOW: &'static str = "Deflate operation results in integer overflow";
+    pub const INVERTED_BOUNDS: &'static str = "Minimum bound is greater than maximum bound";
+    pub const EMPTY_BOUNDS: &'static str = "Bounds have no cells";
+    pub const GRID_IS_EMPTY: &'static str = "Grid has no cells";
+    pub const X_MIN_EXCEEDS_MINIMUM: &'static str = "X minimum bound exceeds i32::MIN";
+    pub const Y_MIN_EXCEEDS_MINIMUM: &'static str = "Y minimum bound exceeds i32::MIN";
+    pub const Z_MIN_EXCEEDS_MINIMUM: &'static str = "Z minimum bound exceeds i32::MIN";
+    pub const X_MAX_EXCEEDS_MAXIMUM: &'static str = "X maximum bound exceeds i32::MAX";
+    pub const Y_MAX_EXCEEDS_MAXIMUM: &'static str = "Y maximum bound exceeds i32::MAX";
+    pub const Z_MAX_EXCEEDS_MAXIMUM: &'static str = "Z maximum bound exceeds i32::MAX";
+    pub const WRAP_OFFSET_OUT_OF_RANGE: &'static str = "Wrap offset is outside of the grid size";
+    pub const INDEX_EXCEEDS_CAPACITY: &'static str =
+        "Wrapped index exceeds the capacity of the grid";
 }
 
+/// An axis of a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    /// The `X` axis (width).
+    X,
+    /// The `Y` axis (height).
+    Y,
+    /// The `Z` axis (depth).
+    Z,
+}
+
+/// Error returned when creating bounds where the minimum is greater than the maximum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoundsError {
+    /// The minimum `X` bound is greater than the maximum `X` bound.
+    InvertedX,
+    /// The minimum `Y` bound is greater than the maximum `Y` bound.
+    InvertedY,
+    /// The minimum `Z` bound is greater than the maximum `Z` bound.
+    InvertedZ,
+}
+
+impl std::fmt::Display for BoundsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let axis = match self {
+            BoundsError::InvertedX => "X",
+            BoundsError::InvertedY => "Y",
+            BoundsError::InvertedZ => "Z",
+        };
+        write!(
+            f,
+            "Minimum {axis} bound is greater than maximum {axis} bound"
+        )
+    }
+}
+
+impl std::error::Error for BoundsError {}
+
+/// Error returned by fallible grid operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RollGridError {
+    /// The requested bounds are not entirely within the grid.
+    OutOfBounds,
+    /// The requested bounds have no cells.
+    EmptyBounds,
+}
+
+impl std::fmt::Display for RollGridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RollGridError::OutOfBounds => write!(f, "{}", constants::OUT_OF_BOUNDS),
+            RollGridError::EmptyBounds => write!(f, "{}", constants::EMPTY_BOUNDS),
+        }
+    }
+}
+
+impl std::error::Error for RollGridError {}
+
+/// Error returned when a coordinate component does not fit in the target type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TryConvertError {
+    /// The first axis whose component is out of range.
+    pub axis: Axis,
+}
+
+impl std::fmt::Display for TryConvertError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:?} component is out of range for the target type",
+            self.axis
+        )
+    }
+}
+
+impl std::error::Error for TryConvertError {}
+
 /// A trait for managing cells during resize operations on grids.
 ///
 /// You can easily create a [CellManager] to use as a [CellManage].
@@ -34,6 +126,112 @@ pub trait TryCellManage<C, T, E> {
     fn try_reload(&mut self, old_position: C, new_position: C, value: &mut T) -> Result<(), E>;
 }
 
+/// Functionality shared by [RollGrid2D](rollgrid2d::RollGrid2D) and
+/// [RollGrid3D](rollgrid3d::RollGrid3D) for writing dimension-agnostic code.
+pub trait RollGrid {
+    /// The coordinate type of the grid.
+    type Coord;
+    /// The iterator over the coordinates in the grid.
+    type Coords: Iterator<Item = Self::Coord>;
+
+    /// Iterate all in-bounds coordinates of the grid.
+    fn coords(&self) -> Self::Coords;
+}
+
+/// A type that can be used as a 2D grid coordinate.
+///
+/// This is implemented for `(i32, i32)`, `[i32; 2]`, and references to both.
+pub trait GridPos2 {
+    /// Convert into an `(x, y)` tuple.
+    fn into_tuple(self) -> (i32, i32);
+}
+
+/// A type that can be used as a 3D grid coordinate.
+///
+/// This is implemented for `(i32, i32, i32)`, `[i32; 3]`, and references to both.
+pub trait GridPos3 {
+    /// Convert into an `(x, y, z)` tuple.
+    fn into_tuple(self) -> (i32, i32, i32);
+}
+
+impl GridPos2 for (i32, i32) {
+    fn into_tuple(self) -> (i32, i32) {
+        self
+    }
+}
+
+impl GridPos2 for [i32; 2] {
+    fn into_tuple(self) -> (i32, i32) {
+        (self[0], self[1])
+    }
+}
+
+impl<P: GridPos2 + Copy> GridPos2 for &P {
+    fn into_tuple(self) -> (i32, i32) {
+        (*self).into_tuple()
+    }
+}
+
+impl GridPos3 for (i32, i32, i32) {
+    fn into_tuple(self) -> (i32, i32, i32) {
+        self
+    }
+}
+
+impl GridPos3 for [i32; 3] {
+    fn into_tuple(self) -> (i32, i32, i32) {
+        (self[0], self[1], self[2])
+    }
+}
+
+impl<P: GridPos3 + Copy> GridPos3 for &P {
+    fn into_tuple(self) -> (i32, i32, i32) {
+        (*self).into_tuple()
+    }
+}
+
+#[cfg(feature = "glam")]
+impl GridPos2 for glam::IVec2 {
+    fn into_tuple(self) -> (i32, i32) {
+        (self.x, self.y)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl GridPos3 for glam::IVec3 {
+    fn into_tuple(self) -> (i32, i32, i32) {
+        (self.x, self.y, self.z)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl GridPos2 for mint::Point2<i32> {
+    fn into_tuple(self) -> (i32, i32) {
+        (self.x, self.y)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl GridPos2 for mint::Vector2<i32> {
+    fn into_tuple(self) -> (i32, i32) {
+        (self.x, self.y)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl GridPos3 for mint::Point3<i32> {
+    fn into_tuple(self) -> (i32, i32, i32) {
+        (self.x, self.y, self.z)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl GridPos3 for mint::Vector3<i32> {
+    fn into_tuple(self) -> (i32, i32, i32) {
+        (self.x, self.y, self.z)
+    }
+}
+
 /// Use the utility function [cell_manager] to create a [CellManager].
 pub struct CellManager<C, T, FL, FU, FR, Marker = ()> {
     load: FL,
@@ -88,6 +286,48 @@ where
     }
 }
 
+/// Forwards `load` and `unload` to one [CellManage] and `reload` to another.
+pub(crate) struct SplitCellManage<ML, MR> {
+    pub(crate) load: ML,
+    pub(crate) reload: MR,
+}
+
+impl<C, T, ML, MR> CellManage<C, T> for SplitCellManage<ML, MR>
+where
+    ML: CellManage<C, T>,
+    MR: CellManage<C, T>,
+{
+    fn load(&mut self, position: C) -> T {
+        self.load.load(position)
+    }
+
+    fn unload(&mut self, position: C, old_value: T) {
+        self.load.unload(position, old_value);
+    }
+
+    fn reload(&mut self, old_position: C, new_position: C, value: &mut T) {
+        self.reload.reload(old_position, new_position, value);
+    }
+}
+
+impl<C, T, E, ML, MR> TryCellManage<C, T, E> for SplitCellManage<ML, MR>
+where
+    ML: TryCellManage<C, T, E>,
+    MR: TryCellManage<C, T, E>,
+{
+    fn try_load(&mut self, position: C) -> Result<T, E> {
+        self.load.try_load(position)
+    }
+
+    fn try_unload(&mut self, position: C, old_value: T) -> Result<(), E> {
+        self.load.try_unload(position, old_value)
+    }
+
+    fn try_reload(&mut self, old_position: C, new_position: C, value: &mut T) -> Result<(), E> {
+        self.reload.try_reload(old_position, new_position, value)
+    }
+}
+
 /// Creates a [CellManager] instance that implements [CellManage] using the given `load`, `unload`, and `reload` functions.
 pub fn cell_manager<C, T, FL, FU, FR>(
     load: FL,
@@ -152,6 +392,22 @@ mod tests {
         print_grid(&grid);
     }
 
+    #[test]
+    pub fn roll_grid_coords_test() {
+        use crate::rollgrid3d::RollGrid3D;
+        fn collect_coords<G: RollGrid>(grid: &G) -> Vec<G::Coord> {
+            grid.coords().collect()
+        }
+        let grid2 = RollGrid2D::new(3, 2, (1, -1), |pos: (i32, i32)| pos);
+        let coords = collect_coords(&grid2);
+        assert_eq!(coords.len(), 6);
+        assert!(coords.iter().all(|&pos| grid2.get(pos) == Some(&pos)));
+        let grid3 = RollGrid3D::new(2, 3, 2, (0, 5, -2), |pos: (i32, i32, i32)| pos);
+        let coords = collect_coords(&grid3);
+        assert_eq!(coords.len(), 12);
+        assert!(coords.iter().all(|&pos| grid3.get(pos) == Some(&pos)));
+    }
+
     #[test]
     pub fn bounds_test() {
         // let a = Bounds2D::from_bounds((0, 0), (3, 3));
@@ -0,0 +1,863 @@
+//! Arithmetic on the tuple coordinates used by the grids and bounds.
+
+use crate::{constants::*, Axis, TryConvertError};
+
+/// Component-wise addition of coordinates that fails on overflow.
+pub trait CheckedAddCoord<Rhs = Self> {
+    type Output;
+    /// Add `rhs` to each component of `self`, returning `None` if any component overflows.
+    fn checked_add_coord(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+/// Component-wise subtraction of coordinates that fails on overflow.
+pub trait CheckedSubCoord<Rhs = Self> {
+    type Output;
+    /// Subtract each component of `rhs` from `self`, returning `None` if any component
+    /// overflows.
+    fn checked_sub_coord(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+/// Component-wise addition of coordinates that clamps at the numeric bounds.
+///
+/// # Example
+/// ```rust
+/// use rollgrid::math::SaturatingAddCoord;
+/// assert_eq!((i32::MAX - 1, 0).saturating_add_coord((5, -5)), (i32::MAX, -5));
+/// ```
+pub trait SaturatingAddCoord<Rhs = Self> {
+    type Output;
+    /// Add `rhs` to each component of `self`, saturating at the numeric bounds.
+    fn saturating_add_coord(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Component-wise subtraction of coordinates that clamps at the numeric bounds.
+///
+/// # Example
+/// ```rust
+/// use rollgrid::math::SaturatingSubCoord;
+/// assert_eq!((i32::MIN + 1, 0, 3).saturating_sub_coord((5, 5, 1)), (i32::MIN, -5, 2));
+/// ```
+pub trait SaturatingSubCoord<Rhs = Self> {
+    type Output;
+    /// Subtract each component of `rhs` from `self`, saturating at the numeric bounds.
+    fn saturating_sub_coord(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Component-wise addition of coordinates that wraps around at the numeric bounds.
+///
+/// # Example
+/// ```rust
+/// use rollgrid::math::WrappingAddCoord;
+/// assert_eq!((i32::MAX, 0).wrapping_add_coord((1, 1)), (i32::MIN, 1));
+/// ```
+pub trait WrappingAddCoord<Rhs = Self> {
+    type Output;
+    /// Add `rhs` to each component of `self`, wrapping around at the numeric bounds.
+    fn wrapping_add_coord(self, rhs: Rhs) -> Self::Output;
+}
+
+impl CheckedAddCoord for (i32, i32) {
+    type Output = (i32, i32);
+    fn checked_add_coord(self, rhs: Self) -> Option<Self::Output> {
+        Some((self.0.checked_add(rhs.0)?, self.1.checked_add(rhs.1)?))
+    }
+}
+
+impl CheckedSubCoord for (i32, i32) {
+    type Output = (i32, i32);
+    fn checked_sub_coord(self, rhs: Self) -> Option<Self::Output> {
+        Some((self.0.checked_sub(rhs.0)?, self.1.checked_sub(rhs.1)?))
+    }
+}
+
+impl SaturatingAddCoord for (i32, i32) {
+    type Output = (i32, i32);
+    fn saturating_add_coord(self, rhs: Self) -> Self::Output {
+        (self.0.saturating_add(rhs.0), self.1.saturating_add(rhs.1))
+    }
+}
+
+impl SaturatingSubCoord for (i32, i32) {
+    type Output = (i32, i32);
+    fn saturating_sub_coord(self, rhs: Self) -> Self::Output {
+        (self.0.saturating_sub(rhs.0), self.1.saturating_sub(rhs.1))
+    }
+}
+
+impl WrappingAddCoord for (i32, i32) {
+    type Output = (i32, i32);
+    fn wrapping_add_coord(self, rhs: Self) -> Self::Output {
+        (self.0.wrapping_add(rhs.0), self.1.wrapping_add(rhs.1))
+    }
+}
+
+impl CheckedAddCoord for (i32, i32, i32) {
+    type Output = (i32, i32, i32);
+    fn checked_add_coord(self, rhs: Self) -> Option<Self::Output> {
+        Some((
+            self.0.checked_add(rhs.0)?,
+            self.1.checked_add(rhs.1)?,
+            self.2.checked_add(rhs.2)?,
+        ))
+    }
+}
+
+impl CheckedSubCoord for (i32, i32, i32) {
+    type Output = (i32, i32, i32);
+    fn checked_sub_coord(self, rhs: Self) -> Option<Self::Output> {
+        Some((
+            self.0.checked_sub(rhs.0)?,
+            self.1.checked_sub(rhs.1)?,
+            self.2.checked_sub(rhs.2)?,
+        ))
+    }
+}
+
+impl SaturatingAddCoord for (i32, i32, i32) {
+    type Output = (i32, i32, i32);
+    fn saturating_add_coord(self, rhs: Self) -> Self::Output {
+        (
+            self.0.saturating_add(rhs.0),
+            self.1.saturating_add(rhs.1),
+            self.2.saturating_add(rhs.2),
+        )
+    }
+}
+
+impl SaturatingSubCoord for (i32, i32, i32) {
+    type Output = (i32, i32, i32);
+    fn saturating_sub_coord(self, rhs: Self) -> Self::Output {
+        (
+            self.0.saturating_sub(rhs.0),
+            self.1.saturating_sub(rhs.1),
+            self.2.saturating_sub(rhs.2),
+        )
+    }
+}
+
+impl WrappingAddCoord for (i32, i32, i32) {
+    type Output = (i32, i32, i32);
+    fn wrapping_add_coord(self, rhs: Self) -> Self::Output {
+        (
+            self.0.wrapping_add(rhs.0),
+            self.1.wrapping_add(rhs.1),
+            self.2.wrapping_add(rhs.2),
+        )
+    }
+}
+
+macro_rules! impl_unsigned_coord {
+    ($(($($t:ident),+) => ($($i:tt),+));* $(;)?) => {
+        $(
+            impl CheckedAddCoord for ($($t,)+) {
+                type Output = Self;
+                fn checked_add_coord(self, rhs: Self) -> Option<Self::Output> {
+                    Some(($(self.$i.checked_add(rhs.$i)?,)+))
+                }
+            }
+
+            impl CheckedSubCoord for ($($t,)+) {
+                type Output = Self;
+                fn checked_sub_coord(self, rhs: Self) -> Option<Self::Output> {
+                    Some(($(self.$i.checked_sub(rhs.$i)?,)+))
+                }
+            }
+
+            impl SaturatingAddCoord for ($($t,)+) {
+                type Output = Self;
+                fn saturating_add_coord(self, rhs: Self) -> Self::Output {
+                    ($(self.$i.saturating_add(rhs.$i),)+)
+                }
+            }
+
+            impl SaturatingSubCoord for ($($t,)+) {
+                type Output = Self;
+                fn saturating_sub_coord(self, rhs: Self) -> Self::Output {
+                    ($(self.$i.saturating_sub(rhs.$i),)+)
+                }
+            }
+
+            impl WrappingAddCoord for ($($t,)+) {
+                type Output = Self;
+                fn wrapping_add_coord(self, rhs: Self) -> Self::Output {
+                    ($(self.$i.wrapping_add(rhs.$i),)+)
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned_coord!(
+    (u32, u32) => (0, 1);
+    (u32, u32, u32) => (0, 1, 2);
+);
+
+/// Add an unsigned `rhs` to `value`, returning `None` if the result exceeds `i32::MAX`.
+///
+/// This is the overflow policy for every "offset plus size" computation in the crate.
+pub fn checked_add_u32_to_i32(value: i32, rhs: u32) -> Option<i32> {
+    value.checked_add_unsigned(rhs)
+}
+
+/// Add an unsigned size to a signed offset, such as the minimum of a grid plus its size.
+///
+/// # Example
+/// ```rust
+/// use rollgrid::math::OffsetSize;
+/// assert_eq!((-3, 4).add_size((5, 6)), Some((2, 10)));
+/// assert_eq!((i32::MAX - 1, 0).add_size((2, 0)), None);
+/// ```
+pub trait OffsetSize<Size>: Sized {
+    /// Add `size` to each component of `self`, returning `None` if any component exceeds
+    /// `i32::MAX`.
+    fn add_size(self, size: Size) -> Option<Self>;
+}
+
+impl OffsetSize<(u32, u32)> for (i32, i32) {
+    fn add_size(self, size: (u32, u32)) -> Option<Self> {
+        Some((
+            checked_add_u32_to_i32(self.0, size.0)?,
+            checked_add_u32_to_i32(self.1, size.1)?,
+        ))
+    }
+}
+
+impl OffsetSize<(u32, u32, u32)> for (i32, i32, i32) {
+    fn add_size(self, size: (u32, u32, u32)) -> Option<Self> {
+        Some((
+            checked_add_u32_to_i32(self.0, size.0)?,
+            checked_add_u32_to_i32(self.1, size.1)?,
+            checked_add_u32_to_i32(self.2, size.2)?,
+        ))
+    }
+}
+
+/// Map `pos` into the window of `size` cells starting at `origin`, using modular
+/// arithmetic on each axis.
+///
+/// Panics if either component of `size` is zero.
+///
+/// # Example
+/// ```rust
+/// use rollgrid::math::wrap_coord_2d;
+/// assert_eq!(wrap_coord_2d((-1, 9), (0, 4), (4, 4)), (3, 5));
+/// ```
+pub fn wrap_coord_2d(pos: (i32, i32), origin: (i32, i32), size: (u32, u32)) -> (i32, i32) {
+    if size.0 == 0 || size.1 == 0 {
+        panic!("{AREA_IS_ZERO}");
+    }
+    (
+        wrap_component(pos.0, origin.0, size.0),
+        wrap_component(pos.1, origin.1, size.1),
+    )
+}
+
+/// Map `pos` into the window of `size` cells starting at `origin`, using modular
+/// arithmetic on each axis.
+///
+/// Panics if any component of `size` is zero.
+pub fn wrap_coord_3d(
+    pos: (i32, i32, i32),
+    origin: (i32, i32, i32),
+    size: (u32, u32, u32),
+) -> (i32, i32, i32) {
+    if size.0 == 0 || size.1 == 0 || size.2 == 0 {
+        panic!("{VOLUME_IS_ZERO}");
+    }
+    (
+        wrap_component(pos.0, origin.0, size.0),
+        wrap_component(pos.1, origin.1, size.1),
+        wrap_component(pos.2, origin.2, size.2),
+    )
+}
+
+fn wrap_component(value: i32, origin: i32, size: u32) -> i32 {
+    let wrapped = (value as i64 - origin as i64).rem_euclid(size as i64) + origin as i64;
+    i32::try_from(wrapped).expect(OFFSET_TOO_CLOSE_TO_MAX)
+}
+
+/// Add `offset` to `position`, panicking with the bound message for the first axis
+/// that overflows.
+pub(crate) fn offset_position_2d(position: (i32, i32), offset: (i32, i32)) -> (i32, i32) {
+    (
+        offset_component(position.0, offset.0, Axis::X),
+        offset_component(position.1, offset.1, Axis::Y),
+    )
+}
+
+/// Add `offset` to `position`, panicking with the bound message for the first axis
+/// that overflows.
+pub(crate) fn offset_position_3d(
+    position: (i32, i32, i32),
+    offset: (i32, i32, i32),
+) -> (i32, i32, i32) {
+    (
+        offset_component(position.0, offset.0, Axis::X),
+        offset_component(position.1, offset.1, Axis::Y),
+        offset_component(position.2, offset.2, Axis::Z),
+    )
+}
+
+/// Add `size` to `position` with [OffsetSize::add_size], panicking with the maximum bound
+/// message for the first axis that exceeds `i32::MAX`.
+pub(crate) fn add_size_2d(position: (i32, i32), size: (u32, u32)) -> (i32, i32) {
+    position.add_size(size).unwrap_or_else(|| {
+        if checked_add_u32_to_i32(position.0, size.0).is_none() {
+            panic!("{X_MAX_EXCEEDS_MAXIMUM}");
+        }
+        panic!("{Y_MAX_EXCEEDS_MAXIMUM}");
+    })
+}
+
+/// Add `size` to `position` with [OffsetSize::add_size], panicking with the maximum bound
+/// message for the first axis that exceeds `i32::MAX`.
+pub(crate) fn add_size_3d(position: (i32, i32, i32), size: (u32, u32, u32)) -> (i32, i32, i32) {
+    position.add_size(size).unwrap_or_else(|| {
+        if checked_add_u32_to_i32(position.0, size.0).is_none() {
+            panic!("{X_MAX_EXCEEDS_MAXIMUM}");
+        } else if checked_add_u32_to_i32(position.1, size.1).is_none() {
+            panic!("{Y_MAX_EXCEEDS_MAXIMUM}");
+        }
+        panic!("{Z_MAX_EXCEEDS_MAXIMUM}");
+    })
+}
+
+fn offset_component(value: i32, offset: i32, axis: Axis) -> i32 {
+    value
+        .checked_add(offset)
+        .unwrap_or_else(|| bound_overflow(axis, offset < 0))
+}
+
+/// Multiply a bound on `axis` by `factor`, panicking with the bound message for `axis`
+/// if the result doesn't fit in `i32`.
+pub(crate) fn scale_component(value: i32, factor: u32, axis: Axis) -> i32 {
+    let scaled = value as i64 * factor as i64;
+    i32::try_from(scaled).unwrap_or_else(|_| bound_overflow(axis, scaled < 0))
+}
+
+fn bound_overflow(axis: Axis, below_minimum: bool) -> ! {
+    let (min_msg, max_msg) = match axis {
+        Axis::X => (X_MIN_EXCEEDS_MINIMUM, X_MAX_EXCEEDS_MAXIMUM),
+        Axis::Y => (Y_MIN_EXCEEDS_MINIMUM, Y_MAX_EXCEEDS_MAXIMUM),
+        Axis::Z => (Z_MIN_EXCEEDS_MINIMUM, Z_MAX_EXCEEDS_MAXIMUM),
+    };
+    if below_minimum {
+        panic!("{min_msg}");
+    }
+    panic!("{max_msg}");
+}
+
+/// Fallible conversion of a coordinate tuple from `S`, component by component.
+///
+/// This is the counterpart of [TryConvert], the same way [From] is to [Into].
+pub trait TryConvertTuple<S>: Sized {
+    /// Convert `value`, failing with the first axis whose component is out of range.
+    fn try_convert_from(value: S) -> Result<Self, TryConvertError>;
+}
+
+/// Fallible narrowing conversion of a coordinate tuple into `T`.
+///
+/// # Example
+/// ```rust
+/// use rollgrid::{math::TryConvert, Axis};
+/// let coord: (i32, i32) = (5i64, -2i64).try_convert().unwrap();
+/// assert_eq!(coord, (5, -2));
+/// let result: Result<(i32, i32), _> = (0i64, i64::MAX).try_convert();
+/// assert_eq!(result.unwrap_err().axis, Axis::Y);
+/// ```
+pub trait TryConvert<T> {
+    /// Convert `self`, failing with the first axis whose component is out of range.
+    fn try_convert(self) -> Result<T, TryConvertError>;
+}
+
+impl<S, T: TryConvertTuple<S>> TryConvert<T> for S {
+    fn try_convert(self) -> Result<T, TryConvertError> {
+        T::try_convert_from(self)
+    }
+}
+
+macro_rules! impl_try_convert_tuple {
+    ($($from:ty => $to:ty),* $(,)?) => {
+        $(
+            impl TryConvertTuple<($from, $from)> for ($to, $to) {
+                fn try_convert_from(value: ($from, $from)) -> Result<Self, TryConvertError> {
+                    let component = |value: $from, axis: Axis| {
+                        <$to>::try_from(value).map_err(|_| TryConvertError { axis })
+                    };
+                    Ok((component(value.0, Axis::X)?, component(value.1, Axis::Y)?))
+                }
+            }
+
+            impl TryConvertTuple<($from, $from, $from)> for ($to, $to, $to) {
+                fn try_convert_from(
+                    value: ($from, $from, $from),
+                ) -> Result<Self, TryConvertError> {
+                    let component = |value: $from, axis: Axis| {
+                        <$to>::try_from(value).map_err(|_| TryConvertError { axis })
+                    };
+                    Ok((
+                        component(value.0, Axis::X)?,
+                        component(value.1, Axis::Y)?,
+                        component(value.2, Axis::Z)?,
+                    ))
+                }
+            }
+        )*
+    };
+}
+
+impl_try_convert_tuple!(
+    i64 => i32,
+    usize => u32,
+    i32 => u32,
+);
+
+/// Describes a coordinate tuple type by its component type and number of dimensions.
+pub trait DimensionsType {
+    /// The type of each component.
+    type Component;
+    /// The number of components.
+    const DIMENSIONS: usize;
+}
+
+/// Infallible conversion of a coordinate tuple from `S`, component by component.
+///
+/// This is the counterpart of [Convert], the same way [From] is to [Into].
+pub trait ConvertTuple<S> {
+    /// Convert `value` into `Self`.
+    fn convert_from(value: S) -> Self;
+}
+
+/// Infallible conversion of a coordinate tuple into `T`.
+///
+/// # Example
+/// ```rust
+/// use rollgrid::math::Convert;
+/// let coord: (f32, f32) = (3, -2).convert();
+/// assert_eq!(coord, (3.0, -2.0));
+/// ```
+pub trait Convert<T> {
+    /// Convert `self` into `T`.
+    fn convert(self) -> T;
+}
+
+impl<S, T: ConvertTuple<S>> Convert<T> for S {
+    fn convert(self) -> T {
+        T::convert_from(self)
+    }
+}
+
+/// Conversion of floating point coordinates back to integer cell coordinates.
+///
+/// Components that are out of range for `i32` saturate to `i32::MIN` or `i32::MAX`,
+/// and `NaN` components become `0`.
+pub trait FloatCoord {
+    /// The integer coordinate type.
+    type Output;
+    /// Round each component to the nearest integer, rounding half-way cases away from `0`.
+    fn round_coord(self) -> Self::Output;
+    /// Round each component toward negative infinity. This gives the cell that contains
+    /// the coordinate, so `-0.5` is in the cell at `-1`, matching `div_euclid` elsewhere.
+    fn floor_coord(self) -> Self::Output;
+    /// Round each component toward positive infinity.
+    fn ceil_coord(self) -> Self::Output;
+}
+
+macro_rules! impl_dimensions_type {
+    ($($component:ty),* $(,)?) => {
+        $(
+            impl DimensionsType for ($component, $component) {
+                type Component = $component;
+                const DIMENSIONS: usize = 2;
+            }
+
+            impl DimensionsType for ($component, $component, $component) {
+                type Component = $component;
+                const DIMENSIONS: usize = 3;
+            }
+        )*
+    };
+}
+
+impl_dimensions_type!(i32, i64, u32, usize, f32, f64);
+
+macro_rules! impl_convert_tuple {
+    ($($from:ty => $to:ty),* $(,)?) => {
+        $(
+            impl ConvertTuple<($from, $from)> for ($to, $to) {
+                fn convert_from(value: ($from, $from)) -> Self {
+                    (value.0 as $to, value.1 as $to)
+                }
+            }
+
+            impl ConvertTuple<($from, $from, $from)> for ($to, $to, $to) {
+                fn convert_from(value: ($from, $from, $from)) -> Self {
+                    (value.0 as $to, value.1 as $to, value.2 as $to)
+                }
+            }
+        )*
+    };
+}
+
+// `i32 -> f32` is lossy above 2^24, like an `as` cast.
+impl_convert_tuple!(
+    i32 => i64,
+    i32 => f32,
+    i32 => f64,
+);
+
+macro_rules! impl_float_coord {
+    ($($float:ty),* $(,)?) => {
+        $(
+            impl FloatCoord for ($float, $float) {
+                type Output = (i32, i32);
+                fn round_coord(self) -> Self::Output {
+                    (self.0.round() as i32, self.1.round() as i32)
+                }
+                fn floor_coord(self) -> Self::Output {
+                    (self.0.floor() as i32, self.1.floor() as i32)
+                }
+                fn ceil_coord(self) -> Self::Output {
+                    (self.0.ceil() as i32, self.1.ceil() as i32)
+                }
+            }
+
+            impl FloatCoord for ($float, $float, $float) {
+                type Output = (i32, i32, i32);
+                fn round_coord(self) -> Self::Output {
+                    (
+                        self.0.round() as i32,
+                        self.1.round() as i32,
+                        self.2.round() as i32,
+                    )
+                }
+                fn floor_coord(self) -> Self::Output {
+                    (
+                        self.0.floor() as i32,
+                        self.1.floor() as i32,
+                        self.2.floor() as i32,
+                    )
+                }
+                fn ceil_coord(self) -> Self::Output {
+                    (
+                        self.0.ceil() as i32,
+                        self.1.ceil() as i32,
+                        self.2.ceil() as i32,
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_float_coord!(f32, f64);
+
+#[cfg(feature = "glam")]
+impl TryConvertTuple<glam::UVec2> for (i32, i32) {
+    fn try_convert_from(value: glam::UVec2) -> Result<Self, TryConvertError> {
+        Self::try_convert_from((value.x as i64, value.y as i64))
+    }
+}
+
+#[cfg(feature = "glam")]
+impl TryConvertTuple<glam::UVec3> for (i32, i32, i32) {
+    fn try_convert_from(value: glam::UVec3) -> Result<Self, TryConvertError> {
+        Self::try_convert_from((value.x as i64, value.y as i64, value.z as i64))
+    }
+}
+
+// `From` can't be implemented between mint types and tuples in this crate since both
+// are foreign, so the conversions go through `ConvertTuple` instead.
+#[cfg(feature = "mint")]
+macro_rules! impl_mint_convert_tuple {
+    ($($mint2:ident, $mint3:ident);* $(;)?) => {
+        $(
+            impl ConvertTuple<mint::$mint2<i32>> for (i32, i32) {
+                fn convert_from(value: mint::$mint2<i32>) -> Self {
+                    (value.x, value.y)
+                }
+            }
+
+            impl ConvertTuple<(i32, i32)> for mint::$mint2<i32> {
+                fn convert_from(value: (i32, i32)) -> Self {
+                    mint::$mint2 {
+                        x: value.0,
+                        y: value.1,
+                    }
+                }
+            }
+
+            impl ConvertTuple<mint::$mint3<i32>> for (i32, i32, i32) {
+                fn convert_from(value: mint::$mint3<i32>) -> Self {
+                    (value.x, value.y, value.z)
+                }
+            }
+
+            impl ConvertTuple<(i32, i32, i32)> for mint::$mint3<i32> {
+                fn convert_from(value: (i32, i32, i32)) -> Self {
+                    mint::$mint3 {
+                        x: value.0,
+                        y: value.1,
+                        z: value.2,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "mint")]
+impl_mint_convert_tuple!(
+    Point2, Point3;
+    Vector2, Vector3;
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coord_2d_test() {
+        assert_eq!((1, -2).checked_add_coord((3, 4)), Some((4, 2)));
+        assert_eq!((1, -2).checked_sub_coord((3, 4)), Some((-2, -6)));
+        assert_eq!((i32::MAX, 0).checked_add_coord((1, 0)), None);
+        assert_eq!((0, i32::MIN).checked_sub_coord((0, 1)), None);
+        assert_eq!(
+            (0, i32::MIN).checked_sub_coord((0, -1)),
+            Some((0, i32::MIN + 1))
+        );
+    }
+
+    #[test]
+    fn coord_3d_test() {
+        assert_eq!((1, -2, 5).checked_add_coord((3, 4, -5)), Some((4, 2, 0)));
+        assert_eq!((1, -2, 5).checked_sub_coord((3, 4, -5)), Some((-2, -6, 10)));
+        assert_eq!((0, 0, i32::MAX).checked_add_coord((0, 0, 1)), None);
+        assert_eq!((i32::MIN, 0, 0).checked_sub_coord((1, 0, 0)), None);
+        assert_eq!(
+            (i32::MAX - 1, 0, 0).checked_add_coord((1, 2, 3)),
+            Some((i32::MAX, 2, 3))
+        );
+    }
+
+    #[test]
+    fn saturating_coord_test() {
+        assert_eq!((i32::MAX, 0).saturating_add_coord((1, 0)), (i32::MAX, 0));
+        assert_eq!(
+            (i32::MAX - 1, 0).saturating_add_coord((1, 0)),
+            (i32::MAX, 0)
+        );
+        assert_eq!((0, i32::MIN).saturating_add_coord((0, -1)), (0, i32::MIN));
+        assert_eq!((0, i32::MIN).saturating_sub_coord((0, 1)), (0, i32::MIN));
+        assert_eq!((i32::MAX, 0).saturating_sub_coord((-1, 0)), (i32::MAX, 0));
+        assert_eq!((3, 4).saturating_sub_coord((1, 1)), (2, 3));
+        assert_eq!(
+            (i32::MAX, i32::MIN, 0).saturating_add_coord((1, -1, 1)),
+            (i32::MAX, i32::MIN, 1)
+        );
+        assert_eq!(
+            (i32::MIN, i32::MAX, 0).saturating_sub_coord((1, -1, 1)),
+            (i32::MIN, i32::MAX, -1)
+        );
+    }
+
+    #[test]
+    fn wrapping_coord_test() {
+        assert_eq!((i32::MAX, 0).wrapping_add_coord((1, 0)), (i32::MIN, 0));
+        assert_eq!((0, i32::MIN).wrapping_add_coord((0, -1)), (0, i32::MAX));
+        assert_eq!((i32::MAX - 1, 0).wrapping_add_coord((1, 0)), (i32::MAX, 0));
+        assert_eq!(
+            (i32::MAX, i32::MIN, 7).wrapping_add_coord((1, -1, 1)),
+            (i32::MIN, i32::MAX, 8)
+        );
+    }
+
+    #[test]
+    fn unsigned_coord_test() {
+        assert_eq!((1u32, 2).checked_add_coord((3, 4)), Some((4, 6)));
+        assert_eq!((5u32, 4, 3).checked_sub_coord((1, 2, 3)), Some((4, 2, 0)));
+        assert_eq!((u32::MAX, 0).checked_add_coord((1, 0)), None);
+        assert_eq!((0u32, 0, 0).checked_sub_coord((0, 0, 1)), None);
+        assert_eq!((u32::MAX, 0).saturating_add_coord((1, 1)), (u32::MAX, 1));
+        assert_eq!((0u32, 5, 0).saturating_sub_coord((1, 1, 0)), (0, 4, 0));
+        assert_eq!((u32::MAX, 0).wrapping_add_coord((1, 1)), (0, 1));
+    }
+
+    #[test]
+    fn offset_size_test() {
+        assert_eq!(checked_add_u32_to_i32(i32::MIN, u32::MAX), Some(i32::MAX));
+        assert_eq!(checked_add_u32_to_i32(i32::MIN + 1, u32::MAX), None);
+        assert_eq!(
+            checked_add_u32_to_i32(-1, i32::MAX as u32 + 1),
+            Some(i32::MAX)
+        );
+        // offset + size == i32::MAX + 1
+        assert_eq!(checked_add_u32_to_i32(i32::MAX - 4, 5), None);
+        assert_eq!((i32::MAX - 4, 0).add_size((4, 5)), Some((i32::MAX, 5)));
+        assert_eq!((0, i32::MAX - 4).add_size((5, 5)), None);
+        assert_eq!(
+            (i32::MAX - 1, -1, i32::MAX - 2).add_size((1, 1, 2)),
+            Some((i32::MAX, 0, i32::MAX))
+        );
+        assert_eq!((0, 0, i32::MAX - 2).add_size((1, 1, 3)), None);
+    }
+
+    #[test]
+    fn wrap_coord_test() {
+        // already inside.
+        assert_eq!(wrap_coord_2d((1, 5), (0, 4), (4, 4)), (1, 5));
+        assert_eq!(wrap_coord_3d((0, 4, 3), (0, 4, 0), (4, 4, 4)), (0, 4, 3));
+        // negative inputs.
+        assert_eq!(wrap_coord_2d((-1, -1), (0, 0), (4, 3)), (3, 2));
+        assert_eq!(
+            wrap_coord_3d((-5, -6, -1), (-4, -4, -4), (2, 2, 2)),
+            (-3, -4, -3)
+        );
+        // more than one period away.
+        assert_eq!(wrap_coord_2d((13, -11), (1, 1), (4, 4)), (1, 1));
+        assert_eq!(
+            wrap_coord_3d((109, 4, -33), (5, 5, 5), (4, 4, 4)),
+            (5, 8, 7)
+        );
+        // extremes don't overflow.
+        assert_eq!(wrap_coord_2d((i32::MIN, i32::MAX), (0, 0), (3, 3)), (1, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Width/Height/Depth cannot be 0")]
+    fn wrap_coord_zero_size_test() {
+        wrap_coord_3d((0, 0, 0), (0, 0, 0), (1, 0, 1));
+    }
+
+    #[test]
+    fn offset_position_test() {
+        assert_eq!(
+            offset_position_2d((i32::MAX - 1, -3), (1, 3)),
+            (i32::MAX, 0)
+        );
+        assert_eq!(
+            offset_position_3d((i32::MIN, 0, 5), (i32::MAX, -1, i32::MIN + 1)),
+            (-1, -1, i32::MIN + 6)
+        );
+    }
+
+    #[test]
+    fn add_size_helpers_test() {
+        assert_eq!(add_size_2d((i32::MAX - 3, -2), (3, 2)), (i32::MAX, 0));
+        assert_eq!(
+            add_size_3d((i32::MIN, 0, 5), (u32::MAX, 1, 2)),
+            (i32::MAX, 1, 7)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Z maximum bound exceeds i32::MAX")]
+    fn add_size_overflow_test() {
+        add_size_3d((0, 0, i32::MAX), (1, 1, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Y minimum bound exceeds i32::MIN")]
+    fn offset_position_overflow_test() {
+        offset_position_3d((0, i32::MIN, i32::MAX), (0, -1, 1));
+    }
+
+    #[test]
+    fn try_convert_test() {
+        let max = i32::MAX as i64;
+        assert_eq!((max, -max).try_convert(), Ok((i32::MAX, -i32::MAX)));
+        assert_eq!((i32::MIN as i64, 0i64).try_convert(), Ok((i32::MIN, 0)));
+        let result: Result<(i32, i32), _> = (max + 1, 0i64).try_convert();
+        assert_eq!(result, Err(TryConvertError { axis: Axis::X }));
+        let result: Result<(i32, i32), _> = (0i64, i32::MIN as i64 - 1).try_convert();
+        assert_eq!(result, Err(TryConvertError { axis: Axis::Y }));
+        let result: Result<(i32, i32, i32), _> = (max, max, max + 1).try_convert();
+        assert_eq!(result, Err(TryConvertError { axis: Axis::Z }));
+        assert_eq!((max, 0i64, -1i64).try_convert(), Ok((i32::MAX, 0, -1)));
+        // the first failing axis is reported
+        let result: Result<(i32, i32, i32), _> = (0i64, max + 1, max + 1).try_convert();
+        assert_eq!(result, Err(TryConvertError { axis: Axis::Y }));
+    }
+
+    #[test]
+    fn try_convert_unsigned_test() {
+        assert_eq!(
+            (u32::MAX as usize, 0usize).try_convert(),
+            Ok((u32::MAX, 0u32))
+        );
+        let result: Result<(u32, u32, u32), _> =
+            (0usize, 0usize, u32::MAX as usize + 1).try_convert();
+        assert_eq!(result, Err(TryConvertError { axis: Axis::Z }));
+        assert_eq!(
+            <(u32, u32)>::try_convert_from((i32::MAX, 0)),
+            Ok((i32::MAX as u32, 0))
+        );
+        let result: Result<(u32, u32), _> = (3i32, -1i32).try_convert();
+        assert_eq!(result, Err(TryConvertError { axis: Axis::Y }));
+        let result: Result<(u32, u32, u32), _> = (-1i32, 0i32, 0i32).try_convert();
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "X component is out of range for the target type"
+        );
+    }
+
+    #[test]
+    fn convert_test() {
+        let coord: (i64, i64) = (i32::MIN, i32::MAX).convert();
+        assert_eq!(coord, (i32::MIN as i64, i32::MAX as i64));
+        let coord: (f64, f64, f64) = (1, -2, i32::MAX).convert();
+        assert_eq!(coord, (1.0, -2.0, 2147483647.0));
+        // f32 cannot represent every integer above 2^24
+        let coord: (f32, f32) = (16_777_217, -16_777_217).convert();
+        assert_eq!(coord, (16_777_216.0, -16_777_216.0));
+        assert_eq!(coord.floor_coord(), (16_777_216, -16_777_216));
+        let coord: (f64, f64) = (16_777_217, -16_777_217).convert();
+        assert_eq!(coord.floor_coord(), (16_777_217, -16_777_217));
+        assert_eq!(<(f32, f32) as DimensionsType>::DIMENSIONS, 2);
+        assert_eq!(<(i32, i32, i32) as DimensionsType>::DIMENSIONS, 3);
+    }
+
+    #[test]
+    fn float_coord_test() {
+        assert_eq!((-0.5f32, 0.5f32).floor_coord(), (-1, 0));
+        assert_eq!((-0.5f32, 0.5f32).round_coord(), (-1, 1));
+        assert_eq!((-0.5f32, 0.5f32).ceil_coord(), (0, 1));
+        assert_eq!((-1.0f32, 1.0f32, -1.0f32).floor_coord(), (-1, 1, -1));
+        assert_eq!((-1.0f64, 1.0f64, -1.0f64).round_coord(), (-1, 1, -1));
+        assert_eq!((-1.0f64, 1.0f64, -1.0f64).ceil_coord(), (-1, 1, -1));
+        assert_eq!((-1.25f64, 2.75f64, -0.0f64).floor_coord(), (-2, 2, 0));
+        // floor matches div_euclid for cells of size 1/4
+        for i in -20i32..20 {
+            let coord = (i as f32 / 4.0, 0.0f32);
+            assert_eq!(coord.floor_coord().0, i.div_euclid(4));
+        }
+        // out of range components saturate
+        assert_eq!((3.0e9f32, -3.0e9f32).floor_coord(), (i32::MAX, i32::MIN));
+        assert_eq!((f64::NAN, 1.5f64).ceil_coord(), (0, 2));
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam_convert_test() {
+        use glam::{UVec2, UVec3};
+        assert_eq!(UVec2::new(3, 4).try_convert(), Ok((3, 4)));
+        let result: Result<(i32, i32), _> = UVec2::new(0, i32::MAX as u32 + 1).try_convert();
+        assert_eq!(result, Err(TryConvertError { axis: Axis::Y }));
+        assert_eq!(
+            UVec3::new(1, 2, i32::MAX as u32).try_convert(),
+            Ok((1, 2, i32::MAX))
+        );
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn mint_convert_test() {
+        let point = mint::Point2 { x: 3, y: -4 };
+        let tuple: (i32, i32) = point.convert();
+        assert_eq!(tuple, (3, -4));
+        let vector: mint::Vector3<i32> = (1, 2, 3).convert();
+        assert_eq!(vector, mint::Vector3 { x: 1, y: 2, z: 3 });
+        let tuple: (i32, i32, i32) = mint::Point3 { x: 1, y: 2, z: 3 }.convert();
+        assert_eq!(tuple, (1, 2, 3));
+    }
+}
@@ -1,4 +1,5 @@
-use crate::{bounds2d::*, cells::FixedArray, constants::*, *};
+use crate::{bounds2d::*, cells::FixedArray, constants::*, grid2d::Grid2D, math::*, *};
+use std::{collections::HashMap, hash::Hash, io};
 
 /// A 2D implementation of a rolling grid. It's a data structure similar
 /// to a circular buffer in the sense that cells can wrap around.
@@ -8,8 +9,9 @@ use crate::{bounds2d::*, cells::FixedArray, constants::*, *};
 pub struct RollGrid2D<T: Sized> {
     cells: FixedArray<T>,
     size: (usize, usize),
-    wrap_offset: (i32, i32),
+    wrap_offset: (u32, u32),
     grid_offset: (i32, i32),
+    region_log: Option<Vec<Vec<Bounds2D>>>,
 }
 
 impl<T: Default> RollGrid2D<T> {
@@ -20,8 +22,21 @@ impl<T: Default> RollGrid2D<T> {
             size: (width, height),
             grid_offset: grid_offset,
             wrap_offset: (0, 0),
+            region_log: None,
         }
     }
+
+    /// Resize the grid without changing the offset, setting new cells to the default
+    /// for `T` and dropping removed cells.
+    ///
+    /// See [resize](Self::resize).
+    pub fn resize_default(&mut self, new_size: (u32, u32)) {
+        self.resize(
+            new_size.0 as usize,
+            new_size.1 as usize,
+            cell_manager(|_: (i32, i32)| T::default(), |_, _| (), |_, _, _| ()),
+        );
+    }
 }
 
 impl<T> RollGrid2D<T> {
@@ -39,6 +54,7 @@ impl<T> RollGrid2D<T> {
             cells: FixedArray::new_2d((width, height), grid_offset, init),
             size: (width, height),
             wrap_offset: (0, 0),
+            region_log: None,
             grid_offset: grid_offset,
         }
     }
@@ -57,6 +73,7 @@ impl<T> RollGrid2D<T> {
             cells: FixedArray::try_new_2d((width, height), grid_offset, init)?,
             size: (width, height),
             wrap_offset: (0, 0),
+            region_log: None,
             grid_offset: grid_offset,
         })
     }
@@ -352,6 +369,11 @@ impl<T> RollGrid2D<T> {
 
     /// Resize and reposition the grid simultaneously.
     ///
+    /// The old and new bounds are only walked once: cells outside the new bounds are
+    /// unloaded, cells outside the old bounds are loaded, and the overlap is carried over
+    /// without being reloaded. If the size doesn't change, this is a regular
+    /// [reposition](Self::reposition).
+    ///
     /// # Example
     /// ```rust, no_run
     /// grid.resize_and_reposition(3, 3, (4, 4), cell_manager(
@@ -399,12 +421,12 @@ impl<T> RollGrid2D<T> {
         if area > i32::MAX as usize {
             panic!("{SIZE_TOO_LARGE}");
         }
-        let (new_x, new_y) = new_position;
-        let nw = width as i32;
-        let nh = height as i32;
+        // Checked before anything is unloaded so that an offset too close to the maximum
+        // can't leave the grid half-resized.
+        let new_max = add_size_2d(new_position, (width as u32, height as u32));
         // Determine what needs to be unloaded
         let old_bounds: Bounds2D = self.bounds();
-        let new_bounds = Bounds2D::new((new_x, new_y), (new_x + nw, new_y + nh));
+        let new_bounds = Bounds2D::try_new(new_position, new_max).expect(INVERTED_BOUNDS);
         if old_bounds.intersects(new_bounds) {
             macro_rules! unload_bounds {
                 ($cond: expr => xmin = $xmin:expr; ymin = $ymin:expr; xmax = $xmax:expr; ymax = $ymax:expr;) => {
@@ -444,6 +466,10 @@ impl<T> RollGrid2D<T> {
                 xmax = old_bounds.x_max();
                 ymax = old_bounds.y_max();
             );
+            // Cells in the overlap of the old and new bounds are carried over as-is.
+            // Unloaded cells are only taken from outside the new bounds and loaded cells
+            // are only created outside the old bounds, so no coordinate is ever both
+            // unloaded and loaded by the same resize.
             let new_grid = FixedArray::new_2d((width, height), new_position, |pos| {
                 if old_bounds.contains(pos) {
                     let index = self.offset_index(pos).expect(OUT_OF_BOUNDS);
@@ -458,6 +484,10 @@ impl<T> RollGrid2D<T> {
                 self.cells.forget_dealloc();
             }
             self.cells = new_grid;
+            // The new array is laid out in coordinate order, so there is no roll to keep.
+            // Keeping the wrap offset would not save reloads either way: `reposition` only
+            // reloads the cells that leave the grid, which depends on the offset of the move
+            // and not on the wrap offset.
             self.wrap_offset = (0, 0);
         } else {
             // !old_bounds.intersects(new_bounds)
@@ -479,6 +509,54 @@ impl<T> RollGrid2D<T> {
         }
     }
 
+    /// Resize and reposition the grid, guaranteeing that the backing storage is
+    /// allocated for exactly the new size afterwards.
+    ///
+    /// [resize_and_reposition](Self::resize_and_reposition) never reuses a larger
+    /// allocation, so this behaves the same; it exists for callers that want the minimal
+    /// footprint to be explicit (and checked) rather than incidental.
+    pub fn resize_and_reposition_shrinking<M>(
+        &mut self,
+        width: usize,
+        height: usize,
+        new_position: (i32, i32),
+        manage: M,
+    ) where
+        M: CellManage<(i32, i32), T>,
+    {
+        self.resize_and_reposition(width, height, new_position, manage);
+        debug_assert_eq!(self.capacity(), self.len());
+    }
+
+    /// Resize and reposition the grid using separate managers for loading and reloading.
+    ///
+    /// `load_manager` handles cells that are loaded and unloaded, while `reload_manager`
+    /// handles cells that remain in the grid but are moved to a new position. Only `reload`
+    /// is called on `reload_manager`, and `reload` is never called on `load_manager`.
+    ///
+    /// See [resize_and_reposition](Self::resize_and_reposition).
+    pub fn resize_split_manage<ML, MR>(
+        &mut self,
+        width: usize,
+        height: usize,
+        new_position: (i32, i32),
+        load_manager: ML,
+        reload_manager: MR,
+    ) where
+        ML: CellManage<(i32, i32), T>,
+        MR: CellManage<(i32, i32), T>,
+    {
+        self.resize_and_reposition(
+            width,
+            height,
+            new_position,
+            SplitCellManage {
+                load: load_manager,
+                reload: reload_manager,
+            },
+        );
+    }
+
     /// Try to resize and reposition the grid using a fallible function.
     ///
     /// # Example
@@ -531,12 +609,12 @@ impl<T> RollGrid2D<T> {
         if area > i32::MAX as usize {
             panic!("{SIZE_TOO_LARGE}");
         }
-        let (new_x, new_y) = new_position;
-        let nw = width as i32;
-        let nh = height as i32;
+        // Checked before anything is unloaded so that an offset too close to the maximum
+        // can't leave the grid half-resized.
+        let new_max = add_size_2d(new_position, (width as u32, height as u32));
         // Determine what needs to be unloaded
         let old_bounds: Bounds2D = self.bounds();
-        let new_bounds = Bounds2D::new((new_x, new_y), (new_x + nw, new_y + nh));
+        let new_bounds = Bounds2D::try_new(new_position, new_max).expect(INVERTED_BOUNDS);
         if old_bounds.intersects(new_bounds) {
             macro_rules! unload_bounds {
                 ($cond: expr => xmin = $xmin:expr; ymin = $ymin:expr; xmax = $xmax:expr; ymax = $ymax:expr;) => {
@@ -615,6 +693,98 @@ impl<T> RollGrid2D<T> {
         Ok(())
     }
 
+    /// Try to resize and reposition the grid using separate fallible managers for loading
+    /// and reloading.
+    ///
+    /// See [resize_split_manage](Self::resize_split_manage) and
+    /// [try_resize_and_reposition](Self::try_resize_and_reposition).
+    pub fn try_resize_split_manage<E, ML, MR>(
+        &mut self,
+        width: usize,
+        height: usize,
+        new_position: (i32, i32),
+        load_manager: ML,
+        reload_manager: MR,
+    ) -> Result<(), E>
+    where
+        ML: TryCellManage<(i32, i32), T, E>,
+        MR: TryCellManage<(i32, i32), T, E>,
+    {
+        self.try_resize_and_reposition(
+            width,
+            height,
+            new_position,
+            SplitCellManage {
+                load: load_manager,
+                reload: reload_manager,
+            },
+        )
+    }
+
+    /// Unload every cell in the grid and then load every cell again, keeping the same
+    /// size and offset. Unlike [resize_and_reposition](Self::resize_and_reposition), this
+    /// never short-circuits, so it can be used to force a full reload.
+    ///
+    /// If `manage` panics, the cells that were not unloaded are dropped and the grid is
+    /// left empty, with a size of `(0, 0)`.
+    ///
+    /// See [CellManage].
+    pub fn reset<M>(&mut self, manage: M)
+    where
+        M: CellManage<(i32, i32), T>,
+    {
+        let mut manage = manage;
+        let (size, offset) = (self.size, self.grid_offset);
+        self.take_cells()
+            .into_iter()
+            .for_each(|(pos, cell)| manage.unload(pos, cell));
+        self.cells = FixedArray::new_2d(size, offset, |pos| manage.load(pos));
+        self.size = size;
+    }
+
+    /// Unload every cell in the grid and then load every cell again using a fallible
+    /// [TryCellManage], keeping the same size and offset.
+    ///
+    /// If an error is returned, the cells that were not unloaded are dropped and the grid
+    /// is left empty, with a size of `(0, 0)`. Use [resize](Self::resize) to give it
+    /// cells again. Until then, lookups such as [get](Self::get) return `None` and
+    /// iteration yields nothing, but [get_wrapped](Self::get_wrapped),
+    /// [toroidal](Self::toroidal) indexing, [moore_wrapping](Self::moore_wrapping),
+    /// [upsample](Self::upsample) and [downsample](Self::downsample) panic.
+    ///
+    /// See [reset](Self::reset) and [TryCellManage].
+    pub fn try_reset<E, M>(&mut self, manage: M) -> Result<(), E>
+    where
+        M: TryCellManage<(i32, i32), T, E>,
+    {
+        let mut manage = manage;
+        let (size, offset) = (self.size, self.grid_offset);
+        self.take_cells()
+            .into_iter()
+            .try_for_each(|(pos, cell)| manage.try_unload(pos, cell))?;
+        self.cells = FixedArray::try_new_2d(size, offset, |pos| manage.try_load(pos))?;
+        self.size = size;
+        Ok(())
+    }
+
+    /// Move the cells out into a new grid, leaving this grid empty with a size of `(0, 0)`.
+    ///
+    /// This is used before running fallible callbacks on every cell, so that an early
+    /// return or a panic drops each remaining cell exactly once.
+    fn take_cells(&mut self) -> RollGrid2D<T> {
+        let cells = std::mem::replace(&mut self.cells, FixedArray::unallocated());
+        let taken = RollGrid2D {
+            cells,
+            size: self.size,
+            wrap_offset: self.wrap_offset,
+            grid_offset: self.grid_offset,
+            region_log: None,
+        };
+        self.size = (0, 0);
+        self.wrap_offset = (0, 0);
+        taken
+    }
+
     /// Translate the grid by offset amount using a reload function.
     ///
     /// The reload function takes the old position, the new position, and
@@ -628,13 +798,133 @@ impl<T> RollGrid2D<T> {
     ///     *cell_mut = new_position;
     /// })
     /// ```
-    pub fn translate<F>(&mut self, offset: (i32, i32), reload: F)
+    pub fn translate<F>(&mut self, offset: impl GridPos2, reload: F)
+    where
+        F: FnMut((i32, i32), (i32, i32), &mut T),
+    {
+        let offset = offset.into_tuple();
+        self.reposition(offset_position_2d(self.grid_offset, offset), reload);
+    }
+
+    /// Start recording the regions that are reloaded by each reposition.
+    ///
+    /// While recording is disabled (the default), repositioning does no extra work.
+    pub fn enable_region_log(&mut self) {
+        if self.region_log.is_none() {
+            self.region_log = Some(Vec::new());
+        }
+    }
+
+    /// Stop recording reloaded regions, discarding any entries that have not been taken.
+    pub fn disable_region_log(&mut self) {
+        self.region_log = None;
+    }
+
+    /// Take the regions recorded since the log was enabled or last taken. Each entry holds
+    /// the non-overlapping regions that were reloaded by a single reposition.
+    ///
+    /// Returns an empty [Vec] if recording is disabled.
+    pub fn take_region_log(&mut self) -> Vec<Vec<Bounds2D>> {
+        self.region_log
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
+    /// Append the regions that moving to `position` will reload to the region log.
+    fn record_regions(&mut self, position: (i32, i32)) {
+        let old_bounds = self.bounds();
+        let new_max = add_size_2d(position, (self.size.0 as u32, self.size.1 as u32));
+        let new_bounds = Bounds2D::try_new(position, new_max).expect(INVERTED_BOUNDS);
+        let regions = match old_bounds.intersection(new_bounds) {
+            Some(kept) => {
+                let mut regions = Vec::with_capacity(2);
+                // The columns that rolled in span the full height of the grid.
+                if new_bounds.x_min() < kept.x_min() {
+                    regions.push(Bounds2D::new(
+                        new_bounds.min,
+                        (kept.x_min(), new_bounds.y_max()),
+                    ));
+                } else if new_bounds.x_max() > kept.x_max() {
+                    regions.push(Bounds2D::new(
+                        (kept.x_max(), new_bounds.y_min()),
+                        new_bounds.max,
+                    ));
+                }
+                // The rows that rolled in only span the columns that were kept.
+                if new_bounds.y_min() < kept.y_min() {
+                    regions.push(Bounds2D::new(
+                        (kept.x_min(), new_bounds.y_min()),
+                        (kept.x_max(), kept.y_min()),
+                    ));
+                } else if new_bounds.y_max() > kept.y_max() {
+                    regions.push(Bounds2D::new(
+                        (kept.x_min(), kept.y_max()),
+                        (kept.x_max(), new_bounds.y_max()),
+                    ));
+                }
+                regions
+            }
+            None => vec![new_bounds],
+        };
+        if let Some(log) = self.region_log.as_mut() {
+            log.push(regions);
+        }
+    }
+
+    /// Translate the grid by `offset`, replacing each cell that enters the grid with
+    /// `fill(new_position)` and dropping its old value. Cells that remain in the grid
+    /// are left untouched.
+    pub fn scroll<F>(&mut self, offset: (i32, i32), fill: F)
+    where
+        F: FnMut((i32, i32)) -> T,
+    {
+        let mut fill = fill;
+        self.translate(offset, |_, new_position, cell| {
+            *cell = fill(new_position);
+        });
+    }
+
+    /// Predict whether translating the grid by `offset` would reload every cell rather
+    /// than only rolling in the cells that enter the grid.
+    ///
+    /// This is the case when the offset is at least the size of the grid on either axis.
+    pub fn translate_is_full_reload(&self, offset: (i32, i32)) -> bool {
+        offset.0.unsigned_abs() as usize >= self.size.0
+            || offset.1.unsigned_abs() as usize >= self.size.1
+    }
+
+    /// Translate the grid by offset amount using a reload function, returning the
+    /// number of cells that were passed to the reload function.
+    ///
+    /// See [translate](Self::translate).
+    pub fn translate_counted<F>(&mut self, offset: impl GridPos2, mut reload: F) -> usize
+    where
+        F: FnMut((i32, i32), (i32, i32), &mut T),
+    {
+        let mut count = 0;
+        self.translate(offset, |old_position, new_position, cell| {
+            count += 1;
+            reload(old_position, new_position, cell);
+        });
+        count
+    }
+
+    /// Reposition the offset of the grid and reload the slots that are changed,
+    /// returning the number of cells that were passed to the reload function.
+    ///
+    /// This is `0` when the position doesn't change, and the number of cells in the grid
+    /// when the new bounds do not overlap the old bounds. See [reposition](Self::reposition).
+    pub fn reposition_counted<F>(&mut self, position: impl GridPos2, mut reload: F) -> usize
     where
         F: FnMut((i32, i32), (i32, i32), &mut T),
     {
-        let (curx, cury) = self.grid_offset;
-        let (ox, oy) = offset;
-        self.reposition((curx + ox, cury + oy), reload);
+        let mut count = 0;
+        self.reposition(position, |old_position, new_position, cell| {
+            count += 1;
+            reload(old_position, new_position, cell);
+        });
+        count
     }
 
     /// Try to translate the grid by offset amount using a fallible reload function.
@@ -651,13 +941,12 @@ impl<T> RollGrid2D<T> {
     ///     Ok(())
     /// })
     /// ```
-    pub fn try_translate<E, F>(&mut self, offset: (i32, i32), reload: F) -> Result<(), E>
+    pub fn try_translate<E, F>(&mut self, offset: impl GridPos2, reload: F) -> Result<(), E>
     where
         F: FnMut((i32, i32), (i32, i32), &mut T) -> Result<(), E>,
     {
-        let (curx, cury) = self.grid_offset;
-        let (ox, oy) = offset;
-        self.try_reposition((curx + ox, cury + oy), reload)
+        let offset = offset.into_tuple();
+        self.try_reposition(offset_position_2d(self.grid_offset, offset), reload)
     }
 
     /// Reposition the offset of the grid and reload the slots that are changed.
@@ -673,26 +962,35 @@ impl<T> RollGrid2D<T> {
     ///     *cell_mut = new_position;
     /// })
     /// ```
-    pub fn reposition<F>(&mut self, position: (i32, i32), reload: F)
+    pub fn reposition<F>(&mut self, position: impl GridPos2, reload: F)
     where
         F: FnMut((i32, i32), (i32, i32), &mut T),
     {
+        let position = position.into_tuple();
         let mut reload = reload;
         if self.grid_offset == position {
             return;
         }
+        // Make sure that the new bounds fit in i32.
+        let (right, bottom) = add_size_2d(position, (self.size.0 as u32, self.size.1 as u32));
+        if self.region_log.is_some() {
+            self.record_regions(position);
+        }
         let (old_x, old_y) = self.grid_offset;
         let (new_x, new_y) = position;
-        let offset = (new_x - old_x, new_y - old_y);
+        // Positions too far apart to subtract in i32 can't overlap, so they take the
+        // full reload path below.
+        let offset = position.checked_sub_coord(self.grid_offset);
         let width = self.size.0 as i32;
         let height = self.size.1 as i32;
-        let (offset_x, offset_y) = offset;
         self.grid_offset = (new_x, new_y);
         // Offset is within bounds, so that means that the grid will be rolled.
         // This allows for bounded reloading of the grid elements.
         // If rolling causes a section to remain on the grid, that section will not be reloaded.
         // Only the elements that are considered new will be reloaded.
-        if offset_x.abs() < width && offset_y.abs() < height {
+        if let Some((offset_x, offset_y)) = offset.filter(|&(offset_x, offset_y)| {
+            offset_x.unsigned_abs() < width as u32 && offset_y.unsigned_abs() < height as u32
+        }) {
             let (roll_x, roll_y) = (self.wrap_offset.0 as i32, self.wrap_offset.1 as i32);
             let (wrapped_offset_x, wrapped_offset_y) =
                 (offset_x.rem_euclid(width), offset_y.rem_euclid(height));
@@ -701,9 +999,52 @@ impl<T> RollGrid2D<T> {
             // every single cell, even if it only needed to reload 8 out of 64 cells.
             let new_rolled_x = (roll_x + wrapped_offset_x).rem_euclid(width);
             let new_rolled_y = (roll_y + wrapped_offset_y).rem_euclid(height);
-            self.wrap_offset = (new_rolled_x, new_rolled_y);
-            let right = new_x + width;
-            let bottom = new_y + height;
+            self.wrap_offset = (new_rolled_x as u32, new_rolled_y as u32);
+            // Moves along a single axis only have a single strip to reload, so
+            // skip the partition setup and resolve the storage indices directly.
+            if offset_y == 0 {
+                let columns = if offset_x >= 0 {
+                    (right - offset_x)..right
+                } else {
+                    new_x..new_x - offset_x
+                };
+                for row in 0..height {
+                    let y = new_y + row;
+                    let row_start = (row + new_rolled_y).rem_euclid(height) as usize * self.size.0;
+                    for (xi, x) in columns.clone().enumerate() {
+                        let prior_x = if offset_x >= 0 {
+                            old_x + xi as i32
+                        } else {
+                            old_x + width + offset_x + xi as i32
+                        };
+                        let column = (x - new_x + new_rolled_x).rem_euclid(width) as usize;
+                        reload((prior_x, y), (x, y), &mut self.cells[row_start + column]);
+                    }
+                }
+                return;
+            }
+            if offset_x == 0 {
+                let rows = if offset_y >= 0 {
+                    (bottom - offset_y)..bottom
+                } else {
+                    new_y..new_y - offset_y
+                };
+                for (yi, y) in rows.enumerate() {
+                    let prior_y = if offset_y >= 0 {
+                        old_y + yi as i32
+                    } else {
+                        old_y + height + offset_y + yi as i32
+                    };
+                    let row_start =
+                        (y - new_y + new_rolled_y).rem_euclid(height) as usize * self.size.0;
+                    for column in 0..width {
+                        let x = new_x + column;
+                        let index = row_start + (column + new_rolled_x).rem_euclid(width) as usize;
+                        reload((x, prior_y), (x, y), &mut self.cells[index]);
+                    }
+                }
+                return;
+            }
             // Calculate ranges
             // Combining new_x_range and new_y_range gets the corner.
             // The partition on either the left or right side
@@ -797,26 +1138,35 @@ impl<T> RollGrid2D<T> {
     ///     *cell_mut = new_position;
     /// })
     /// ```
-    pub fn try_reposition<E, F>(&mut self, position: (i32, i32), reload: F) -> Result<(), E>
+    pub fn try_reposition<E, F>(&mut self, position: impl GridPos2, reload: F) -> Result<(), E>
     where
         F: FnMut((i32, i32), (i32, i32), &mut T) -> Result<(), E>,
     {
+        let position = position.into_tuple();
         if self.grid_offset == position {
             return Ok(());
         }
+        // Make sure that the new bounds fit in i32.
+        let (right, bottom) = add_size_2d(position, (self.size.0 as u32, self.size.1 as u32));
+        if self.region_log.is_some() {
+            self.record_regions(position);
+        }
         let (old_x, old_y) = self.grid_offset;
         let (new_x, new_y) = position;
-        let offset = (new_x - old_x, new_y - old_y);
+        // Positions too far apart to subtract in i32 can't overlap, so they take the
+        // full reload path below.
+        let offset = position.checked_sub_coord(self.grid_offset);
         let mut reload = reload;
         let width = self.size.0 as i32;
         let height = self.size.1 as i32;
-        let (offset_x, offset_y) = offset;
         self.grid_offset = (new_x, new_y);
         // Offset is within bounds, so that means that the grid will be rolled.
         // This allows for bounded reloading of the grid elements.
         // If rolling causes a section to remain on the grid, that section will not be reloaded.
         // Only the elements that are considered new will be reloaded.
-        if offset_x.abs() < width && offset_y.abs() < height {
+        if let Some((offset_x, offset_y)) = offset.filter(|&(offset_x, offset_y)| {
+            offset_x.unsigned_abs() < width as u32 && offset_y.unsigned_abs() < height as u32
+        }) {
             let (roll_x, roll_y) = (self.wrap_offset.0 as i32, self.wrap_offset.1 as i32);
             let (wrapped_offset_x, wrapped_offset_y) =
                 (offset_x.rem_euclid(width), offset_y.rem_euclid(height));
@@ -825,9 +1175,7 @@ impl<T> RollGrid2D<T> {
             // every single cell, even if it only needed to reload 8 out of 64 cells.
             let new_rolled_x = (roll_x + wrapped_offset_x).rem_euclid(width);
             let new_rolled_y = (roll_y + wrapped_offset_y).rem_euclid(height);
-            self.wrap_offset = (new_rolled_x, new_rolled_y);
-            let right = new_x + width;
-            let bottom = new_y + height;
+            self.wrap_offset = (new_rolled_x as u32, new_rolled_y as u32);
             // Calculate ranges
             // Combining new_x_range and new_y_range gets the corner.
             // The partition on either the left or right side
@@ -910,8 +1258,8 @@ impl<T> RollGrid2D<T> {
     }
 
     /// Get the offset relative to the grid's offset.
-    pub fn relative_offset(&self, coord: (i32, i32)) -> (i32, i32) {
-        let (x, y) = coord;
+    pub fn relative_offset(&self, coord: impl GridPos2) -> (i32, i32) {
+        let (x, y) = coord.into_tuple();
         (x - self.grid_offset.0, y - self.grid_offset.1)
     }
 
@@ -919,7 +1267,8 @@ impl<T> RollGrid2D<T> {
     /// This method allows to find the index of a particular offset in the grid.
     /// Offsets are relative to the world origin `(0, 0, 0)`, and must account for
     /// the grid offset.
-    fn offset_index(&self, (x, y): (i32, i32)) -> Option<usize> {
+    fn offset_index(&self, coord: impl GridPos2) -> Option<usize> {
+        let (x, y) = coord.into_tuple();
         let (mx, my) = self.grid_offset;
         let width = self.size.0 as i32;
         let height = self.size.1 as i32;
@@ -931,13 +1280,53 @@ impl<T> RollGrid2D<T> {
         let ny = y - my;
         // Wrap x and y
         let (wrap_x, wrap_y) = (self.wrap_offset.0 as i32, self.wrap_offset.1 as i32);
+        // Only an internal bug in the roll logic can put the wrap offset out of range, so
+        // these checks are left to debug builds.
+        debug_assert!(
+            (0..width).contains(&wrap_x) && (0..height).contains(&wrap_y),
+            "{WRAP_OFFSET_OUT_OF_RANGE}: {:?} is not within {:?}",
+            self.wrap_offset,
+            self.size,
+        );
         let wx = (nx + wrap_x).rem_euclid(width);
         let wy = (ny + wrap_y).rem_euclid(height);
-        Some((wy as usize * self.size.0) + wx as usize)
+        let index = (wy as usize * self.size.0) + wx as usize;
+        debug_assert!(index < self.cells.len(), "{INDEX_EXCEEDS_CAPACITY}");
+        Some(index)
+    }
+
+    /// Find the index of the storage slot at `coord` modulo the grid size, ignoring the
+    /// grid offset.
+    fn wrapped_index(&self, (x, y): (i32, i32)) -> usize {
+        if self.len() == 0 {
+            panic!("{GRID_IS_EMPTY}");
+        }
+        let x = (x as i64).rem_euclid(self.size.0 as i64) as usize;
+        let y = (y as i64).rem_euclid(self.size.1 as i64) as usize;
+        y * self.size.0 + x
+    }
+
+    /// Find the index of a coordinate after wrapping it into the bounds of the grid.
+    ///
+    /// Takes `i64` coordinates so that it can be used for positions outside the range of
+    /// `i32`, such as the neighbors of a coordinate at `i32::MIN`.
+    fn wrapped_index_wide(&self, (x, y): (i64, i64)) -> usize {
+        if self.len() == 0 {
+            panic!("{GRID_IS_EMPTY}");
+        }
+        let (mx, my) = self.grid_offset;
+        let width = self.size.0 as i64;
+        let height = self.size.1 as i64;
+        let nx = (x - mx as i64).rem_euclid(width);
+        let ny = (y - my as i64).rem_euclid(height);
+        let (wrap_x, wrap_y) = (self.wrap_offset.0 as i64, self.wrap_offset.1 as i64);
+        let wx = (nx + wrap_x).rem_euclid(width);
+        let wy = (ny + wrap_y).rem_euclid(height);
+        (wy as usize * self.size.0) + wx as usize
     }
 
     /// Reads the value from the cell without moving it. This leaves the memory in the cell unchanged.
-    pub unsafe fn read(&self, coord: (i32, i32)) -> Option<T> {
+    pub unsafe fn read(&self, coord: impl GridPos2) -> Option<T> {
         let index = self.offset_index(coord)?;
         Some(self.cells.read(index))
     }
@@ -949,30 +1338,439 @@ impl<T> RollGrid2D<T> {
     /// Additionally, it does not drop the contents of the cell. Semantically, `value` is moved into the cell at the given coordinate.
     ///
     /// This is appropriate for initializing uninitialized cells, or overwriting memory that has previously been [read] from.
-    pub unsafe fn write(&mut self, coord: (i32, i32), value: T) {
+    pub unsafe fn write(&mut self, coord: impl GridPos2, value: T) {
         let index = self.offset_index(coord).expect(OUT_OF_BOUNDS);
         self.cells.write(index, value);
     }
 
     /// Get a reference to the cell's value if it exists and the coord is in bounds, otherwise return `None`.
-    pub fn get(&self, coord: (i32, i32)) -> Option<&T> {
+    pub fn get(&self, coord: impl GridPos2) -> Option<&T> {
         let index = self.offset_index(coord)?;
         Some(&self.cells[index])
     }
 
     /// Get a mutable reference to the cell's value if it exists and the coord is in bounds, otherwise return `None`.
-    pub fn get_mut(&mut self, coord: (i32, i32)) -> Option<&mut T> {
+    pub fn get_mut(&mut self, coord: impl GridPos2) -> Option<&mut T> {
         let index = self.offset_index(coord)?;
         Some(&mut self.cells[index])
     }
 
     /// Set the cell's value, returning the old value in the process.
-    pub fn set(&mut self, coord: (i32, i32), value: T) -> Option<T> {
+    pub fn set(&mut self, coord: impl GridPos2, value: T) -> Option<T> {
         let index = self.offset_index(coord)?;
         let dest = &mut self.cells[index];
         Some(std::mem::replace(dest, value))
     }
 
+    /// Swap the values of the cells at `a` and `b`.
+    ///
+    /// Panics if either coordinate is out of bounds.
+    pub fn swap(&mut self, a: impl GridPos2, b: impl GridPos2) {
+        let ia = self.offset_index(a).expect(OUT_OF_BOUNDS);
+        let ib = self.offset_index(b).expect(OUT_OF_BOUNDS);
+        self.cells.as_mut_slice().swap(ia, ib);
+    }
+
+    /// Set every cell on the perimeter of the grid to `value`, leaving the interior untouched.
+    pub fn fill_border(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.bounds().iter_border().for_each(|coord| {
+            let index = self.offset_index(coord).expect(OUT_OF_BOUNDS);
+            self.cells[index] = value.clone();
+        });
+    }
+
+    /// Get a [Grid2D] of references to the cells within `bounds`.
+    ///
+    /// Panics if `bounds` is empty or not entirely within the grid.
+    pub fn subgrid(&self, bounds: Bounds2D) -> Grid2D<&T> {
+        self.try_subgrid(bounds)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Get a [Grid2D] of references to the cells within `bounds`.
+    ///
+    /// Returns [RollGridError::OutOfBounds] if `bounds` is not entirely within the grid,
+    /// or [RollGridError::EmptyBounds] if it has no cells.
+    pub fn try_subgrid(&self, bounds: Bounds2D) -> Result<Grid2D<&T>, RollGridError> {
+        self.check_subgrid(bounds)?;
+        Ok(Grid2D::new(
+            bounds.width() as usize,
+            bounds.height() as usize,
+            bounds.min,
+            |pos| &self.cells[self.offset_index(pos).expect(OUT_OF_BOUNDS)],
+        ))
+    }
+
+    /// Get a [Grid2D] of mutable references to the cells within `bounds`.
+    ///
+    /// Panics if `bounds` is empty or not entirely within the grid.
+    pub fn subgrid_mut(&mut self, bounds: Bounds2D) -> Grid2D<&mut T> {
+        if let Err(err) = self.check_subgrid(bounds) {
+            panic!("{err}");
+        }
+        let cells_ptr = unsafe { self.cells.as_mut_ptr() };
+        let grid = &*self;
+        Grid2D::new(
+            bounds.width() as usize,
+            bounds.height() as usize,
+            bounds.min,
+            // Each coordinate maps to a unique index, so no cell is borrowed twice.
+            |pos| unsafe { &mut *cells_ptr.add(grid.offset_index(pos).expect(OUT_OF_BOUNDS)) },
+        )
+    }
+
+    /// Copy the cells within `bounds` into a new [Grid2D].
+    ///
+    /// Panics if `bounds` is empty or not entirely within the grid.
+    pub fn copy_subgrid(&self, bounds: Bounds2D) -> Grid2D<T>
+    where
+        T: Copy,
+    {
+        self.try_copy_subgrid(bounds)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Copy the cells within `bounds` into a new [Grid2D].
+    ///
+    /// Returns [RollGridError::OutOfBounds] if `bounds` is not entirely within the grid,
+    /// or [RollGridError::EmptyBounds] if it has no cells.
+    pub fn try_copy_subgrid(&self, bounds: Bounds2D) -> Result<Grid2D<T>, RollGridError>
+    where
+        T: Copy,
+    {
+        self.check_subgrid(bounds)?;
+        Ok(Grid2D::new(
+            bounds.width() as usize,
+            bounds.height() as usize,
+            bounds.min,
+            |pos| self.cells[self.offset_index(pos).expect(OUT_OF_BOUNDS)],
+        ))
+    }
+
+    /// Clone the cells within `bounds` into a new [Grid2D].
+    ///
+    /// Panics if `bounds` is empty or not entirely within the grid.
+    pub fn clone_subgrid(&self, bounds: Bounds2D) -> Grid2D<T>
+    where
+        T: Clone,
+    {
+        self.try_clone_subgrid(bounds)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Clone the cells within `bounds` into a new [Grid2D].
+    ///
+    /// Returns [RollGridError::OutOfBounds] if `bounds` is not entirely within the grid,
+    /// or [RollGridError::EmptyBounds] if it has no cells.
+    pub fn try_clone_subgrid(&self, bounds: Bounds2D) -> Result<Grid2D<T>, RollGridError>
+    where
+        T: Clone,
+    {
+        self.check_subgrid(bounds)?;
+        Ok(Grid2D::new(
+            bounds.width() as usize,
+            bounds.height() as usize,
+            bounds.min,
+            |pos| self.cells[self.offset_index(pos).expect(OUT_OF_BOUNDS)].clone(),
+        ))
+    }
+
+    /// Clone the cells within `src` into the region of the same size whose minimum corner
+    /// is `dst_min`.
+    ///
+    /// The source and destination may overlap; the source cells are cloned before any
+    /// cell is written. Source cells outside the grid and destination cells outside the
+    /// grid are skipped.
+    pub fn copy_within(&mut self, src: Bounds2D, dst_min: (i32, i32))
+    where
+        T: Clone,
+    {
+        let delta = (
+            dst_min.0 as i64 - src.min.0 as i64,
+            dst_min.1 as i64 - src.min.1 as i64,
+        );
+        let Some(src) = src.intersection(self.bounds()) else {
+            return;
+        };
+        let grid_bounds = self.bounds();
+        let copies: Vec<(usize, T)> = src
+            .iter()
+            .filter_map(|(x, y)| {
+                let dst = (
+                    i32::try_from(x as i64 + delta.0).ok()?,
+                    i32::try_from(y as i64 + delta.1).ok()?,
+                );
+                if !grid_bounds.contains(dst) {
+                    return None;
+                }
+                let index = self.offset_index(dst).expect(OUT_OF_BOUNDS);
+                Some((index, self.get((x, y)).expect(OUT_OF_BOUNDS).clone()))
+            })
+            .collect();
+        copies
+            .into_iter()
+            .for_each(|(index, value)| self.cells[index] = value);
+    }
+
+    fn check_subgrid(&self, bounds: Bounds2D) -> Result<(), RollGridError> {
+        if bounds.intersection(self.bounds()) != Some(bounds) {
+            Err(RollGridError::OutOfBounds)
+        } else if bounds.area() == 0 {
+            Err(RollGridError::EmptyBounds)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Get a reference to the cell at `coord`, treating the grid as a torus.
+    ///
+    /// This ignores the grid offset and indexes the underlying buffer purely modulo the
+    /// grid size, so `coord` and `coord + size` refer to the same cell. The storage slot
+    /// has no fixed relation to the cell that [get](Self::get) returns for `coord`; use
+    /// [toroidal](Self::toroidal) to wrap coordinates into the grid's bounds instead.
+    ///
+    /// Panics if the grid is empty.
+    pub fn get_wrapped(&self, coord: impl GridPos2) -> &T {
+        let index = self.wrapped_index(coord.into_tuple());
+        &self.cells[index]
+    }
+
+    /// Get a mutable reference to the cell at `coord`, treating the grid as a torus.
+    ///
+    /// See [get_wrapped](Self::get_wrapped).
+    pub fn get_wrapped_mut(&mut self, coord: impl GridPos2) -> &mut T {
+        let index = self.wrapped_index(coord.into_tuple());
+        &mut self.cells[index]
+    }
+
+    /// Get a view of the grid that wraps coordinates into the grid's bounds when indexed
+    /// rather than panicking.
+    ///
+    /// Unlike [get_wrapped](Self::get_wrapped), the view wraps relative to the grid offset,
+    /// so in-bounds coordinates always index the same cell as [get](Self::get).
+    ///
+    /// Indexing the view panics if the grid is empty.
+    pub fn toroidal(&self) -> Toroidal<'_, T> {
+        Toroidal(self)
+    }
+
+    /// Iterate the in-bounds orthogonal neighbors of `coord` (up to 4) along with their coordinates.
+    ///
+    /// Neighbors are yielded in the order `-Y`, `+X`, `+Y`, `-X`.
+    pub fn von_neumann_neighbors(
+        &self,
+        coord: (i32, i32),
+    ) -> impl Iterator<Item = ((i32, i32), &T)> {
+        const OFFSETS: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+        self.neighbors(coord, OFFSETS)
+    }
+
+    /// Iterate the in-bounds surrounding cells of `coord` (up to 8) along with their coordinates.
+    ///
+    /// Neighbors are yielded in row-major order, skipping `coord` itself.
+    pub fn moore_neighbors(&self, coord: (i32, i32)) -> impl Iterator<Item = ((i32, i32), &T)> {
+        const OFFSETS: [(i32, i32); 8] = [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (-1, 0),
+            (1, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+        ];
+        self.neighbors(coord, OFFSETS)
+    }
+
+    /// Get the eight surrounding cells of `coord`, or `None` for the neighbors that are out
+    /// of bounds.
+    ///
+    /// Neighbors are returned clockwise starting from the `-X -Y` corner: `(-1, -1)`,
+    /// `(0, -1)`, `(1, -1)`, `(1, 0)`, `(1, 1)`, `(0, 1)`, `(-1, 1)`, `(-1, 0)`.
+    pub fn moore(&self, coord: (i32, i32)) -> [Option<&T>; 8] {
+        let (x, y) = coord;
+        MOORE_CLOCKWISE.map(|(dx, dy)| self.get((x.checked_add(dx)?, y.checked_add(dy)?)))
+    }
+
+    /// Get the eight surrounding cells of `coord`, treating the grid as a torus.
+    ///
+    /// Neighbors are returned in the same order as [moore](Self::moore), and are wrapped
+    /// into the grid's bounds the same way as [toroidal](Self::toroidal).
+    pub fn moore_wrapping(&self, coord: (i32, i32)) -> [&T; 8] {
+        let (x, y) = (coord.0 as i64, coord.1 as i64);
+        MOORE_CLOCKWISE
+            .map(|(dx, dy)| &self.cells[self.wrapped_index_wide((x + dx as i64, y + dy as i64))])
+    }
+
+    /// Get the cells of the 2x2 block with `coord` as its minimum corner, in the order
+    /// `(x, y)`, `(x + 1, y)`, `(x, y + 1)`, `(x + 1, y + 1)`.
+    ///
+    /// Returns `None` if any of the cells are out of bounds.
+    pub fn quad(&self, coord: (i32, i32)) -> Option<[&T; 4]> {
+        let (x, y) = coord;
+        let (x1, y1) = (x.checked_add(1)?, y.checked_add(1)?);
+        Some([
+            self.get((x, y))?,
+            self.get((x1, y))?,
+            self.get((x, y1))?,
+            self.get((x1, y1))?,
+        ])
+    }
+
+    /// Reduce each `factor.0` by `factor.1` block of cells into a single value.
+    ///
+    /// Blocks are anchored at the grid's offset, so cell `(i, j)` of the resulting
+    /// grid is the reduction of the block whose minimum corner is
+    /// `offset + (i * factor.0, j * factor.1)`. The resulting grid has an offset of `(0, 0)`.
+    /// When the grid size is not a multiple of `factor`, the blocks along the maximum
+    /// edges are partial and `reduce` receives fewer cells.
+    ///
+    /// The cells of each block are passed to `reduce` in `x -> y` order.
+    ///
+    /// Panics if either component of `factor` is `0` or if the grid is empty.
+    pub fn downsample<U, F: FnMut(&[&T]) -> U>(
+        &self,
+        factor: (u32, u32),
+        mut reduce: F,
+    ) -> Grid2D<U> {
+        if factor.0 == 0 || factor.1 == 0 {
+            panic!("{AREA_IS_ZERO}");
+        }
+        if self.len() == 0 {
+            panic!("{GRID_IS_EMPTY}");
+        }
+        let (fx, fy) = (factor.0 as usize, factor.1 as usize);
+        let (width, height) = self.size;
+        let bounds = self.bounds();
+        let mut block = Vec::with_capacity(fx.saturating_mul(fy).min(self.len()));
+        Grid2D::new(width.div_ceil(fx), height.div_ceil(fy), (0, 0), |(i, j)| {
+            let min_x = bounds.min.0 as i64 + i as i64 * fx as i64;
+            let min_y = bounds.min.1 as i64 + j as i64 * fy as i64;
+            let max_x = (min_x + fx as i64).min(bounds.max.0 as i64);
+            let max_y = (min_y + fy as i64).min(bounds.max.1 as i64);
+            block.clear();
+            for y in min_y..max_y {
+                for x in min_x..max_x {
+                    block.push(self.get((x as i32, y as i32)).expect(OUT_OF_BOUNDS));
+                }
+            }
+            reduce(&block)
+        })
+    }
+
+    /// Replicate each cell into a `factor.0` by `factor.1` block, producing a grid
+    /// `factor` times larger.
+    ///
+    /// The grid offset is scaled by `factor` as well, so the cell at `(x, y)` fills the
+    /// block whose minimum corner is `(x * factor.0, y * factor.1)`. In other words,
+    /// the cell at `coord` in the resulting grid holds the cell at
+    /// `(coord.0.div_euclid(factor.0), coord.1.div_euclid(factor.1))` in `self`.
+    ///
+    /// Panics if either component of `factor` is `0`, if the grid is empty, if the resulting
+    /// size is too large, or if the scaled bounds would overflow.
+    pub fn upsample(&self, factor: (u32, u32)) -> RollGrid2D<T>
+    where
+        T: Clone,
+    {
+        if factor.0 == 0 || factor.1 == 0 {
+            panic!("{AREA_IS_ZERO}");
+        }
+        if self.len() == 0 {
+            panic!("{GRID_IS_EMPTY}");
+        }
+        let bounds = self.bounds().scaled_up(factor);
+        let (width, height) = (bounds.width() as usize, bounds.height() as usize);
+        RollGrid2D::new(width, height, bounds.min, |(x, y)| {
+            let source = (x.div_euclid(factor.0 as i32), y.div_euclid(factor.1 as i32));
+            self.get(source).expect(OUT_OF_BOUNDS).clone()
+        })
+    }
+
+    fn neighbors<const N: usize>(
+        &self,
+        coord: (i32, i32),
+        offsets: [(i32, i32); N],
+    ) -> impl Iterator<Item = ((i32, i32), &T)> {
+        offsets.into_iter().filter_map(move |(x, y)| {
+            let pos = (coord.0.checked_add(x)?, coord.1.checked_add(y)?);
+            Some((pos, self.get(pos)?))
+        })
+    }
+
+    /// Swap the cells in row `y1` with the cells in row `y2`.
+    ///
+    /// Panics if either row is out of bounds.
+    pub fn swap_rows(&mut self, y1: i32, y2: i32) {
+        if y1 < self.y_min() || y1 >= self.y_max() || y2 < self.y_min() || y2 >= self.y_max() {
+            panic!("{OUT_OF_BOUNDS}");
+        }
+        if y1 == y2 {
+            return;
+        }
+        for x in self.x_min()..self.x_max() {
+            let a = self.offset_index((x, y1)).expect(OUT_OF_BOUNDS);
+            let b = self.offset_index((x, y2)).expect(OUT_OF_BOUNDS);
+            self.cells.swap(a, b);
+        }
+    }
+
+    /// Swap the cells in column `x1` with the cells in column `x2`.
+    ///
+    /// Panics if either column is out of bounds.
+    pub fn swap_columns(&mut self, x1: i32, x2: i32) {
+        if x1 < self.x_min() || x1 >= self.x_max() || x2 < self.x_min() || x2 >= self.x_max() {
+            panic!("{OUT_OF_BOUNDS}");
+        }
+        if x1 == x2 {
+            return;
+        }
+        for y in self.y_min()..self.y_max() {
+            let a = self.offset_index((x1, y)).expect(OUT_OF_BOUNDS);
+            let b = self.offset_index((x2, y)).expect(OUT_OF_BOUNDS);
+            self.cells.swap(a, b);
+        }
+    }
+
+    /// Rearrange the cells in memory so that the wrap offset is zero on both axes.
+    /// After normalizing, the backing storage is in row-major coordinate order.
+    pub fn normalize(&mut self) {
+        self.normalize_axis(Axis::X);
+        self.normalize_axis(Axis::Y);
+    }
+
+    /// Rearrange the cells in memory so that the wrap offset is zero on `axis`,
+    /// leaving the wrap offset of the other axis unchanged.
+    ///
+    /// [Axis::Z] does not exist on a 2D grid, so normalizing it does nothing.
+    pub fn normalize_axis(&mut self, axis: Axis) {
+        let width = self.size.0;
+        match axis {
+            Axis::X => {
+                let wrap_x = self.wrap_offset.0 as usize;
+                if wrap_x == 0 {
+                    return;
+                }
+                self.cells
+                    .as_mut_slice()
+                    .chunks_exact_mut(width)
+                    .for_each(|row| row.rotate_left(wrap_x));
+                self.wrap_offset.0 = 0;
+            }
+            Axis::Y => {
+                let wrap_y = self.wrap_offset.1 as usize;
+                if wrap_y == 0 {
+                    return;
+                }
+                self.cells.as_mut_slice().rotate_left(wrap_y * width);
+                self.wrap_offset.1 = 0;
+            }
+            Axis::Z => (),
+        }
+    }
+
     /// Get the dimensions of the grid.
     pub fn size(&self) -> (usize, usize) {
         self.size
@@ -993,13 +1791,35 @@ impl<T> RollGrid2D<T> {
         self.grid_offset
     }
 
+    /// Get the offset of the grid floor-divided by `unit`, for example to get the chunk
+    /// that contains the grid offset when `unit` is the chunk size.
+    ///
+    /// Panics if either dimension of `unit` is zero.
+    pub fn offset_in_units(&self, unit: (u32, u32)) -> (i32, i32) {
+        if unit.0 == 0 || unit.1 == 0 {
+            panic!("{AREA_IS_ZERO}");
+        }
+        (
+            (self.grid_offset.0 as i64).div_euclid(unit.0 as i64) as i32,
+            (self.grid_offset.1 as i64).div_euclid(unit.1 as i64) as i32,
+        )
+    }
+
+    /// Get the internal wrap offset of the grid, which is where the cell at the
+    /// grid offset is located in the backing storage on each axis.
+    ///
+    /// This is mostly useful for debugging.
+    pub fn wrap_offset(&self) -> (u32, u32) {
+        self.wrap_offset
+    }
+
     /// Get the minimum bound on the `X` axis.
     pub fn x_min(&self) -> i32 {
         self.grid_offset.0
     }
     /// Get the maximum bound on the `X` axis.
     pub fn x_max(&self) -> i32 {
-        self.grid_offset.0 + self.size.0 as i32
+        checked_add_u32_to_i32(self.grid_offset.0, self.size.0 as u32).expect(X_MAX_EXCEEDS_MAXIMUM)
     }
 
     /// Get the minimum bound on the `Y` axis.
@@ -1009,7 +1829,7 @@ impl<T> RollGrid2D<T> {
 
     /// Get the maximum bound on the `Y` axis.
     pub fn y_max(&self) -> i32 {
-        self.grid_offset.1 + self.size.1 as i32
+        checked_add_u32_to_i32(self.grid_offset.1, self.size.1 as u32).expect(Y_MAX_EXCEEDS_MAXIMUM)
     }
 
     /// Get the bounds of the grid.
@@ -1025,6 +1845,74 @@ impl<T> RollGrid2D<T> {
         self.size.0 * self.size.1
     }
 
+    /// The number of cells the backing storage has allocated space for.
+    ///
+    /// Resizing always allocates exactly the new size, so this is equal to [len](Self::len).
+    pub fn capacity(&self) -> usize {
+        self.cells.capacity()
+    }
+
+    /// Get the backing storage of the grid as a slice.
+    ///
+    /// Cells are in storage order, which only matches coordinate order
+    /// when the wrap offset is zero.
+    pub fn as_slice(&self) -> &[T] {
+        self.cells.as_slice()
+    }
+
+    /// Get the backing storage of the grid as a mutable slice.
+    ///
+    /// Cells are in storage order, which only matches coordinate order
+    /// when the wrap offset is zero.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.cells.as_mut_slice()
+    }
+
+    /// Clone the cells into a [Vec] in coordinate order (`x -> y`, from `min` to `max`),
+    /// independent of the wrap offset.
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().map(|(_, cell)| cell.clone()).collect()
+    }
+
+    /// Iterate the backing storage as contiguous slices in coordinate order (`x -> y`).
+    ///
+    /// Each row is yielded as one slice, or as two slices when the wrap offset splits it,
+    /// so concatenating the slices gives the same sequence as [to_vec](Self::to_vec)
+    /// without copying or normalizing the grid.
+    pub fn logical_chunks(&self) -> impl Iterator<Item = &[T]> {
+        let (width, height) = self.size;
+        let (wrap_x, wrap_y) = (self.wrap_offset.0 as usize, self.wrap_offset.1 as usize);
+        let cells = self.cells.as_slice();
+        (0..height).flat_map(move |y| {
+            let start = ((y + wrap_y) % height) * width;
+            let row = &cells[start..start + width];
+            let (tail, head) = row.split_at(wrap_x);
+            [head, tail].into_iter().filter(|chunk| !chunk.is_empty())
+        })
+    }
+
+    /// Create a [Grid2D] with the same bounds as the grid, where each cell holds its own coordinate.
+    pub fn coordinate_grid(&self) -> Grid2D<(i32, i32)> {
+        Grid2D::new(self.size.0, self.size.1, self.grid_offset, |pos| pos)
+    }
+
+    /// Split the backing storage into `n` contiguous index ranges of near-equal size
+    /// that together cover `0..len`. This is useful for distributing work across threads
+    /// with [as_mut_slice](Self::as_mut_slice).
+    ///
+    /// The ranges index the backing storage, not coordinates. Cells must be normalized
+    /// (the wrap offset must be zero) for index `i` to correspond to the `i`th coordinate
+    /// in iteration order.
+    ///
+    /// If `n` is greater than `len`, the trailing ranges are empty. If `n` is zero, no ranges
+    /// are returned.
+    pub fn work_ranges(&self, n: usize) -> Vec<std::ops::Range<usize>> {
+        self.cells.work_ranges(n)
+    }
+
     /// Get an iterator over the cells in the grid.
     pub fn iter<'a>(&'a self) -> RollGrid2DIterator<'a, T> {
         RollGrid2DIterator {
@@ -1040,24 +1928,395 @@ impl<T> RollGrid2D<T> {
             grid: self,
         }
     }
+
+    /// Replace every cell with the result of `f`, which takes the cell's coordinate and
+    /// its old value by value. Cells are visited in coordinate order.
+    ///
+    /// While `f` runs it owns the old value of the cell being transformed. If `f` panics,
+    /// every other cell is dropped and the backing storage is freed, so nothing is leaked
+    /// or dropped twice. The grid is left empty with a size of `(0, 0)`, the same as after a
+    /// failed [try_reset](Self::try_reset), until [resize](Self::resize) gives it cells again.
+    pub fn transform_all<F: FnMut((i32, i32), T) -> T>(&mut self, f: F) {
+        /// Drops every cell except the one that is owned by `f` if `f` panics, leaving the
+        /// grid empty.
+        struct PanicGuard<'a, T> {
+            cells: &'a mut FixedArray<T>,
+            size: &'a mut (usize, usize),
+            wrap_offset: &'a mut (u32, u32),
+            hole: usize,
+        }
+        impl<T> Drop for PanicGuard<'_, T> {
+            fn drop(&mut self) {
+                unsafe {
+                    if std::mem::needs_drop::<T>() {
+                        for index in (0..self.cells.len()).filter(|&index| index != self.hole) {
+                            self.cells.drop_in_place(index);
+                        }
+                    }
+                    self.cells.forget_dealloc();
+                }
+                *self.cells = FixedArray::unallocated();
+                *self.size = (0, 0);
+                *self.wrap_offset = (0, 0);
+            }
+        }
+        let mut f = f;
+        for pos in self.bounds().iter() {
+            let index = self.offset_index(pos).expect(OUT_OF_BOUNDS);
+            let guard = PanicGuard {
+                cells: &mut self.cells,
+                size: &mut self.size,
+                wrap_offset: &mut self.wrap_offset,
+                hole: index,
+            };
+            unsafe {
+                let value = f(pos, guard.cells.read(index));
+                guard.cells.write(index, value);
+            }
+            std::mem::forget(guard);
+        }
+    }
+
+    /// Get an iterator over the cells in the intersection of `bounds` and the grid's bounds.
+    ///
+    /// If `bounds` does not intersect the grid, the iterator is empty.
+    pub fn iter_region<'a>(&'a self, bounds: Bounds2D) -> RollGrid2DIterator<'a, T> {
+        RollGrid2DIterator {
+            bounds_iter: self.region_bounds(bounds).iter(),
+            grid: self,
+        }
+    }
+
+    /// Get a mutable iterator over the cells in the intersection of `bounds` and the grid's
+    /// bounds.
+    ///
+    /// If `bounds` does not intersect the grid, the iterator is empty.
+    pub fn iter_region_mut<'a>(&'a mut self, bounds: Bounds2D) -> RollGrid2DMutIterator<'a, T> {
+        RollGrid2DMutIterator {
+            bounds_iter: self.region_bounds(bounds).iter(),
+            grid: self,
+        }
+    }
+
+    /// Iterate the rows of the grid from the minimum `Y` to the maximum. Each row yields
+    /// its cells from the minimum `X` to the maximum.
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        self.bounds()
+            .iter_rows()
+            .map(move |(y, xs)| xs.map(move |x| self.get((x, y)).expect(OUT_OF_BOUNDS)))
+    }
+
+    /// Iterate the columns of the grid from the minimum `X` to the maximum. Each column
+    /// yields its cells from the minimum `Y` to the maximum.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let bounds = self.bounds();
+        let y_range = bounds.y_min()..bounds.y_max();
+        (bounds.x_min()..bounds.x_max()).map(move |x| {
+            y_range
+                .clone()
+                .map(move |y| self.get((x, y)).expect(OUT_OF_BOUNDS))
+        })
+    }
+
+    /// Clamp `bounds` to the grid's bounds, returning empty bounds if they don't intersect.
+    fn region_bounds(&self, bounds: Bounds2D) -> Bounds2D {
+        let grid_bounds = self.bounds();
+        bounds
+            .intersection(grid_bounds)
+            .unwrap_or(Bounds2D::new(grid_bounds.min, grid_bounds.min))
+    }
+
+    /// Render the physical index in the backing storage of each logical cell, to help
+    /// visualize the wrap.
+    ///
+    /// The first line holds the bounds and wrap offset. It is followed by one line per row,
+    /// from the minimum `Y` to the maximum, listing the indices from the minimum `X` to the
+    /// maximum.
+    pub fn debug_layout(&self) -> String {
+        use std::fmt::Write;
+        let bounds = self.bounds();
+        let digits = self.len().saturating_sub(1).to_string().len();
+        let mut layout = format!("{bounds} wrap_offset: {:?}\n", self.wrap_offset());
+        for y in bounds.y_min()..bounds.y_max() {
+            let row: Vec<String> = (bounds.x_min()..bounds.x_max())
+                .map(|x| {
+                    let index = self.offset_index((x, y)).expect(OUT_OF_BOUNDS);
+                    format!("{index:>digits$}")
+                })
+                .collect();
+            writeln!(layout, "{y}: {}", row.join(" ")).unwrap();
+        }
+        layout
+    }
+
+    /// Get a parallel iterator over the cells in the grid.
+    ///
+    /// Cells are visited in storage order rather than coordinate order.
+    #[cfg(feature = "rayon")]
+    pub fn par_iter(&self) -> impl rayon::iter::IndexedParallelIterator<Item = ((i32, i32), &T)>
+    where
+        T: Sync,
+    {
+        use rayon::prelude::*;
+        let (size, wrap_offset, grid_offset) = (self.size, self.wrap_offset, self.grid_offset);
+        self.cells
+            .as_slice()
+            .par_iter()
+            .enumerate()
+            .map(move |(index, cell)| (index_coord(size, wrap_offset, grid_offset, index), cell))
+    }
+
+    /// Get a mutable parallel iterator over the cells in the grid.
+    ///
+    /// Cells are visited in storage order rather than coordinate order.
+    #[cfg(feature = "rayon")]
+    pub fn par_iter_mut(
+        &mut self,
+    ) -> impl rayon::iter::IndexedParallelIterator<Item = ((i32, i32), &mut T)>
+    where
+        T: Send,
+    {
+        use rayon::prelude::*;
+        let (size, wrap_offset, grid_offset) = (self.size, self.wrap_offset, self.grid_offset);
+        self.cells
+            .as_mut_slice()
+            .par_iter_mut()
+            .enumerate()
+            .map(move |(index, cell)| (index_coord(size, wrap_offset, grid_offset, index), cell))
+    }
+
+    /// Group the coordinates of the cells in the grid by the key that `key` produces
+    /// for each cell. The coordinates of each group are in iteration order.
+    pub fn group_by<K: Eq + Hash, F: FnMut(&T) -> K>(
+        &self,
+        mut key: F,
+    ) -> HashMap<K, Vec<(i32, i32)>> {
+        let mut groups: HashMap<K, Vec<(i32, i32)>> = HashMap::new();
+        self.iter().for_each(|(pos, cell)| {
+            groups.entry(key(cell)).or_default().push(pos);
+        });
+        groups
+    }
+
+    /// Get an iterator over the cells in the grid along with whether
+    /// each cell lies on the perimeter of the grid.
+    pub fn iter_with_edge(&self) -> impl Iterator<Item = ((i32, i32), &T, bool)> {
+        let bounds = self.bounds();
+        self.iter().map(move |(pos, cell)| {
+            let edge = pos.0 == bounds.x_min()
+                || pos.1 == bounds.y_min()
+                || pos.0 == bounds.x_max() - 1
+                || pos.1 == bounds.y_max() - 1;
+            (pos, cell, edge)
+        })
+    }
+}
+
+impl<U> RollGrid2D<Option<U>> {
+    /// Get an iterator over the occupied cells in the grid, yielding the inner values of the
+    /// cells that are `Some` and skipping the cells that are `None`.
+    pub fn iter_some(&self) -> impl Iterator<Item = ((i32, i32), &U)> {
+        self.iter()
+            .filter_map(|(pos, cell)| cell.as_ref().map(|value| (pos, value)))
+    }
 }
 
 impl<T: Copy> RollGrid2D<T> {
     /// Get a copy of the grid value.
-    pub fn get_copy(&self, coord: (i32, i32)) -> Option<T> {
+    pub fn get_copy(&self, coord: impl GridPos2) -> Option<T> {
         let index = self.offset_index(coord)?;
         Some(self.cells[index])
     }
 }
 
 impl<T: Clone> RollGrid2D<T> {
+    /// Take a [GridSnapshot] of the current cells and their coordinates.
+    pub fn snapshot(&self) -> GridSnapshot<T> {
+        GridSnapshot {
+            bounds: self.bounds(),
+            cells: self.iter().map(|(_, cell)| cell.clone()).collect(),
+        }
+    }
+
     /// Get a clone of the grid value.
-    pub fn get_clone(&self, coord: (i32, i32)) -> Option<T> {
+    pub fn get_clone(&self, coord: impl GridPos2) -> Option<T> {
         let index = self.offset_index(coord)?;
         Some(self.cells[index].clone())
     }
 }
 
+impl<T: PartialEq> RollGrid2D<T> {
+    /// Get the cells that differ from `snapshot`, along with their current values.
+    ///
+    /// Cells at coordinates that are not within the snapshot are always reported.
+    pub fn diff_snapshot(&self, snapshot: &GridSnapshot<T>) -> Vec<((i32, i32), &T)> {
+        self.iter()
+            .filter(|(pos, cell)| snapshot.get(*pos) != Some(*cell))
+            .collect()
+    }
+
+    /// Write the cells that differ from `previous` to `w` as `(coord, value)` pairs,
+    /// using `encode` to write each value.
+    ///
+    /// The delta starts with the number of pairs as a little-endian `u64`, followed by
+    /// each coordinate as two little-endian `i32`s and the encoded value. Cells at
+    /// coordinates that are not within `previous` are always written. The delta can be
+    /// read back with [apply_delta](Self::apply_delta).
+    pub fn encode_delta<W, F>(
+        &self,
+        previous: &Grid2D<T>,
+        w: &mut W,
+        mut encode: F,
+    ) -> io::Result<()>
+    where
+        W: io::Write,
+        F: FnMut(&T, &mut W) -> io::Result<()>,
+    {
+        let changed: Vec<_> = self
+            .iter()
+            .filter(|(pos, cell)| previous.get(*pos) != Some(*cell))
+            .collect();
+        w.write_all(&(changed.len() as u64).to_le_bytes())?;
+        for ((x, y), cell) in changed {
+            w.write_all(&x.to_le_bytes())?;
+            w.write_all(&y.to_le_bytes())?;
+            encode(cell, w)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> RollGrid2D<T> {
+    /// Read a delta written by [encode_delta](Self::encode_delta) from `r` and apply it
+    /// to the grid, using `decode` to read each value.
+    ///
+    /// Returns an error with [io::ErrorKind::InvalidData] if the delta contains a
+    /// coordinate that is not within the grid. Pairs before the offending coordinate
+    /// will already have been applied.
+    pub fn apply_delta<R, F>(&mut self, r: &mut R, mut decode: F) -> io::Result<()>
+    where
+        R: io::Read,
+        F: FnMut(&mut R) -> io::Result<T>,
+    {
+        let mut count = [0u8; 8];
+        r.read_exact(&mut count)?;
+        for _ in 0..u64::from_le_bytes(count) {
+            let mut coord = [0u8; 4];
+            r.read_exact(&mut coord)?;
+            let x = i32::from_le_bytes(coord);
+            r.read_exact(&mut coord)?;
+            let y = i32::from_le_bytes(coord);
+            let value = decode(r)?;
+            let Some(cell) = self.get_mut((x, y)) else {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{OUT_OF_BOUNDS}: {:?}", (x, y)),
+                ));
+            };
+            *cell = value;
+        }
+        Ok(())
+    }
+}
+
+impl<T> RollGrid for RollGrid2D<T> {
+    type Coord = (i32, i32);
+    type Coords = Bounds2DIter;
+
+    fn coords(&self) -> Self::Coords {
+        self.bounds().iter()
+    }
+}
+
+/// Formats the size, offset, and cells of the grid, with the cells as a list of rows
+/// in coordinate order (independent of the wrap offset).
+impl<T: std::fmt::Debug> std::fmt::Debug for RollGrid2D<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows: Vec<Vec<&T>> = self
+            .bounds()
+            .iter_rows()
+            .map(|(y, xs)| xs.map(|x| self.get((x, y)).expect(OUT_OF_BOUNDS)).collect())
+            .collect();
+        f.debug_struct("RollGrid2D")
+            .field("size", &self.size)
+            .field("offset", &self.grid_offset)
+            .field("cells", &rows)
+            .finish()
+    }
+}
+
+/// Grids are equal when they have the same size, offset, and cells at each coordinate.
+/// The wrap offset is an internal detail, so it is not compared.
+impl<T: PartialEq> PartialEq for RollGrid2D<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size
+            && self.grid_offset == other.grid_offset
+            && self.iter().zip(other.iter()).all(|((_, a), (_, b))| a == b)
+    }
+}
+
+impl<T: Eq> Eq for RollGrid2D<T> {}
+
+impl<T, C: GridPos2> std::ops::Index<C> for RollGrid2D<T> {
+    type Output = T;
+
+    /// Get a reference to the cell at `index`, panicking if it is out of bounds.
+    fn index(&self, index: C) -> &Self::Output {
+        let index = index.into_tuple();
+        self.get(index)
+            .unwrap_or_else(|| panic!("{OUT_OF_BOUNDS}: {index:?} is not in {}", self.bounds()))
+    }
+}
+
+impl<T, C: GridPos2> std::ops::IndexMut<C> for RollGrid2D<T> {
+    /// Get a mutable reference to the cell at `index`, panicking if it is out of bounds.
+    fn index_mut(&mut self, index: C) -> &mut Self::Output {
+        let index = index.into_tuple();
+        let cell_index = self
+            .offset_index(index)
+            .unwrap_or_else(|| panic!("{OUT_OF_BOUNDS}: {index:?} is not in {}", self.bounds()));
+        &mut self.cells[cell_index]
+    }
+}
+
+/// A view of a [RollGrid2D] that treats it as a torus when indexed, created with
+/// [RollGrid2D::toroidal].
+pub struct Toroidal<'a, T>(&'a RollGrid2D<T>);
+
+impl<'a, T> std::ops::Index<(i32, i32)> for Toroidal<'a, T> {
+    type Output = T;
+
+    /// Get a reference to the cell at `index`, wrapping it into the grid's bounds.
+    fn index(&self, index: (i32, i32)) -> &Self::Output {
+        let (x, y) = index;
+        &self.0.cells[self.0.wrapped_index_wide((x as i64, y as i64))]
+    }
+}
+
+/// A copy of the cells of a [RollGrid2D] at a point in time.
+pub struct GridSnapshot<T> {
+    bounds: Bounds2D,
+    cells: Vec<T>,
+}
+
+impl<T> GridSnapshot<T> {
+    /// The bounds of the grid when the snapshot was taken.
+    pub fn bounds(&self) -> Bounds2D {
+        self.bounds
+    }
+
+    /// Get the value that the cell at `coord` had when the snapshot was taken.
+    pub fn get(&self, coord: impl GridPos2) -> Option<&T> {
+        let coord = coord.into_tuple();
+        if !self.bounds.contains(coord) {
+            return None;
+        }
+        let (x, y) = coord.checked_sub_coord(self.bounds.min)?;
+        self.cells
+            .get(y as usize * self.bounds.width() as usize + x as usize)
+    }
+}
+
 /// Iterator over all cells in a [RollGrid2D].
 pub struct RollGrid2DIterator<'a, T> {
     grid: &'a RollGrid2D<T>,
@@ -1078,6 +2337,28 @@ impl<'a, T> Iterator for RollGrid2DIterator<'a, T> {
     }
 }
 
+impl<'a, T> ExactSizeIterator for RollGrid2DIterator<'a, T> {}
+
+/// The inverse of [RollGrid2D::offset_index]: find the coordinate of the cell at `index`
+/// in the backing storage.
+#[cfg(feature = "rayon")]
+fn index_coord(
+    size: (usize, usize),
+    wrap_offset: (u32, u32),
+    grid_offset: (i32, i32),
+    index: usize,
+) -> (i32, i32) {
+    let (width, height) = (size.0 as i64, size.1 as i64);
+    let wx = (index % size.0) as i64;
+    let wy = (index / size.0) as i64;
+    let nx = (wx - wrap_offset.0 as i64).rem_euclid(width);
+    let ny = (wy - wrap_offset.1 as i64).rem_euclid(height);
+    (
+        (grid_offset.0 as i64 + nx) as i32,
+        (grid_offset.1 as i64 + ny) as i32,
+    )
+}
+
 /// Mutable iterator over all cells in the [RollGrid2D].
 pub struct RollGrid2DMutIterator<'a, T> {
     grid: &'a mut RollGrid2D<T>,
@@ -1102,6 +2383,74 @@ impl<'a, T> Iterator for RollGrid2DMutIterator<'a, T> {
     }
 }
 
+impl<'a, T> ExactSizeIterator for RollGrid2DMutIterator<'a, T> {}
+
+impl<T> IntoIterator for RollGrid2D<T> {
+    type Item = ((i32, i32), T);
+    type IntoIter = RollGrid2DIntoIterator<T>;
+
+    /// Consume the grid, yielding each cell with its coordinate in coordinate order.
+    fn into_iter(self) -> Self::IntoIter {
+        RollGrid2DIntoIterator {
+            bounds_iter: self.bounds().iter(),
+            grid: self,
+        }
+    }
+}
+
+/// Owning iterator over all cells in the [RollGrid2D].
+pub struct RollGrid2DIntoIterator<T> {
+    grid: RollGrid2D<T>,
+    bounds_iter: Bounds2DIter,
+}
+
+impl<T> Iterator for RollGrid2DIntoIterator<T> {
+    type Item = ((i32, i32), T);
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.bounds_iter.size_hint()
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.bounds_iter.next()?;
+        let index = self.grid.offset_index(next)?;
+        // Each coordinate is only visited once, so each cell is only read once.
+        unsafe { Some((next, self.grid.cells.read(index))) }
+    }
+}
+
+impl<T> ExactSizeIterator for RollGrid2DIntoIterator<T> {}
+
+impl<T> Drop for RollGrid2DIntoIterator<T> {
+    fn drop(&mut self) {
+        // Drop the cells that were not yielded, then free the buffer without
+        // dropping the cells that were already moved out.
+        if std::mem::needs_drop::<T>() {
+            for pos in self.bounds_iter.by_ref() {
+                let index = self.grid.offset_index(pos).expect(OUT_OF_BOUNDS);
+                unsafe {
+                    self.grid.cells.drop_in_place(index);
+                }
+            }
+        }
+        unsafe {
+            self.grid.cells.forget_dealloc();
+        }
+    }
+}
+
+/// The offsets of the Moore neighborhood, clockwise from the `-X -Y` corner.
+const MOORE_CLOCKWISE: [(i32, i32); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1146,67 +2495,1709 @@ mod tests {
     }
 
     #[test]
-    fn resize_and_reposition_test() {
-        struct DropCoord {
-            coord: (i32, i32),
-            unloaded: bool,
+    fn index_test() {
+        let mut grid = RollGrid2D::new(3, 3, (-1, -1), |pos: (i32, i32)| pos);
+        grid.translate((1, 2), |_, new_pos, cell| {
+            *cell = new_pos;
+        });
+        grid.bounds().iter().for_each(|pos| {
+            assert_eq!(grid[pos], pos);
+        });
+        grid[(1, 2)] = (0, 0);
+        assert_eq!(grid.get_copy((1, 2)), Some((0, 0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Out of bounds: (2, 0) is not in (0, 0)..(2, 2)")]
+    fn index_out_of_bounds_test() {
+        let grid = RollGrid2D::new(2, 2, (0, 0), |pos: (i32, i32)| pos);
+        let _ = grid[(2, 0)];
+    }
+
+    #[test]
+    fn swap_rows_test() {
+        let mut grid = RollGrid2D::new(3, 4, (0, 0), |pos: (i32, i32)| pos);
+        // Roll the grid so that the rows being swapped are wrapped internally.
+        grid.translate((1, 2), |_, new_pos, cell| {
+            *cell = new_pos;
+        });
+        grid.swap_rows(2, 5);
+        for x in grid.x_min()..grid.x_max() {
+            assert_eq!(grid[(x, 2)], (x, 5));
+            assert_eq!(grid[(x, 5)], (x, 2));
+            assert_eq!(grid[(x, 3)], (x, 3));
+            assert_eq!(grid[(x, 4)], (x, 4));
         }
-        impl From<(i32, i32)> for DropCoord {
-            fn from(value: (i32, i32)) -> Self {
-                Self {
-                    coord: value,
-                    unloaded: false,
-                }
-            }
+        grid.swap_rows(3, 3);
+        for x in grid.x_min()..grid.x_max() {
+            assert_eq!(grid[(x, 3)], (x, 3));
         }
-        impl Drop for DropCoord {
-            fn drop(&mut self) {
-                // assert!(self.unloaded);
-            }
+    }
+
+    #[test]
+    fn swap_columns_test() {
+        let mut grid = RollGrid2D::new(4, 3, (0, 0), |pos: (i32, i32)| pos);
+        grid.translate((2, 1), |_, new_pos, cell| {
+            *cell = new_pos;
+        });
+        grid.swap_columns(2, 4);
+        for y in grid.y_min()..grid.y_max() {
+            assert_eq!(grid[(2, y)], (4, y));
+            assert_eq!(grid[(4, y)], (2, y));
+            assert_eq!(grid[(3, y)], (3, y));
+            assert_eq!(grid[(5, y)], (5, y));
         }
-        fn verify_grid(grid: &RollGrid2D<DropCoord>) {
-            for y in grid.y_min()..grid.y_max() {
-                for x in grid.x_min()..grid.x_max() {
-                    let pos = (x, y);
-                    let cell = grid.get(pos).expect("Cell was None");
-                    assert_eq!(pos, cell.coord);
-                }
-            }
+        grid.swap_columns(5, 5);
+        for y in grid.y_min()..grid.y_max() {
+            assert_eq!(grid[(5, y)], (5, y));
         }
-        for height in 1..7 {
-            for width in 1..7 {
-                for y in -1..6 {
-                    for x in -1..6 {
-                        let mut grid =
-                            RollGrid2D::new(4, 4, (0, 0), |pos: (i32, i32)| DropCoord::from(pos));
-                        // reposition to half point to ensure that wrapping does not cause lookup invalidation.
-                        grid.reposition((2, 2), |old_pos, new_pos, cell| {
-                            assert_eq!(old_pos, cell.coord);
-                            cell.coord = new_pos;
-                        });
-                        grid.resize_and_reposition(
-                            width,
-                            height,
-                            (x, y),
-                            crate::cell_manager(
-                                |pos| DropCoord::from(pos),
-                                |pos, value| {
-                                    let mut old = value;
-                                    old.unloaded = true;
-                                    assert_eq!(pos, old.coord);
-                                },
-                                |_, new_pos, value| {
-                                    value.coord = new_pos;
-                                },
-                            ),
-                        );
-                        grid.iter_mut().for_each(|(_, cell)| {
-                            cell.unloaded = true;
-                        });
-                        verify_grid(&grid);
-                    }
-                }
-            }
+    }
+
+    #[test]
+    #[should_panic(expected = "Out of bounds")]
+    fn swap_rows_out_of_bounds_test() {
+        let mut grid = RollGrid2D::new(2, 2, (0, 0), |pos: (i32, i32)| pos);
+        grid.swap_rows(0, 2);
+    }
+
+    #[test]
+    fn iter_with_edge_test() {
+        let mut grid = RollGrid2D::new(4, 3, (0, 0), |pos: (i32, i32)| pos);
+        grid.reposition((-2, 5), |_, new, cell| *cell = new);
+        let mut count = 0;
+        for (pos, cell, edge) in grid.iter_with_edge() {
+            count += 1;
+            assert_eq!(pos, *cell);
+            let expected = pos.0 == -2 || pos.0 == 1 || pos.1 == 5 || pos.1 == 7;
+            assert_eq!(edge, expected, "{pos:?}");
         }
+        assert_eq!(count, 12);
+        assert_eq!(grid.iter().len(), 12);
+        assert_eq!(grid.iter_mut().skip(5).len(), 7);
+        let single = RollGrid2D::new(1, 1, (3, 3), |pos: (i32, i32)| pos);
+        assert!(single.iter_with_edge().all(|(_, _, edge)| edge));
+    }
+
+    #[test]
+    fn diff_snapshot_test() {
+        let mut grid = RollGrid2D::new(4, 4, (0, 0), |pos: (i32, i32)| pos);
+        grid.reposition((1, 2), |_, new_pos, cell| *cell = new_pos);
+        let snapshot = grid.snapshot();
+        assert_eq!(snapshot.bounds(), grid.bounds());
+        assert_eq!(snapshot.get((4, 5)), Some(&(4, 5)));
+        assert_eq!(snapshot.get((0, 0)), None);
+        assert!(grid.diff_snapshot(&snapshot).is_empty());
+        grid[(1, 2)] = (-1, -1);
+        grid[(4, 3)] = (-2, -2);
+        // setting a cell to the same value is not a change.
+        grid[(2, 4)] = (2, 4);
+        let mut diff = grid.diff_snapshot(&snapshot);
+        diff.sort();
+        assert_eq!(diff, vec![((1, 2), &(-1, -1)), ((4, 3), &(-2, -2))]);
+        // cells moved out of the snapshot bounds are reported.
+        grid.translate((1, 0), |_, new_pos, cell| *cell = new_pos);
+        let mut diff = grid.diff_snapshot(&snapshot);
+        diff.sort();
+        assert_eq!(
+            diff,
+            vec![
+                ((4, 3), &(-2, -2)),
+                ((5, 2), &(5, 2)),
+                ((5, 3), &(5, 3)),
+                ((5, 4), &(5, 4)),
+                ((5, 5), &(5, 5)),
+            ]
+        );
+        let grid = RollGrid2D::new(4, 2, (i32::MIN, 0), |pos: (i32, i32)| pos);
+        let snapshot = grid.snapshot();
+        assert_eq!(snapshot.get((i32::MIN + 3, 1)), Some(&(i32::MIN + 3, 1)));
+        assert_eq!(snapshot.get((i32::MAX, 1)), None);
+    }
+
+    #[test]
+    fn resize_split_manage_test() {
+        let mut grid = RollGrid2D::new(3, 3, (0, 0), |pos: (i32, i32)| pos);
+        let mut loaded = Vec::new();
+        let mut unloaded = Vec::new();
+        let mut reloaded = Vec::new();
+        // same size: carried over cells are moved with the reload manager.
+        grid.resize_split_manage(
+            3,
+            3,
+            (1, 0),
+            crate::cell_manager(
+                |_| panic!("Unexpected load"),
+                |_, _| panic!("Unexpected unload"),
+                |_, _, _| panic!("Reload on load manager"),
+            ),
+            crate::cell_manager(
+                |_| panic!("Load on reload manager"),
+                |_, _| panic!("Unload on reload manager"),
+                |old_pos, new_pos, cell: &mut (i32, i32)| {
+                    reloaded.push((old_pos, new_pos));
+                    *cell = new_pos;
+                },
+            ),
+        );
+        reloaded.sort();
+        assert_eq!(
+            reloaded,
+            vec![((0, 0), (3, 0)), ((0, 1), (3, 1)), ((0, 2), (3, 2))]
+        );
+        // new size: exposed cells are handled by the load manager.
+        grid.resize_split_manage(
+            4,
+            3,
+            (0, 0),
+            crate::cell_manager(
+                |pos| {
+                    loaded.push(pos);
+                    pos
+                },
+                |pos, value| {
+                    assert_eq!(pos, value);
+                    unloaded.push(pos);
+                },
+                |_, _, _| panic!("Reload on load manager"),
+            ),
+            crate::cell_manager(
+                |_| panic!("Load on reload manager"),
+                |_, _| panic!("Unload on reload manager"),
+                |_, _, _| panic!("Unexpected reload"),
+            ),
+        );
+        loaded.sort();
+        assert_eq!(loaded, vec![(0, 0), (0, 1), (0, 2)]);
+        assert!(unloaded.is_empty());
+        grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+    }
+
+    #[test]
+    fn try_resize_split_manage_test() {
+        let mut grid = RollGrid2D::new(3, 3, (0, 0), |pos: (i32, i32)| pos);
+        let mut reloads = 0;
+        grid.try_resize_split_manage(
+            3,
+            3,
+            (0, 1),
+            crate::try_cell_manager(
+                |_| panic!("Unexpected load"),
+                |_, _| panic!("Unexpected unload"),
+                |_, _, _| panic!("Reload on load manager"),
+            ),
+            crate::try_cell_manager(
+                |_| panic!("Load on reload manager"),
+                |_, _| panic!("Unload on reload manager"),
+                |_, new_pos, cell: &mut (i32, i32)| {
+                    reloads += 1;
+                    *cell = new_pos;
+                    Ok::<(), &str>(())
+                },
+            ),
+        )
+        .unwrap();
+        assert_eq!(reloads, 3);
+        grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+        // errors from the load manager are returned.
+        let result = grid.try_resize_split_manage(
+            4,
+            3,
+            (0, 1),
+            crate::try_cell_manager(
+                |_| Err("load failed"),
+                |_, _| Ok(()),
+                |_, _, _| panic!("Reload on load manager"),
+            ),
+            crate::try_cell_manager(
+                |_| panic!("Load on reload manager"),
+                |_, _| panic!("Unload on reload manager"),
+                |_, _, _| panic!("Unexpected reload"),
+            ),
+        );
+        assert_eq!(result, Err("load failed"));
+    }
+
+    #[test]
+    fn toroidal_test() {
+        let mut grid = RollGrid2D::new(4, 3, (0, 0), |pos: (i32, i32)| pos);
+        grid.reposition((2, -1), |_, new_pos, cell| *cell = new_pos);
+        let toroidal = grid.toroidal();
+        for pos in grid.bounds().iter() {
+            assert_eq!(toroidal[pos], pos);
+            assert_eq!(toroidal[(pos.0 + 4 * 1000, pos.1 - 3 * 1000)], pos);
+        }
+        assert_eq!(toroidal[(1, -1)], (5, -1));
+        assert_eq!(toroidal[(6, 2)], (2, -1));
+        assert_eq!(
+            toroidal[(i32::MIN, i32::MAX)],
+            toroidal[(i32::MIN + 4, i32::MAX - 3)]
+        );
+        let grid = RollGrid2D::new(3, 2, (1, 0), |pos: (i32, i32)| pos);
+        assert_eq!(grid.toroidal()[(0, 0)], (3, 0));
+        assert_eq!(grid.toroidal()[(4, -1)], (1, 1));
+    }
+
+    #[test]
+    fn get_wrapped_test() {
+        let mut grid = RollGrid2D::new(3, 4, (0, 0), |pos: (i32, i32)| pos);
+        grid.reposition((2, -1), |_, new_pos, cell| *cell = new_pos);
+        for (pos, cell) in grid.iter() {
+            assert_eq!(grid.get_wrapped(pos), cell);
+            assert_eq!(grid.get_wrapped((pos.0 + 3, pos.1)), cell);
+            assert_eq!(grid.get_wrapped((pos.0 - 6, pos.1 + 8)), cell);
+        }
+        // left neighbor of the left edge is the right edge.
+        assert_eq!(grid.get_wrapped((1, 0)), &(4, 0));
+        assert_eq!(grid.get_wrapped((2, -2)), &(2, 2));
+        assert_eq!(
+            grid.get_wrapped((i32::MIN, i32::MAX)),
+            grid.get_wrapped((i32::MIN + 3, i32::MAX - 4))
+        );
+        *grid.get_wrapped_mut((5, 3)) = (-1, -1);
+        assert_eq!(grid.get((2, -1)), Some(&(-1, -1)));
+    }
+
+    #[test]
+    fn get_wrapped_ignores_offset_test() {
+        let mut grid = RollGrid2D::new(3, 2, (1, 0), |pos: (i32, i32)| pos);
+        // storage slot (0, 0) holds the cell created for the grid's minimum.
+        assert_eq!(grid.get_wrapped((0, 0)), &(1, 0));
+        assert_eq!(grid.get_wrapped((3, -2)), &(1, 0));
+        assert_eq!(grid.get_wrapped((2, 1)), &(3, 1));
+        grid.translate((1, 0), |_, new_pos, cell| *cell = new_pos);
+        assert_eq!(grid.get_wrapped((0, 0)), &(4, 0));
+        assert_eq!(grid.get_wrapped((1, 0)), &(2, 0));
+        // a full reload keeps the storage layout, so in-bounds coordinates can differ from `get`.
+        let mut grid = RollGrid2D::new(4, 4, (0, 0), |pos: (i32, i32)| pos);
+        grid.reposition((5, 0), |_, new_pos, cell| *cell = new_pos);
+        assert_eq!(grid.get((5, 0)), Some(&(5, 0)));
+        assert_eq!(grid.get_wrapped((5, 0)), &(6, 0));
+        assert_eq!(grid.toroidal()[(5, 0)], (5, 0));
+    }
+
+    #[test]
+    fn normalize_axis_test() {
+        let mut grid = RollGrid2D::new(4, 3, (0, 0), |pos: (i32, i32)| pos);
+        grid.translate((3, 0), |_, new_pos, cell| *cell = new_pos);
+        grid.translate((2, 0), |_, new_pos, cell| *cell = new_pos);
+        assert_eq!(grid.wrap_offset, (1, 0));
+        grid.normalize_axis(Axis::X);
+        assert_eq!(grid.wrap_offset, (0, 0));
+        grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+        grid.translate((1, 2), |_, new_pos, cell| *cell = new_pos);
+        assert_eq!(grid.wrap_offset, (1, 2));
+        grid.normalize_axis(Axis::X);
+        assert_eq!(grid.wrap_offset, (0, 2));
+        grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+        grid.normalize_axis(Axis::Z);
+        assert_eq!(grid.wrap_offset, (0, 2));
+        grid.normalize_axis(Axis::Y);
+        assert_eq!(grid.wrap_offset, (0, 0));
+        grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+        let expected = grid.bounds().iter().collect::<Vec<_>>();
+        assert_eq!(grid.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn normalize_test() {
+        let mut grid = RollGrid2D::new(5, 4, (0, 0), |pos: (i32, i32)| pos);
+        grid.translate((-2, 3), |_, new_pos, cell| *cell = new_pos);
+        assert_ne!(grid.wrap_offset, (0, 0));
+        grid.normalize();
+        assert_eq!(grid.wrap_offset, (0, 0));
+        let expected = grid.bounds().iter().collect::<Vec<_>>();
+        assert_eq!(grid.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn moore_test() {
+        let grid = RollGrid2D::new(3, 3, (0, 0), |pos: (i32, i32)| pos);
+        assert_eq!(
+            grid.moore((1, 1)),
+            [
+                Some(&(0, 0)),
+                Some(&(1, 0)),
+                Some(&(2, 0)),
+                Some(&(2, 1)),
+                Some(&(2, 2)),
+                Some(&(1, 2)),
+                Some(&(0, 2)),
+                Some(&(0, 1)),
+            ]
+        );
+        let corner = grid.moore((0, 0));
+        assert_eq!(corner.iter().filter(|cell| cell.is_none()).count(), 5);
+        assert_eq!(
+            corner,
+            [
+                None,
+                None,
+                None,
+                Some(&(1, 0)),
+                Some(&(1, 1)),
+                Some(&(0, 1)),
+                None,
+                None
+            ]
+        );
+        let wrapped = grid.moore_wrapping((0, 0));
+        assert_eq!(
+            wrapped,
+            [
+                &(2, 2),
+                &(0, 2),
+                &(1, 2),
+                &(1, 0),
+                &(1, 1),
+                &(0, 1),
+                &(2, 1),
+                &(2, 0)
+            ]
+        );
+        assert_eq!(grid.moore_wrapping((2, 1))[3], &(0, 1));
+        let grid = RollGrid2D::new(2, 2, (i32::MIN, i32::MAX - 2), |pos: (i32, i32)| pos);
+        let (x, y) = (i32::MIN, i32::MAX - 1);
+        assert_eq!(grid.moore_wrapping((x, y))[0], &(x + 1, y - 1));
+        assert_eq!(grid.moore((x, y))[7], None);
+        // after translating, neighbors follow the new bounds rather than the storage layout.
+        let mut grid = RollGrid2D::new(3, 3, (0, 0), |pos: (i32, i32)| pos);
+        grid.translate((2, -1), |_, new_pos, cell| *cell = new_pos);
+        assert_ne!(grid.wrap_offset(), (0, 0));
+        assert_eq!(
+            grid.moore((3, 0)),
+            [
+                Some(&(2, -1)),
+                Some(&(3, -1)),
+                Some(&(4, -1)),
+                Some(&(4, 0)),
+                Some(&(4, 1)),
+                Some(&(3, 1)),
+                Some(&(2, 1)),
+                Some(&(2, 0)),
+            ]
+        );
+        assert_eq!(grid.moore((2, -1))[..3], [None, None, None]);
+        assert_eq!(grid.moore((2, -1))[3], Some(&(3, -1)));
+        let wrapped = grid.moore_wrapping((2, -1));
+        assert_eq!(
+            wrapped,
+            [
+                &(4, 1),
+                &(2, 1),
+                &(3, 1),
+                &(3, -1),
+                &(3, 0),
+                &(2, 0),
+                &(4, 0),
+                &(4, -1)
+            ]
+        );
+    }
+
+    #[test]
+    fn neighbors_test() {
+        let grid = RollGrid2D::new(3, 3, (0, 0), |pos: (i32, i32)| pos);
+        let center = grid.von_neumann_neighbors((1, 1)).collect::<Vec<_>>();
+        assert_eq!(
+            center,
+            vec![
+                ((1, 0), &(1, 0)),
+                ((2, 1), &(2, 1)),
+                ((1, 2), &(1, 2)),
+                ((0, 1), &(0, 1)),
+            ]
+        );
+        assert_eq!(grid.moore_neighbors((1, 1)).count(), 8);
+        assert!(grid
+            .moore_neighbors((1, 1))
+            .all(|(pos, cell)| pos == *cell && pos != (1, 1)));
+        // edges yield fewer neighbors.
+        let corner = grid
+            .von_neumann_neighbors((0, 0))
+            .map(|(pos, _)| pos)
+            .collect::<Vec<_>>();
+        assert_eq!(corner, vec![(1, 0), (0, 1)]);
+        let corner = grid
+            .moore_neighbors((2, 2))
+            .map(|(pos, _)| pos)
+            .collect::<Vec<_>>();
+        assert_eq!(corner, vec![(1, 1), (2, 1), (1, 2)]);
+        assert_eq!(grid.moore_neighbors((1, 0)).count(), 5);
+        // out of bounds coordinates can still have in-bounds neighbors.
+        assert_eq!(
+            grid.von_neumann_neighbors((-1, 2))
+                .map(|(pos, _)| pos)
+                .collect::<Vec<_>>(),
+            vec![(0, 2)]
+        );
+        let edge = RollGrid2D::new(1, 1, (i32::MAX - 1, i32::MIN), |pos: (i32, i32)| pos);
+        assert_eq!(edge.moore_neighbors((i32::MAX - 1, i32::MIN)).count(), 0);
+        assert_eq!(edge.moore_neighbors((i32::MAX, i32::MIN)).count(), 1);
+    }
+
+    #[test]
+    fn quad_test() {
+        let mut grid = RollGrid2D::new(3, 3, (0, 0), |pos: (i32, i32)| pos);
+        grid.reposition((1, 1), |_, new_pos, cell| *cell = new_pos);
+        assert_eq!(
+            grid.quad((1, 1)),
+            Some([&(1, 1), &(2, 1), &(1, 2), &(2, 2)])
+        );
+        assert_eq!(
+            grid.quad((2, 2)),
+            Some([&(2, 2), &(3, 2), &(2, 3), &(3, 3)])
+        );
+        // far edges
+        assert_eq!(grid.quad((3, 1)), None);
+        assert_eq!(grid.quad((1, 3)), None);
+        assert_eq!(grid.quad((0, 1)), None);
+    }
+
+    #[test]
+    fn resize_wrap_reset_test() {
+        // The number of reloads after a resize does not depend on whether the
+        // wrap offset was reset by the resize.
+        let count_reloads = |grid: &mut RollGrid2D<(i32, i32)>, position: (i32, i32)| {
+            let mut reloads = 0;
+            grid.reposition(position, |old_pos, new_pos, cell| {
+                assert_eq!(old_pos, *cell);
+                *cell = new_pos;
+                reloads += 1;
+            });
+            grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+            reloads
+        };
+        for (x, y) in [(1, 0), (0, 2), (-2, 1), (3, -1)] {
+            let mut resized = RollGrid2D::new(4, 4, (0, 0), |pos: (i32, i32)| pos);
+            count_reloads(&mut resized, (2, 1));
+            assert_ne!(resized.wrap_offset, (0, 0));
+            // height is unchanged, width grows.
+            resized.resize(
+                6,
+                4,
+                crate::cell_manager(|pos| pos, |_, _| (), |_, _, _| ()),
+            );
+            assert_eq!(resized.wrap_offset, (0, 0));
+            let mut wrapped = RollGrid2D::new(6, 4, (0, 0), |pos: (i32, i32)| pos);
+            count_reloads(&mut wrapped, (1, 3));
+            count_reloads(&mut wrapped, (2, 1));
+            assert_ne!(wrapped.wrap_offset, (0, 0));
+            assert_eq!(resized.bounds(), wrapped.bounds());
+            let target = (2 + x, 1 + y);
+            assert_eq!(
+                count_reloads(&mut resized, target),
+                count_reloads(&mut wrapped, target)
+            );
+        }
+    }
+
+    #[test]
+    fn copy_within_test() {
+        let new_grid = || {
+            let mut grid = RollGrid2D::new(5, 5, (0, 0), |pos: (i32, i32)| pos);
+            grid.translate((-1, 2), |_, new_pos, cell| *cell = new_pos);
+            grid
+        };
+        // overlapping destination, shifted by (1, 1).
+        let mut grid = new_grid();
+        let src = Bounds2D::new((0, 3), (3, 6));
+        grid.copy_within(src, (1, 4));
+        for pos in grid.bounds().iter() {
+            let shifted = (pos.0 - 1, pos.1 - 1);
+            let expected = if src.contains(shifted) { shifted } else { pos };
+            assert_eq!(grid[pos], expected, "{pos:?}");
+        }
+        // overlapping destination, shifted by (-1, -1).
+        let mut grid = new_grid();
+        grid.copy_within(src, (-1, 2));
+        for pos in grid.bounds().iter() {
+            let shifted = (pos.0 + 1, pos.1 + 1);
+            let expected = if src.contains(shifted) { shifted } else { pos };
+            assert_eq!(grid[pos], expected, "{pos:?}");
+        }
+        // destination cells outside the grid are skipped.
+        let mut grid = new_grid();
+        grid.copy_within(Bounds2D::new((-1, 2), (1, 4)), (3, 6));
+        assert_eq!(grid[(3, 6)], (-1, 2));
+        assert_eq!(grid[(2, 6)], (2, 6));
+        assert_eq!(grid.iter().filter(|(pos, cell)| pos != *cell).count(), 1);
+        // source cells outside the grid are skipped.
+        let mut grid = new_grid();
+        grid.copy_within(Bounds2D::new((-3, 0), (0, 3)), (0, 3));
+        assert_eq!(grid[(2, 5)], (-1, 2));
+        assert_eq!(grid.iter().filter(|(pos, cell)| pos != *cell).count(), 1);
+    }
+
+    #[test]
+    fn subgrid_test() {
+        let mut grid = RollGrid2D::new(4, 4, (0, 0), |pos: (i32, i32)| pos);
+        grid.reposition((1, -2), |_, new_pos, cell| *cell = new_pos);
+        let bounds = Bounds2D::new((2, -1), (5, 1));
+        let subgrid = grid.subgrid(bounds);
+        assert_eq!(subgrid.bounds(), bounds);
+        assert_eq!(subgrid.len(), 6);
+        subgrid
+            .iter()
+            .for_each(|(pos, cell)| assert_eq!(pos, **cell));
+        drop(subgrid);
+        let copied = grid.copy_subgrid(bounds);
+        let cloned = grid.clone_subgrid(bounds);
+        assert_eq!(copied.as_slice(), cloned.as_slice());
+        copied.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+        let mut subgrid = grid.subgrid_mut(bounds);
+        subgrid.iter_mut().for_each(|(_, cell)| **cell = (0, 0));
+        drop(subgrid);
+        for (pos, cell) in grid.iter() {
+            if bounds.contains(pos) {
+                assert_eq!(*cell, (0, 0));
+            } else {
+                assert_eq!(pos, *cell);
+            }
+        }
+        // the copies are unaffected by later writes
+        copied.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+    }
+
+    #[test]
+    fn try_subgrid_test() {
+        let grid = RollGrid2D::new(4, 4, (0, 0), |pos: (i32, i32)| pos);
+        let too_large = Bounds2D::new((-1, 0), (4, 5));
+        assert_eq!(
+            grid.try_subgrid(too_large).err(),
+            Some(RollGridError::OutOfBounds)
+        );
+        assert_eq!(
+            grid.try_copy_subgrid(too_large).err(),
+            Some(RollGridError::OutOfBounds)
+        );
+        assert_eq!(
+            grid.try_clone_subgrid(too_large).err(),
+            Some(RollGridError::OutOfBounds)
+        );
+        let valid = Bounds2D::new((1, 1), (3, 4));
+        assert_eq!(grid.try_subgrid(valid).map(|subgrid| subgrid.len()), Ok(6));
+        assert_eq!(
+            grid.try_copy_subgrid(valid).map(|subgrid| subgrid[(2, 3)]),
+            Ok((2, 3))
+        );
+        assert_eq!(
+            grid.try_clone_subgrid(valid)
+                .map(|subgrid| subgrid.bounds()),
+            Ok(valid)
+        );
+        assert!(grid.try_subgrid(grid.bounds()).is_ok());
+        for empty in [Bounds2D::new((1, 1), (1, 3)), Bounds2D::new((1, 1), (3, 1))] {
+            assert_eq!(
+                grid.try_subgrid(empty).err(),
+                Some(RollGridError::EmptyBounds)
+            );
+            assert_eq!(
+                grid.try_copy_subgrid(empty).err(),
+                Some(RollGridError::EmptyBounds)
+            );
+            assert_eq!(
+                grid.try_clone_subgrid(empty).err(),
+                Some(RollGridError::EmptyBounds)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Bounds have no cells")]
+    fn subgrid_mut_empty_test() {
+        let mut grid = RollGrid2D::new(4, 4, (0, 0), |pos: (i32, i32)| pos);
+        grid.subgrid_mut(Bounds2D::new((1, 1), (1, 3)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Out of bounds")]
+    fn subgrid_out_of_bounds_test() {
+        let grid = RollGrid2D::new(4, 4, (0, 0), |pos: (i32, i32)| pos);
+        grid.clone_subgrid(Bounds2D::new((1, 1), (3, 5)));
+    }
+
+    #[test]
+    fn fill_border_test() {
+        let mut grid = RollGrid2D::new(4, 3, (0, 0), |_| 0);
+        grid.reposition((-2, 5), |_, _, cell| *cell = 0);
+        grid.fill_border(1);
+        for (pos, cell) in grid.iter() {
+            let on_border = pos.0 == -2 || pos.0 == 1 || pos.1 == 5 || pos.1 == 7;
+            assert_eq!(*cell, if on_border { 1 } else { 0 }, "{pos:?}");
+        }
+        assert_eq!(grid.get((-1, 6)), Some(&0));
+        assert_eq!(grid.get((0, 6)), Some(&0));
+    }
+
+    #[test]
+    fn downsample_test() {
+        let grid = RollGrid2D::new(10, 7, (-3, 2), |_| 1u32);
+        let sums = grid.downsample((4, 3), |block| block.iter().copied().sum::<u32>());
+        assert_eq!(sums.size(), (3, 3));
+        assert_eq!(sums.offset(), (0, 0));
+        assert_eq!(sums.as_slice(), [12, 12, 6, 12, 12, 6, 4, 4, 2]);
+        assert_eq!(sums.iter().map(|(_, sum)| *sum).sum::<u32>(), 70);
+        // blocks are passed in x -> y order
+        let grid = RollGrid2D::new(4, 4, (0, 0), |pos: (i32, i32)| pos);
+        let corners = grid.downsample((2, 2), |block| (*block[0], *block[block.len() - 1]));
+        assert_eq!(corners[(1, 0)], ((2, 0), (3, 1)));
+        assert_eq!(corners[(0, 1)], ((0, 2), (1, 3)));
+        let whole = grid.downsample((8, 8), |block| block.len());
+        assert_eq!(whole.as_slice(), [16]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Width/Height cannot be 0")]
+    fn downsample_zero_factor_test() {
+        let grid = RollGrid2D::new(4, 4, (0, 0), |_| 1u32);
+        grid.downsample((0, 2), |block| block.len());
+    }
+
+    #[test]
+    fn upsample_test() {
+        let grid = RollGrid2D::new(2, 2, (0, 0), |pos: (i32, i32)| pos);
+        let upsampled = grid.upsample((2, 2));
+        assert_eq!(upsampled.size(), (4, 4));
+        assert_eq!(upsampled.offset(), (0, 0));
+        for (pos, cell) in upsampled.iter() {
+            assert_eq!(*cell, (pos.0 / 2, pos.1 / 2));
+        }
+        assert_eq!(upsampled.get((3, 1)), Some(&(1, 0)));
+        // the offset is scaled, and negative coordinates fill their own blocks
+        let mut grid = RollGrid2D::new(3, 2, (0, 0), |pos: (i32, i32)| pos);
+        grid.reposition((-2, 5), |_, new_pos, cell| *cell = new_pos);
+        let upsampled = grid.upsample((3, 2));
+        assert_eq!(upsampled.size(), (9, 4));
+        assert_eq!(upsampled.offset(), (-6, 10));
+        for (pos, cell) in upsampled.iter() {
+            assert_eq!(*cell, (pos.0.div_euclid(3), pos.1.div_euclid(2)));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "X minimum bound exceeds i32::MIN")]
+    fn upsample_offset_overflow_test() {
+        let grid = RollGrid2D::new(2, 2, (i32::MIN / 2 - 1, 0), |_| 0);
+        grid.upsample((2, 2));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_iter_test() {
+        use rayon::prelude::*;
+        use std::collections::HashSet;
+        let mut grid = RollGrid2D::new(7, 5, (0, 0), |pos: (i32, i32)| pos);
+        grid.reposition((-3, 9), |_, new_pos, cell| *cell = new_pos);
+        grid.translate((2, -4), |_, new_pos, cell| *cell = new_pos);
+        assert_ne!(grid.wrap_offset, (0, 0));
+        let visited: Vec<(i32, i32)> = grid
+            .par_iter()
+            .map(|(pos, cell)| {
+                assert_eq!(pos, *cell);
+                pos
+            })
+            .collect();
+        assert_eq!(visited.len(), grid.len());
+        let unique: HashSet<(i32, i32)> = visited.iter().copied().collect();
+        assert_eq!(unique, grid.bounds().iter().collect());
+        grid.par_iter_mut()
+            .for_each(|((x, y), cell)| *cell = (x * 2, y * 2));
+        grid.iter()
+            .for_each(|((x, y), cell)| assert_eq!(*cell, (x * 2, y * 2)));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_iter_mut_send_test() {
+        use rayon::prelude::*;
+        use std::cell::Cell;
+        // `Cell` is `Send` but not `Sync`.
+        let mut grid = RollGrid2D::new(4, 3, (0, 0), |_| Cell::new(0));
+        grid.translate((1, 2), |_, _, cell| cell.set(0));
+        grid.par_iter_mut()
+            .for_each(|((x, y), cell)| cell.set(x * 10 + y));
+        grid.iter()
+            .for_each(|((x, y), cell)| assert_eq!(cell.get(), x * 10 + y));
+    }
+
+    #[test]
+    fn group_by_test() {
+        let mut grid = RollGrid2D::new(4, 3, (0, 0), |(x, y)| (x + y) % 3);
+        grid.reposition((1, 1), |_, (x, y), cell| *cell = (x + y) % 3);
+        let groups = grid.group_by(|cell| *cell == 0);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[&true], vec![(2, 1), (1, 2), (4, 2), (3, 3)]);
+        assert_eq!(groups[&false].len(), 8);
+        let groups = grid.group_by(|cell| *cell);
+        assert_eq!(groups.values().map(Vec::len).sum::<usize>(), grid.len());
+        for (key, coords) in groups {
+            for pos in coords {
+                assert_eq!(grid[pos], key);
+            }
+        }
+    }
+
+    #[test]
+    fn wrap_offset_to_vec_test() {
+        let mut grid = RollGrid2D::new(3, 2, (0, 0), |pos: (i32, i32)| pos);
+        assert_eq!(grid.wrap_offset(), (0, 0));
+        assert_eq!(grid.to_vec(), grid.as_slice());
+        grid.translate((1, 1), |_, new_pos, cell| *cell = new_pos);
+        assert_eq!(grid.wrap_offset(), (1, 1));
+        assert_eq!(
+            grid.to_vec(),
+            vec![(1, 1), (2, 1), (3, 1), (1, 2), (2, 2), (3, 2)]
+        );
+        assert_ne!(grid.to_vec(), grid.as_slice());
+        grid.translate((-3, 0), |_, new_pos, cell| *cell = new_pos);
+        assert_eq!(grid.wrap_offset(), (1, 1));
+        assert_eq!(grid.to_vec(), grid.bounds().iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn translate_near_max_test() {
+        let mut grid = RollGrid2D::new(4, 4, (i32::MAX - 5, 0), |pos: (i32, i32)| pos);
+        grid.translate((1, 0), |_, new_pos, cell| *cell = new_pos);
+        assert_eq!(grid.bounds().max, (i32::MAX, 4));
+    }
+
+    #[test]
+    #[should_panic(expected = "X maximum bound exceeds i32::MAX")]
+    fn translate_past_max_test() {
+        let mut grid = RollGrid2D::new(4, 4, (i32::MAX - 5, 0), |pos: (i32, i32)| pos);
+        grid.translate((2, 0), |_, new_pos, cell| *cell = new_pos);
+    }
+
+    #[test]
+    #[should_panic(expected = "Y minimum bound exceeds i32::MIN")]
+    fn translate_past_min_test() {
+        let mut grid = RollGrid2D::new(4, 4, (0, i32::MIN + 1), |pos: (i32, i32)| pos);
+        grid.translate((0, -1), |_, new_pos, cell| *cell = new_pos);
+        assert_eq!(grid.offset(), (0, i32::MIN));
+        let _ = grid.try_translate((0, -1), |_, _, _| Ok::<(), ()>(()));
+    }
+
+    #[test]
+    fn reset_test() {
+        let mut grid = RollGrid2D::new(3, 2, (0, 0), |pos: (i32, i32)| pos);
+        grid.translate((1, 1), |_, new_pos, cell| *cell = new_pos);
+        let mut unloaded = Vec::new();
+        let mut loaded = Vec::new();
+        grid.reset(cell_manager(
+            |pos: (i32, i32)| {
+                loaded.push(pos);
+                (pos.0 * 10, pos.1 * 10)
+            },
+            |pos, old_value| {
+                assert_eq!(pos, old_value);
+                unloaded.push(pos);
+            },
+            |_, _, _| panic!("reset does not reload"),
+        ));
+        let expected: Vec<(i32, i32)> = grid.bounds().iter().collect();
+        assert_eq!(unloaded, expected);
+        assert_eq!(loaded, expected);
+        assert_eq!(grid.offset(), (1, 1));
+        assert_eq!(grid.size(), (3, 2));
+        assert_eq!(grid.wrap_offset(), (0, 0));
+        grid.iter()
+            .for_each(|(pos, cell)| assert_eq!(*cell, (pos.0 * 10, pos.1 * 10)));
+        let result = grid.try_reset(try_cell_manager(
+            |pos: (i32, i32)| if pos == (2, 2) { Err("load") } else { Ok(pos) },
+            |_, _| Ok(()),
+            |_, _, _| Ok(()),
+        ));
+        assert_eq!(result, Err("load"));
+    }
+
+    #[test]
+    fn try_reset_error_drop_test() {
+        use std::rc::Rc;
+        let counter = Rc::new(());
+        for fail_at in [(0, 0), (2, 1)] {
+            // failing unload.
+            let mut grid = RollGrid2D::new(3, 2, (0, 0), |_| counter.clone());
+            grid.translate((1, 0), |_, _, _| ());
+            let result = grid.try_reset(try_cell_manager(
+                |_| Ok(counter.clone()),
+                |pos, _| {
+                    if pos == (fail_at.0 + 1, fail_at.1) {
+                        Err("unload")
+                    } else {
+                        Ok(())
+                    }
+                },
+                |_, _, _| Ok(()),
+            ));
+            assert_eq!(result, Err("unload"));
+            assert_eq!(Rc::strong_count(&counter), 1);
+            assert_eq!(grid.size(), (0, 0));
+            assert_eq!(grid.iter().count(), 0);
+            drop(grid);
+            assert_eq!(Rc::strong_count(&counter), 1);
+            // failing load.
+            let mut grid = RollGrid2D::new(3, 2, (0, 0), |_| counter.clone());
+            let result = grid.try_reset(try_cell_manager(
+                |pos| {
+                    if pos == fail_at {
+                        Err("load")
+                    } else {
+                        Ok(counter.clone())
+                    }
+                },
+                |_, _| Ok(()),
+                |_, _, _| Ok(()),
+            ));
+            assert_eq!(result, Err("load"));
+            drop(grid);
+            assert_eq!(Rc::strong_count(&counter), 1);
+        }
+    }
+
+    #[test]
+    fn try_reset_empty_state_test() {
+        let mut grid = RollGrid2D::new(3, 2, (1, 1), |pos| pos);
+        let result = grid.try_reset(try_cell_manager(
+            |_| Err("load"),
+            |_, _| Ok(()),
+            |_, _, _| Ok(()),
+        ));
+        assert_eq!(result, Err("load"));
+        assert_eq!(grid.size(), (0, 0));
+        assert_eq!(grid.len(), 0);
+        assert_eq!(grid.as_slice(), &[]);
+        assert_eq!(grid.get((1, 1)), None);
+        assert_eq!(grid.iter().count(), 0);
+        assert_eq!(grid.debug_layout().lines().count(), 1);
+        grid.resize(2, 2, cell_manager(|pos| pos, |_, _| (), |_, _, _| ()));
+        assert_eq!(grid.size(), (2, 2));
+        assert_eq!(grid.get((1, 1)), Some(&(1, 1)));
+        grid.iter().for_each(|(pos, cell)| assert_eq!(*cell, pos));
+    }
+
+    #[test]
+    #[should_panic(expected = "Grid has no cells")]
+    fn get_wrapped_empty_test() {
+        let mut grid = RollGrid2D::new(2, 2, (0, 0), |pos| pos);
+        let _ = grid.try_reset(try_cell_manager(
+            |_| Err(()),
+            |_, _| Ok(()),
+            |_, _, _| Ok(()),
+        ));
+        grid.get_wrapped((0, 0));
+    }
+
+    #[test]
+    fn reset_panic_drop_test() {
+        use std::{panic, rc::Rc};
+        let counter = Rc::new(());
+        let mut grid = RollGrid2D::new(3, 2, (0, 0), |_| counter.clone());
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            grid.reset(cell_manager(
+                |_| counter.clone(),
+                |pos, _| assert_ne!(pos, (1, 1)),
+                |_, _, _| (),
+            ));
+        }));
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&counter), 1);
+        drop(grid);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn offset_in_units_test() {
+        let grid = RollGrid2D::new(4, 4, (-17, 32), |pos: (i32, i32)| pos);
+        assert_eq!(grid.offset_in_units((16, 16)), (-2, 2));
+        assert_eq!(grid.offset_in_units((1, 1)), (-17, 32));
+        let grid = RollGrid2D::new(4, 4, (-16, -1), |pos: (i32, i32)| pos);
+        assert_eq!(grid.offset_in_units((16, 16)), (-1, -1));
+        assert_eq!(grid.offset_in_units((3, 5)), (-6, -1));
+        let grid = RollGrid2D::new(4, 4, (i32::MIN, 15), |pos: (i32, i32)| pos);
+        assert_eq!(grid.offset_in_units((u32::MAX, 16)), (-1, 0));
+    }
+
+    #[test]
+    fn scroll_test() {
+        for offset in [(1, 0), (-2, 0), (0, 1), (0, -2), (1, -1), (-2, 2), (5, 0)] {
+            let mut grid = RollGrid2D::new(4, 3, (0, 0), |pos: (i32, i32)| Some(pos));
+            let old_bounds = grid.bounds();
+            grid.scroll(offset, |_| None);
+            assert_eq!(grid.offset(), offset);
+            grid.iter().for_each(|(pos, cell)| {
+                if old_bounds.contains(pos) {
+                    // retained cells keep their value
+                    assert_eq!(*cell, Some(pos));
+                } else {
+                    assert_eq!(*cell, None);
+                }
+            });
+        }
+        // old values are dropped when they are replaced
+        let counter = std::rc::Rc::new(());
+        let mut grid = RollGrid2D::new(2, 2, (0, 0), |_| counter.clone());
+        assert_eq!(std::rc::Rc::strong_count(&counter), 5);
+        grid.scroll((1, 0), |_| std::rc::Rc::new(()));
+        assert_eq!(std::rc::Rc::strong_count(&counter), 3);
+    }
+
+    #[test]
+    fn into_iter_test() {
+        let mut grid = RollGrid2D::new(3, 2, (0, 0), |pos: (i32, i32)| pos);
+        grid.translate((-1, 1), |_, new_pos, cell| *cell = new_pos);
+        let expected: Vec<(i32, i32)> = grid.bounds().iter().collect();
+        let iter = grid.into_iter();
+        assert_eq!(iter.len(), 6);
+        let cells: Vec<((i32, i32), (i32, i32))> = iter.collect();
+        assert_eq!(
+            cells.iter().map(|(pos, _)| *pos).collect::<Vec<_>>(),
+            expected
+        );
+        cells.iter().for_each(|(pos, cell)| assert_eq!(pos, cell));
+        // drain into a map
+        let grid = RollGrid2D::new(2, 2, (5, 5), |(x, y)| x * y);
+        let map: HashMap<(i32, i32), i32> = grid.into_iter().collect();
+        assert_eq!(map.len(), 4);
+        assert_eq!(map[&(6, 5)], 30);
+        // every cell is dropped exactly once, including the ones that are not yielded
+        let counter = std::rc::Rc::new(());
+        let mut grid = RollGrid2D::new(3, 3, (0, 0), |_| counter.clone());
+        grid.translate((1, 1), |_, _, _| {});
+        let mut iter = grid.into_iter();
+        let taken: Vec<_> = iter.by_ref().take(4).collect();
+        assert_eq!(std::rc::Rc::strong_count(&counter), 10);
+        drop(iter);
+        assert_eq!(std::rc::Rc::strong_count(&counter), 5);
+        drop(taken);
+        assert_eq!(std::rc::Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn grid_pos_test() {
+        let mut grid = RollGrid2D::new(3, 3, (1, 1), |pos: (i32, i32)| pos);
+        grid.translate((1, 0), |_, new_pos, cell| *cell = new_pos);
+        let tuple = (2, 3);
+        let array = [2, 3];
+        let (tuple_ref, array_ref) = (&tuple, &array);
+        assert_eq!(grid.get(tuple), Some(&(2, 3)));
+        assert_eq!(grid.get(array), Some(&(2, 3)));
+        assert_eq!(grid.get(tuple_ref), Some(&(2, 3)));
+        assert_eq!(grid.get(array_ref), Some(&(2, 3)));
+        assert_eq!(grid.get([0, 0]), None);
+        *grid.get_mut([3, 1]).unwrap() = (0, 0);
+        assert_eq!(grid[(3, 1)], (0, 0));
+        assert_eq!(grid.set(array_ref, (3, 1)), Some((2, 3)));
+        assert_eq!(grid[[2, 3]], (3, 1));
+        grid[array_ref] = (9, 9);
+        assert_eq!(grid.get_copy(tuple_ref), Some((9, 9)));
+        assert_eq!(grid.get_clone([2, 3]), Some((9, 9)));
+        grid.swap([2, 3], tuple_ref);
+        assert_eq!(grid[tuple_ref], (9, 9));
+        grid.swap(array, (3, 3));
+        assert_eq!(grid[[3, 3]], (9, 9));
+        assert_eq!(grid.relative_offset([4, 4]), (2, 3));
+        assert_eq!(grid.get_wrapped([5, 3]), grid.get_wrapped((2, 0)));
+    }
+
+    #[test]
+    fn rows_columns_test() {
+        let mut grid = RollGrid2D::new(3, 2, (0, 0), |(x, y)| x * 10 + y);
+        grid.translate((-2, 1), |_, new_pos, cell| {
+            *cell = new_pos.0 * 10 + new_pos.1
+        });
+        let rows: Vec<Vec<i32>> = grid.rows().map(|row| row.copied().collect()).collect();
+        assert_eq!(rows, vec![vec![-19, -9, 1], vec![-18, -8, 2]]);
+        let columns: Vec<Vec<i32>> = grid.columns().map(|col| col.copied().collect()).collect();
+        assert_eq!(columns, vec![vec![-19, -18], vec![-9, -8], vec![1, 2]]);
+    }
+
+    #[test]
+    fn iter_region_test() {
+        let mut grid = RollGrid2D::new(4, 4, (0, 0), |pos: (i32, i32)| pos);
+        grid.translate((2, -1), |_, new_pos, cell| *cell = new_pos);
+        // clamped to the grid bounds (2, -1)..(6, 3)
+        let region = Bounds2D::new((4, -5), (10, 1));
+        let cells: Vec<_> = grid.iter_region(region).collect();
+        assert_eq!(
+            cells,
+            vec![
+                ((4, -1), &(4, -1)),
+                ((5, -1), &(5, -1)),
+                ((4, 0), &(4, 0)),
+                ((5, 0), &(5, 0)),
+            ]
+        );
+        assert_eq!(grid.iter_region(grid.bounds()).count(), 16);
+        grid.iter_region_mut(Bounds2D::new((3, 1), (5, 3)))
+            .for_each(|(_, cell)| *cell = (0, 0));
+        grid.iter().for_each(|(pos, cell)| {
+            if (3..5).contains(&pos.0) && (1..3).contains(&pos.1) {
+                assert_eq!(*cell, (0, 0));
+            } else {
+                assert_eq!(*cell, pos);
+            }
+        });
+        // bounds outside of the grid yield nothing
+        assert_eq!(grid.iter_region(Bounds2D::new((6, 0), (8, 2))).count(), 0);
+        assert_eq!(
+            grid.iter_region_mut(Bounds2D::new((-9, -9), (-1, -1)))
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn debug_layout_test() {
+        let mut grid = RollGrid2D::new(4, 3, (0, 0), |pos: (i32, i32)| pos);
+        let bounds = grid.bounds();
+        assert_eq!(
+            grid.debug_layout(),
+            format!(
+                "{bounds} wrap_offset: (0, 0)\n0:  0  1  2  3\n1:  4  5  6  7\n2:  8  9 10 11\n"
+            )
+        );
+        grid.reposition((1, 2), |_, new_pos, cell| *cell = new_pos);
+        let bounds = grid.bounds();
+        assert_eq!(
+            grid.debug_layout(),
+            format!(
+                "{bounds} wrap_offset: (1, 2)\n2:  9 10 11  8\n3:  1  2  3  0\n4:  5  6  7  4\n"
+            )
+        );
+    }
+
+    #[test]
+    fn transform_all_test() {
+        use std::rc::Rc;
+        let mut grid = RollGrid2D::new(3, 2, (0, 0), |pos: (i32, i32)| pos);
+        grid.translate((1, 1), |_, new_pos, cell| *cell = new_pos);
+        let mut visited = Vec::new();
+        grid.transform_all(|pos, (x, y)| {
+            visited.push(pos);
+            (x * 10, y * 10)
+        });
+        assert_eq!(visited, grid.bounds().iter().collect::<Vec<_>>());
+        grid.iter()
+            .for_each(|(pos, cell)| assert_eq!(*cell, (pos.0 * 10, pos.1 * 10)));
+        // old values are moved into `f` and dropped exactly once
+        let counter = Rc::new(());
+        let mut grid = RollGrid2D::new(3, 3, (0, 0), |_| counter.clone());
+        grid.transform_all(|_, old| {
+            drop(old);
+            Rc::new(())
+        });
+        assert_eq!(Rc::strong_count(&counter), 1);
+        let mut grid = RollGrid2D::new(3, 3, (0, 0), |_| counter.clone());
+        grid.transform_all(|_, old| old);
+        assert_eq!(Rc::strong_count(&counter), 10);
+        drop(grid);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn transform_all_panic_test() {
+        use std::rc::Rc;
+        let counter = Rc::new(());
+        let replacement = Rc::new(());
+        let mut grid = RollGrid2D::new(3, 3, (0, 0), |_| counter.clone());
+        grid.translate((1, 0), |_, _, _| {});
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            grid.transform_all(|pos, _old| {
+                if pos == (2, 1) {
+                    panic!("transform failed");
+                }
+                replacement.clone()
+            });
+        }));
+        assert!(result.is_err());
+        // the cell owned by `f` was dropped while unwinding and every other cell by the guard
+        assert_eq!(Rc::strong_count(&counter), 1);
+        assert_eq!(Rc::strong_count(&replacement), 1);
+        assert_eq!(grid.size(), (0, 0));
+        assert_eq!(grid.len(), 0);
+        assert_eq!(grid.wrap_offset(), (0, 0));
+        assert!(grid.get((0, 0)).is_none());
+        assert_eq!(grid.iter().count(), 0);
+        grid.resize(
+            2,
+            1,
+            cell_manager(|_| counter.clone(), |_, _| (), |_, _, _| ()),
+        );
+        assert_eq!(grid.iter().count(), 2);
+        assert_eq!(Rc::strong_count(&counter), 3);
+        drop(grid);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn iter_some_test() {
+        let mut grid = RollGrid2D::new(3, 3, (0, 0), |(x, y)| {
+            if (x + y) % 2 == 0 {
+                Some(x * 10 + y)
+            } else {
+                None
+            }
+        });
+        let occupied: Vec<_> = grid.iter_some().collect();
+        assert_eq!(
+            occupied,
+            vec![
+                ((0, 0), &0),
+                ((2, 0), &20),
+                ((1, 1), &11),
+                ((0, 2), &2),
+                ((2, 2), &22)
+            ]
+        );
+        grid.translate((1, 0), |_, _, cell| *cell = None);
+        grid.set((1, 2), Some(-1));
+        let occupied: Vec<_> = grid.iter_some().map(|(pos, _)| pos).collect();
+        assert_eq!(occupied, vec![(2, 0), (1, 1), (1, 2), (2, 2)]);
+        let empty: RollGrid2D<Option<u8>> = RollGrid2D::new_default(2, 2, (0, 0));
+        assert_eq!(empty.iter_some().count(), 0);
+    }
+
+    #[test]
+    fn region_log_test() {
+        use std::collections::HashSet;
+        let mut grid = RollGrid2D::new(4, 3, (0, 0), |pos: (i32, i32)| pos);
+        // disabled by default
+        grid.translate((1, 0), |_, new_pos, cell| *cell = new_pos);
+        assert!(grid.take_region_log().is_empty());
+        grid.enable_region_log();
+        let moves = [(2, 0), (-1, 1), (0, -2), (10, 10), (1, 1)];
+        let mut reloaded = Vec::new();
+        for offset in moves {
+            let mut cells = HashSet::new();
+            grid.translate(offset, |_, new_pos, cell| {
+                cells.insert(new_pos);
+                *cell = new_pos;
+            });
+            reloaded.push(cells);
+        }
+        // repositioning to the current position changes nothing and records nothing
+        grid.reposition(grid.offset(), |_, _, _| unreachable!());
+        let log = grid.take_region_log();
+        assert_eq!(log.len(), moves.len());
+        assert_eq!(log[0], vec![Bounds2D::new((5, 0), (7, 3))]);
+        assert_eq!(
+            log[1],
+            vec![Bounds2D::new((2, 1), (3, 4)), Bounds2D::new((3, 3), (6, 4)),]
+        );
+        assert_eq!(log[2], vec![Bounds2D::new((2, -1), (6, 1))]);
+        assert_eq!(log[3], vec![Bounds2D::new((12, 9), (16, 12))]);
+        for (regions, cells) in log.iter().zip(reloaded.iter()) {
+            let logged: Vec<(i32, i32)> = regions.iter().flat_map(|region| region.iter()).collect();
+            assert_eq!(logged.len(), cells.len());
+            assert_eq!(logged.into_iter().collect::<HashSet<_>>(), *cells);
+        }
+        assert!(grid.take_region_log().is_empty());
+        grid.disable_region_log();
+        grid.translate((1, 0), |_, _, _| {});
+        assert!(grid.take_region_log().is_empty());
+    }
+
+    #[test]
+    fn reposition_single_axis_test() {
+        use std::collections::HashSet;
+        for offset in [(3, 0), (-2, 0), (0, 4), (0, -1), (6, 0), (0, -4)] {
+            let mut grid = RollGrid2D::new(7, 5, (0, 0), |pos: (i32, i32)| pos);
+            // give the grid a nonzero wrap offset first
+            grid.reposition((2, 3), |_, new_pos, cell| *cell = new_pos);
+            assert_eq!(grid.wrap_offset(), (2, 3));
+            let mut try_grid = RollGrid2D::new(7, 5, (0, 0), |pos: (i32, i32)| pos);
+            try_grid.reposition((2, 3), |_, new_pos, cell| *cell = new_pos);
+            let old_bounds = grid.bounds();
+            let new_position = (2 + offset.0, 3 + offset.1);
+            let mut reloaded = HashSet::new();
+            let mut calls = Vec::new();
+            grid.reposition(new_position, |old_pos, new_pos, cell| {
+                calls.push((old_pos, new_pos));
+                assert_eq!(*cell, old_pos);
+                assert!(old_bounds.contains(old_pos));
+                assert!(!old_bounds.contains(new_pos));
+                // the cell is reused from the same row or column on the opposite side
+                if offset.1 == 0 {
+                    assert_eq!(old_pos.1, new_pos.1);
+                    assert_eq!((new_pos.0 - old_pos.0).abs(), 7);
+                } else {
+                    assert_eq!(old_pos.0, new_pos.0);
+                    assert_eq!((new_pos.1 - old_pos.1).abs(), 5);
+                }
+                assert!(reloaded.insert(new_pos));
+                *cell = new_pos;
+            });
+            let expected: HashSet<(i32, i32)> = grid
+                .bounds()
+                .iter()
+                .filter(|pos| !old_bounds.contains(*pos))
+                .collect();
+            assert_eq!(reloaded, expected);
+            grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+            // the result matches a grid that was created at the new position
+            let fresh = RollGrid2D::new(7, 5, new_position, |pos: (i32, i32)| pos);
+            assert_eq!(fresh.to_vec(), grid.to_vec());
+            // the fallible version reloads the same cells in the same order
+            let mut try_calls = Vec::new();
+            try_grid
+                .try_reposition(new_position, |old_pos, new_pos, cell| {
+                    try_calls.push((old_pos, new_pos));
+                    *cell = new_pos;
+                    Ok::<(), ()>(())
+                })
+                .unwrap();
+            assert_eq!(try_calls, calls);
+            assert_eq!(try_grid.wrap_offset(), grid.wrap_offset());
+            assert_eq!(try_grid.as_slice(), grid.as_slice());
+        }
+    }
+
+    #[test]
+    fn delta_round_trip_test() {
+        fn encode(cell: &u16, w: &mut Vec<u8>) -> io::Result<()> {
+            w.extend_from_slice(&cell.to_le_bytes());
+            Ok(())
+        }
+        fn decode(r: &mut &[u8]) -> io::Result<u16> {
+            let mut bytes = [0u8; 2];
+            io::Read::read_exact(r, &mut bytes)?;
+            Ok(u16::from_le_bytes(bytes))
+        }
+        let mut old_state = RollGrid2D::new(5, 4, (-2, 1), |(x, y)| (x * 10 + y) as u16);
+        let previous = old_state.clone_subgrid(old_state.bounds());
+        let mut grid = RollGrid2D::new(5, 4, (-2, 1), |(x, y)| (x * 10 + y) as u16);
+        grid.set((0, 2), 500);
+        grid.set((2, 4), 600);
+        grid.set((-2, 1), 700);
+        let mut delta = Vec::new();
+        grid.encode_delta(&previous, &mut delta, encode).unwrap();
+        assert_eq!(delta.len(), 8 + 3 * (8 + 2));
+        old_state
+            .apply_delta(&mut delta.as_slice(), decode)
+            .unwrap();
+        assert_eq!(old_state.to_vec(), grid.to_vec());
+        // an unchanged grid encodes an empty delta
+        let mut delta = Vec::new();
+        grid.encode_delta(&grid.clone_subgrid(grid.bounds()), &mut delta, encode)
+            .unwrap();
+        assert_eq!(delta, 0u64.to_le_bytes());
+        // cells outside of the previous grid are always written (4 new cells and 2 changed cells)
+        grid.translate((1, 0), |_, (x, y), cell| *cell = (x * 10 + y) as u16);
+        let mut delta = Vec::new();
+        grid.encode_delta(&previous, &mut delta, encode).unwrap();
+        assert_eq!(delta[..8], 6u64.to_le_bytes());
+        // applying a delta with coordinates outside of the grid fails
+        let mut small = RollGrid2D::new(2, 2, (0, 0), |_| 0u16);
+        let err = small
+            .apply_delta(&mut delta.as_slice(), decode)
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn swap_test() {
+        let mut grid = RollGrid2D::new(3, 3, (0, 0), |pos: (i32, i32)| pos);
+        grid.translate((1, 2), |_, new_pos, cell| *cell = new_pos);
+        grid.swap((1, 2), (3, 4));
+        assert_eq!(grid[(1, 2)], (3, 4));
+        assert_eq!(grid[(3, 4)], (1, 2));
+        grid.swap((2, 3), (2, 3));
+        assert_eq!(grid[(2, 3)], (2, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "Out of bounds")]
+    fn swap_out_of_bounds_test() {
+        let mut grid = RollGrid2D::new(3, 3, (0, 0), |pos: (i32, i32)| pos);
+        grid.swap((0, 0), (0, 3));
+    }
+
+    #[test]
+    fn translate_is_full_reload_test() {
+        let grid = RollGrid2D::new(8, 6, (0, 0), |pos: (i32, i32)| pos);
+        assert!(!grid.translate_is_full_reload((0, 0)));
+        assert!(!grid.translate_is_full_reload((7, 5)));
+        assert!(!grid.translate_is_full_reload((-7, -5)));
+        assert!(grid.translate_is_full_reload((8, 0)));
+        assert!(grid.translate_is_full_reload((-8, 0)));
+        assert!(grid.translate_is_full_reload((0, 6)));
+        assert!(grid.translate_is_full_reload((0, -6)));
+        assert!(grid.translate_is_full_reload((i32::MIN, 0)));
+        // the prediction matches what translate does
+        for offset in [(7, 5), (8, 0), (-7, 1), (0, -6), (3, -3)] {
+            let mut moved = RollGrid2D::new(8, 6, (0, 0), |pos: (i32, i32)| pos);
+            let full = moved.translate_is_full_reload(offset);
+            let count = moved.translate_counted(offset, |_, _, _| ());
+            assert_eq!(full, count == moved.len(), "{offset:?}");
+        }
+    }
+
+    #[test]
+    fn reposition_counted_test() {
+        let mut grid = RollGrid2D::new(8, 6, (0, 0), |pos: (i32, i32)| pos);
+        assert_eq!(grid.reposition_counted((0, 0), |_, _, _| ()), 0);
+        // the rolled-in area is the 2x6 and 8x1 strips minus their 2x1 overlap
+        let count = grid.reposition_counted((2, 1), |_, new_pos, cell| *cell = new_pos);
+        assert_eq!(count, 12 + 8 - 2);
+        let count = grid.translate_counted((0, -3), |_, new_pos, cell| *cell = new_pos);
+        assert_eq!(count, 3 * 8);
+        assert_eq!(grid.translate_counted((8, 0), |_, _, _| ()), grid.len());
+        assert_eq!(
+            grid.reposition_counted((-100, 50), |_, _, _| ()),
+            grid.len()
+        );
+    }
+
+    #[test]
+    fn reposition_across_i32_range_test() {
+        let mut grid = RollGrid2D::new(4, 3, (i32::MIN, 0), |pos: (i32, i32)| pos);
+        let count = grid.reposition_counted((i32::MAX - 4, 0), |_, new_pos, cell| *cell = new_pos);
+        assert_eq!(count, grid.len());
+        grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+        grid.try_reposition((i32::MIN, 0), |_, new_pos, cell| {
+            *cell = new_pos;
+            Ok::<(), ()>(())
+        })
+        .unwrap();
+        assert_eq!(grid.offset(), (i32::MIN, 0));
+        grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+    }
+
+    #[test]
+    fn logical_chunks_test() {
+        let mut grid = RollGrid2D::new(5, 3, (0, 0), |pos: (i32, i32)| pos);
+        assert_eq!(grid.logical_chunks().count(), 3);
+        assert_eq!(
+            grid.logical_chunks().collect::<Vec<_>>().concat(),
+            grid.to_vec()
+        );
+        for offset in [(2, 0), (0, 1), (-1, 2), (3, -4), (1, 1)] {
+            grid.translate(offset, |_, new_pos, cell| *cell = new_pos);
+            let chunks: Vec<&[(i32, i32)]> = grid.logical_chunks().collect();
+            let expected_chunks = if grid.wrap_offset().0 == 0 { 3 } else { 6 };
+            assert_eq!(chunks.len(), expected_chunks);
+            assert_eq!(chunks.concat(), grid.to_vec());
+            assert_eq!(chunks.concat(), grid.bounds().iter().collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn coordinate_grid_test() {
+        let mut grid = RollGrid2D::new(5, 3, (0, 0), |_| ());
+        grid.reposition((-7, 4), |_, _, _| ());
+        let coords = grid.coordinate_grid();
+        assert_eq!(coords.bounds(), grid.bounds());
+        assert_eq!(coords.len(), grid.len());
+        coords.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+    }
+
+    #[test]
+    fn resize_and_reposition_max_bound_test() {
+        let mut grid = RollGrid2D::new(2, 2, (0, 0), |pos: (i32, i32)| pos);
+        let offset = (i32::MAX - 4, i32::MAX - 3);
+        grid.resize_and_reposition(
+            4,
+            3,
+            offset,
+            crate::cell_manager(|pos| pos, |_, _| (), |_, _, _| ()),
+        );
+        assert_eq!(grid.bounds().max, (i32::MAX, i32::MAX));
+        grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+    }
+
+    #[test]
+    #[should_panic(expected = "Y maximum bound exceeds i32::MAX")]
+    fn resize_and_reposition_past_max_test() {
+        let mut grid = RollGrid2D::new(2, 2, (0, 0), |pos: (i32, i32)| pos);
+        grid.resize_and_reposition(
+            4,
+            3,
+            (0, i32::MAX - 2),
+            crate::cell_manager(
+                |pos| pos,
+                |_, _| panic!("Unloaded before validation"),
+                |_, _, _| (),
+            ),
+        );
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "Wrap offset is outside of the grid size")]
+    fn corrupt_wrap_offset_test() {
+        let mut grid = RollGrid2D::new(4, 4, (0, 0), |pos: (i32, i32)| pos);
+        grid.wrap_offset = (4, 0);
+        grid.get((0, 0));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "Wrapped index exceeds the capacity of the grid")]
+    fn corrupt_size_test() {
+        let mut grid = RollGrid2D::new(2, 2, (0, 0), |pos: (i32, i32)| pos);
+        grid.size = (3, 2);
+        grid.get((2, 1));
+    }
+
+    #[test]
+    fn resize_default_test() {
+        let mut grid = RollGrid2D::new(2, 2, (-1, -1), |pos: (i32, i32)| pos);
+        grid.translate((1, 0), |_, new_pos, cell| *cell = new_pos);
+        grid.resize_default((3, 1));
+        assert_eq!(grid.size(), (3, 1));
+        assert_eq!(grid.offset(), (0, -1));
+        assert_eq!(grid.to_vec(), vec![(0, -1), (1, -1), (0, 0)]);
+    }
+
+    #[test]
+    fn eq_test() {
+        let mut a = RollGrid2D::new(4, 3, (0, 0), |pos: (i32, i32)| pos);
+        let b = RollGrid2D::new(4, 3, (2, 1), |pos: (i32, i32)| pos);
+        assert_ne!(a, b);
+        a.reposition((2, 1), |_, new_pos, cell| *cell = new_pos);
+        assert_ne!(a.wrap_offset(), b.wrap_offset());
+        assert_eq!(a, b);
+        a.set((3, 2), (0, 0));
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn debug_test() {
+        let mut grid = RollGrid2D::new(3, 2, (0, 0), |(x, y)| x * 10 + y);
+        grid.translate((1, 1), |_, (x, y), cell| *cell = x * 10 + y);
+        // cells are printed in coordinate order regardless of the wrap offset
+        assert_eq!(
+            format!("{grid:?}"),
+            "RollGrid2D { size: (3, 2), offset: (1, 1), cells: [[11, 21, 31], [12, 22, 32]] }"
+        );
+        assert!(format!("{grid:#?}").contains("cells: [\n        [\n            11,"));
+    }
+
+    #[test]
+    fn resize_shrinking_test() {
+        let mut grid = RollGrid2D::new(8, 8, (0, 0), |pos: (i32, i32)| pos);
+        assert_eq!(grid.capacity(), grid.len());
+        for (size, position) in [((4, 4), (2, 2)), ((3, 2), (-5, 1)), ((6, 5), (0, 0))] {
+            let old_bounds = grid.bounds();
+            let old_ptr = unsafe { grid.cells.as_ptr() };
+            let (mut loads, mut unloads) = (0, 0);
+            grid.resize_and_reposition_shrinking(
+                size.0,
+                size.1,
+                position,
+                cell_manager(
+                    |pos| {
+                        loads += 1;
+                        pos
+                    },
+                    |_, _| unloads += 1,
+                    |_, new_pos, cell| *cell = new_pos,
+                ),
+            );
+            let new_bounds = grid.bounds();
+            assert_eq!(grid.size(), size);
+            assert_eq!(grid.capacity(), grid.len());
+            // the cells were moved into a fresh allocation of the new size
+            assert_ne!(unsafe { grid.cells.as_ptr() }, old_ptr);
+            let kept = old_bounds
+                .intersection(new_bounds)
+                .map_or(0, |kept| kept.area());
+            assert_eq!(unloads, old_bounds.area() - kept);
+            assert_eq!(loads, new_bounds.area() - kept);
+            grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+        }
+    }
+
+    #[test]
+    fn work_ranges_test() {
+        for (w, h) in [(1, 1), (3, 4), (7, 5)] {
+            let mut grid = RollGrid2D::new(w, h, (0, 0), |pos: (i32, i32)| pos);
+            let len = grid.len();
+            for n in [1, 2, 3, 7, len, len + 3] {
+                let ranges = grid.work_ranges(n);
+                assert_eq!(ranges.len(), n);
+                let mut next = 0;
+                for range in ranges.iter() {
+                    assert_eq!(range.start, next);
+                    assert!(range.len() <= len / n + 1);
+                    assert!(range.len() >= len / n);
+                    next = range.end;
+                }
+                assert_eq!(next, len);
+                let slice = grid.as_mut_slice();
+                for range in ranges {
+                    slice[range].iter_mut().for_each(|cell| cell.1 += 1);
+                }
+            }
+            assert!(grid.work_ranges(0).is_empty());
+        }
+    }
+
+    #[test]
+    fn resize_and_reposition_disjoint_test() {
+        use std::collections::HashSet;
+        for (width, height) in [(2, 2), (3, 5), (6, 4)] {
+            for y in -3..6 {
+                for x in -3..6 {
+                    let mut grid = RollGrid2D::new(4, 4, (0, 0), |pos: (i32, i32)| pos);
+                    grid.reposition((1, 2), |_, new_pos, cell| *cell = new_pos);
+                    let old_bounds = grid.bounds();
+                    let mut loaded = HashSet::new();
+                    let mut unloaded = HashSet::new();
+                    grid.resize_and_reposition(
+                        width,
+                        height,
+                        (x, y),
+                        crate::cell_manager(
+                            |pos| {
+                                assert!(loaded.insert(pos));
+                                pos
+                            },
+                            |pos, value| {
+                                assert_eq!(pos, value);
+                                assert!(unloaded.insert(pos));
+                            },
+                            |_, _, _| panic!("Unexpected reload"),
+                        ),
+                    );
+                    assert!(loaded.is_disjoint(&unloaded));
+                    let new_bounds = grid.bounds();
+                    for pos in new_bounds.iter() {
+                        assert_eq!(grid.get(pos), Some(&pos));
+                        assert_eq!(loaded.contains(&pos), !old_bounds.contains(pos));
+                    }
+                    for pos in old_bounds.iter() {
+                        assert_eq!(unloaded.contains(&pos), !new_bounds.contains(pos));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn resize_and_reposition_grow_move_count_test() {
+        use std::collections::HashSet;
+        let mut grid = RollGrid2D::new(4, 4, (0, 0), |pos: (i32, i32)| pos);
+        grid.reposition((1, 1), |_, new_pos, cell| *cell = new_pos);
+        let old_bounds = grid.bounds();
+        let new_bounds = Bounds2D::new((-1, 2), (6, 9));
+        let overlap = old_bounds.intersection(new_bounds).unwrap();
+        let mut loaded = HashSet::new();
+        let mut unloaded = HashSet::new();
+        let mut reloads = 0;
+        grid.resize_and_reposition(
+            7,
+            7,
+            (-1, 2),
+            crate::cell_manager(
+                |pos| {
+                    assert!(loaded.insert(pos), "{pos:?} was loaded twice");
+                    pos
+                },
+                |pos, _| assert!(unloaded.insert(pos), "{pos:?} was unloaded twice"),
+                |_, _, _| reloads += 1,
+            ),
+        );
+        assert_eq!(reloads, 0);
+        assert_eq!(loaded.len() as i64, new_bounds.area() - overlap.area());
+        assert_eq!(unloaded.len() as i64, old_bounds.area() - overlap.area());
+        assert!(loaded.iter().all(|&pos| !old_bounds.contains(pos)));
+        new_bounds
+            .iter()
+            .for_each(|pos| assert_eq!(grid.get(pos), Some(&pos)));
+    }
+
+    #[test]
+    fn resize_and_reposition_test() {
+        struct DropCoord {
+            coord: (i32, i32),
+            unloaded: bool,
+        }
+        impl From<(i32, i32)> for DropCoord {
+            fn from(value: (i32, i32)) -> Self {
+                Self {
+                    coord: value,
+                    unloaded: false,
+                }
+            }
+        }
+        impl Drop for DropCoord {
+            fn drop(&mut self) {
+                // assert!(self.unloaded);
+            }
+        }
+        fn verify_grid(grid: &RollGrid2D<DropCoord>) {
+            for y in grid.y_min()..grid.y_max() {
+                for x in grid.x_min()..grid.x_max() {
+                    let pos = (x, y);
+                    let cell = grid.get(pos).expect("Cell was None");
+                    assert_eq!(pos, cell.coord);
+                }
+            }
+        }
+        for height in 1..7 {
+            for width in 1..7 {
+                for y in -1..6 {
+                    for x in -1..6 {
+                        let mut grid =
+                            RollGrid2D::new(4, 4, (0, 0), |pos: (i32, i32)| DropCoord::from(pos));
+                        // reposition to half point to ensure that wrapping does not cause lookup invalidation.
+                        grid.reposition((2, 2), |old_pos, new_pos, cell| {
+                            assert_eq!(old_pos, cell.coord);
+                            cell.coord = new_pos;
+                        });
+                        grid.resize_and_reposition(
+                            width,
+                            height,
+                            (x, y),
+                            crate::cell_manager(
+                                |pos| DropCoord::from(pos),
+                                |pos, value| {
+                                    let mut old = value;
+                                    old.unloaded = true;
+                                    assert_eq!(pos, old.coord);
+                                },
+                                |_, new_pos, value| {
+                                    value.coord = new_pos;
+                                },
+                            ),
+                        );
+                        grid.iter_mut().for_each(|(_, cell)| {
+                            cell.unloaded = true;
+                        });
+                        verify_grid(&grid);
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam_test() {
+        use glam::IVec2;
+        let mut grid = RollGrid2D::new(3, 3, (0, 0), |pos: (i32, i32)| pos);
+        grid.translate(IVec2::new(1, -1), |_, new_pos, cell| *cell = new_pos);
+        assert_eq!(grid.offset(), (1, -1));
+        assert_eq!(grid[IVec2::new(3, 1)], (3, 1));
+        assert_eq!(grid.get(IVec2::new(0, 0)), None);
+        grid.reposition(IVec2::new(2, 2), |_, new_pos, cell| *cell = new_pos);
+        grid[IVec2::new(2, 2)] = (0, 0);
+        assert_eq!(grid.get(IVec2::new(2, 2)), Some(&(0, 0)));
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn mint_test() {
+        use crate::math::Convert;
+        use mint::{Point2, Vector2};
+        let offset = Point2 { x: -1, y: 2 };
+        let mut grid = RollGrid2D::new(3, 3, offset.convert(), |pos: (i32, i32)| pos);
+        assert_eq!(grid.offset(), (-1, 2));
+        assert_eq!(grid.get(Point2 { x: 0, y: 3 }), Some(&(0, 3)));
+        grid.reposition(Point2 { x: 0, y: 0 }, |_, new_pos, cell| *cell = new_pos);
+        grid.translate(Vector2 { x: 1, y: 0 }, |_, new_pos, cell| *cell = new_pos);
+        assert_eq!(grid[Point2 { x: 3, y: 2 }], (3, 2));
+        assert_eq!(grid.get(Point2 { x: 0, y: 0 }), None);
     }
 }
@@ -1,4 +1,4 @@
-use crate::{bounds3d::*, cells::FixedArray, constants::*, *};
+use crate::{bounds3d::*, cells::FixedArray, constants::*, grid3d::Grid3D, math::*, *};
 
 /// A 3D implementation of a rolling grid. It's a data structure similar
 /// to a circular buffer in the sense that cells can wrap around.
@@ -8,7 +8,7 @@ use crate::{bounds3d::*, cells::FixedArray, constants::*, *};
 pub struct RollGrid3D<T> {
     cells: FixedArray<T>,
     size: (usize, usize, usize),
-    wrap_offset: (i32, i32, i32),
+    wrap_offset: (u32, u32, u32),
     grid_offset: (i32, i32, i32),
 }
 
@@ -27,6 +27,33 @@ impl<T: Default> RollGrid3D<T> {
             wrap_offset: (0, 0, 0),
         }
     }
+
+    /// Resize the grid without changing the offset, setting new cells to the default
+    /// for `T` and dropping removed cells.
+    ///
+    /// See [resize](Self::resize).
+    pub fn resize_default(&mut self, new_size: (u32, u32, u32)) {
+        self.resize(
+            new_size.0 as usize,
+            new_size.1 as usize,
+            new_size.2 as usize,
+            cell_manager(|_: (i32, i32, i32)| T::default(), |_, _| (), |_, _, _| ()),
+        );
+    }
+}
+
+impl RollGrid3D<()> {
+    /// Create a new [RollGrid3D] of unit cells without running any per-cell
+    /// initialization. This is useful for testing rolling behavior at large sizes.
+    pub fn new_zst(size: (u32, u32, u32), grid_offset: (i32, i32, i32)) -> Self {
+        let size = (size.0 as usize, size.1 as usize, size.2 as usize);
+        Self {
+            cells: FixedArray::new_zst_3d(size, grid_offset),
+            size,
+            wrap_offset: (0, 0, 0),
+            grid_offset,
+        }
+    }
 }
 
 impl<T> RollGrid3D<T> {
@@ -422,6 +449,10 @@ impl<T> RollGrid3D<T> {
 
     /// Resize and reposition the grid simultaneously.
     ///
+    /// Like [RollGrid2D::resize_and_reposition](crate::rollgrid2d::RollGrid2D::resize_and_reposition),
+    /// each cell is visited once and the overlap of the old and new bounds is carried over
+    /// without being reloaded.
+    ///
     /// # Example
     /// ```rust, no_run
     /// grid.resize_and_reposition(3, 3, 3, (4, 4, 4), cell_manager(
@@ -475,84 +506,20 @@ impl<T> RollGrid3D<T> {
         if volume > i32::MAX as usize {
             panic!("{SIZE_TOO_LARGE}");
         }
-        let (new_x, new_y, new_z) = new_position;
-        let new_width = width as i32;
-        let new_height = height as i32;
-        let new_depth = depth as i32;
+        // Checked before anything is unloaded. See `RollGrid2D::resize_and_reposition`.
+        let new_max = add_size_3d(new_position, (width as u32, height as u32, depth as u32));
         let old_bounds = self.bounds();
-        let new_bounds = Bounds3D::new(
-            (new_x, new_y, new_z),
-            (new_x + new_width, new_y + new_height, new_z + new_depth),
-        );
+        let new_bounds = Bounds3D::try_new(new_position, new_max).expect(INVERTED_BOUNDS);
         if old_bounds.intersects(new_bounds) {
-            macro_rules! unload_bounds {
-                ($cond:expr => xmin = $xmin:expr; ymin = $ymin:expr; zmin = $zmin:expr; xmax = $xmax:expr; ymax = $ymax:expr; zmax = $zmax:expr;) => {
-                    if $cond {
-                        Bounds3D::new(($xmin, $ymin, $zmin), ($xmax, $ymax, $zmax))
-                            .iter()
-                            .for_each(|pos| {
-                                let index = self.offset_index(pos).expect(OUT_OF_BOUNDS);
-                                unsafe {
-                                    manage.unload(pos, self.cells.read(index));
-                                }
-                            });
+            old_bounds.difference(new_bounds).for_each(|region| {
+                region.iter().for_each(|pos| {
+                    let index = self.offset_index(pos).expect(OUT_OF_BOUNDS);
+                    unsafe {
+                        manage.unload(pos, self.cells.read(index));
                     }
-                };
-            }
-            // Y+ region
-            unload_bounds!(old_bounds.y_max() > new_bounds.y_max() =>
-                xmin = old_bounds.x_min();
-                ymin = new_bounds.y_max();
-                zmin = old_bounds.z_min();
-                xmax = old_bounds.x_max();
-                ymax = old_bounds.y_max();
-                zmax = old_bounds.z_max();
-            );
-            // Y- region
-            unload_bounds!(old_bounds.y_min() < new_bounds.y_min() =>
-                xmin = old_bounds.x_min();
-                ymin = old_bounds.y_min();
-                zmin = old_bounds.z_min();
-                xmax = old_bounds.x_max();
-                ymax = new_bounds.y_min();
-                zmax = old_bounds.z_max();
-            );
-            // Z+ region (row)
-            unload_bounds!(old_bounds.z_max() > new_bounds.z_max() =>
-                xmin = old_bounds.x_min();
-                ymin = new_bounds.y_min().max(old_bounds.y_min());
-                zmin = new_bounds.z_max();
-                xmax = old_bounds.x_max();
-                ymax = new_bounds.y_max().min(old_bounds.y_max());
-                zmax = old_bounds.z_max();
-            );
-            // Z- region (row)
-            unload_bounds!(old_bounds.z_min() < new_bounds.z_min() =>
-                xmin = old_bounds.x_min();
-                ymin = new_bounds.y_min().max(old_bounds.y_min());
-                zmin = old_bounds.z_min();
-                xmax = old_bounds.x_max();
-                ymax = new_bounds.y_max().min(old_bounds.y_max());
-                zmax = new_bounds.z_min();
-            );
-            // X+ region (cube)
-            unload_bounds!(old_bounds.x_max() > new_bounds.x_max() =>
-                xmin = new_bounds.x_max();
-                ymin = new_bounds.y_min().max(old_bounds.y_min());
-                zmin = new_bounds.z_min().max(old_bounds.z_min());
-                xmax = old_bounds.x_max();
-                ymax = new_bounds.y_max().min(old_bounds.y_max());
-                zmax = new_bounds.z_max().min(old_bounds.z_max());
-            );
-            // X- region (cube)
-            unload_bounds!(old_bounds.x_min() < new_bounds.x_min() =>
-                xmin = old_bounds.x_min();
-                ymin = new_bounds.y_min().max(old_bounds.y_min());
-                zmin = new_bounds.z_min().max(old_bounds.z_min());
-                xmax = new_bounds.x_min();
-                ymax = new_bounds.y_max().min(old_bounds.y_max());
-                zmax = new_bounds.z_max().min(old_bounds.z_max());
-            );
+                });
+            });
+            // No coordinate is both unloaded and loaded. See `RollGrid2D::resize_and_reposition`.
             let new_grid = FixedArray::new_3d(size, new_position, |pos| {
                 if old_bounds.contains(pos) {
                     let index = self.offset_index(pos).expect(OUT_OF_BOUNDS);
@@ -567,6 +534,8 @@ impl<T> RollGrid3D<T> {
                 self.cells.forget_dealloc();
             }
             self.cells = new_grid;
+            // The new array is laid out in coordinate order, so there is no roll to keep.
+            // See `RollGrid2D::resize_and_reposition`.
             self.wrap_offset = (0, 0, 0);
         } else {
             // !old_bounds.intersects(new_bounds)
@@ -587,6 +556,57 @@ impl<T> RollGrid3D<T> {
         }
     }
 
+    /// Resize and reposition the grid, guaranteeing that the backing storage is
+    /// allocated for exactly the new size afterwards.
+    ///
+    /// [resize_and_reposition](Self::resize_and_reposition) never reuses a larger
+    /// allocation, so this behaves the same; it exists for callers that want the minimal
+    /// footprint to be explicit (and checked) rather than incidental.
+    pub fn resize_and_reposition_shrinking<M>(
+        &mut self,
+        width: usize,
+        height: usize,
+        depth: usize,
+        new_position: (i32, i32, i32),
+        manage: M,
+    ) where
+        M: CellManage<(i32, i32, i32), T>,
+    {
+        self.resize_and_reposition(width, height, depth, new_position, manage);
+        debug_assert_eq!(self.capacity(), self.len());
+    }
+
+    /// Resize and reposition the grid using separate managers for loading and reloading.
+    ///
+    /// `load_manager` handles cells that are loaded and unloaded, while `reload_manager`
+    /// handles cells that remain in the grid but are moved to a new position. Only `reload`
+    /// is called on `reload_manager`, and `reload` is never called on `load_manager`.
+    ///
+    /// See [resize_and_reposition](Self::resize_and_reposition).
+    pub fn resize_split_manage<ML, MR>(
+        &mut self,
+        width: usize,
+        height: usize,
+        depth: usize,
+        new_position: (i32, i32, i32),
+        load_manager: ML,
+        reload_manager: MR,
+    ) where
+        ML: CellManage<(i32, i32, i32), T>,
+        MR: CellManage<(i32, i32, i32), T>,
+    {
+        self.resize_and_reposition(
+            width,
+            height,
+            depth,
+            new_position,
+            SplitCellManage {
+                load: load_manager,
+                reload: reload_manager,
+            },
+        );
+    }
+
     /// Try to resize and reposition the grid using a fallible function.
     ///
     /// # Example
@@ -647,83 +667,17 @@ impl<T> RollGrid3D<T> {
         if volume > i32::MAX as usize {
             panic!("{SIZE_TOO_LARGE}");
         }
-        let (new_x, new_y, new_z) = new_position;
-        let new_width = width as i32;
-        let new_height = height as i32;
-        let new_depth = depth as i32;
+        // Checked before anything is unloaded. See `RollGrid2D::resize_and_reposition`.
+        let new_max = add_size_3d(new_position, (width as u32, height as u32, depth as u32));
         let old_bounds = self.bounds();
-        let new_bounds = Bounds3D::new(
-            (new_x, new_y, new_z),
-            (new_x + new_width, new_y + new_height, new_z + new_depth),
-        );
+        let new_bounds = Bounds3D::try_new(new_position, new_max).expect(INVERTED_BOUNDS);
         if old_bounds.intersects(new_bounds) {
-            macro_rules! unload_bounds {
-                ($cond:expr => xmin = $xmin:expr; ymin = $ymin:expr; zmin = $zmin:expr; xmax = $xmax:expr; ymax = $ymax:expr; zmax = $zmax:expr;) => {
-                    if $cond {
-                        Bounds3D::new(($xmin, $ymin, $zmin), ($xmax, $ymax, $zmax))
-                            .iter()
-                            .try_for_each(|pos| {
-                                let index = self.offset_index(pos).expect(OUT_OF_BOUNDS);
-                                unsafe { manage.try_unload(pos, self.cells.read(index))? }
-                                Ok(())
-                            })?;
-                    }
-                };
-            }
-            // Y+ region
-            unload_bounds!(old_bounds.y_max() > new_bounds.y_max() =>
-                xmin = old_bounds.x_min();
-                ymin = new_bounds.y_max();
-                zmin = old_bounds.z_min();
-                xmax = old_bounds.x_max();
-                ymax = old_bounds.y_max();
-                zmax = old_bounds.z_max();
-            );
-            // Y- region
-            unload_bounds!(old_bounds.y_min() < new_bounds.y_min() =>
-                xmin = old_bounds.x_min();
-                ymin = old_bounds.y_min();
-                zmin = old_bounds.z_min();
-                xmax = old_bounds.x_max();
-                ymax = new_bounds.y_min();
-                zmax = old_bounds.z_max();
-            );
-            // Z+ region (row)
-            unload_bounds!(old_bounds.z_max() > new_bounds.z_max() =>
-                xmin = old_bounds.x_min();
-                ymin = new_bounds.y_min().max(old_bounds.y_min());
-                zmin = new_bounds.z_max();
-                xmax = old_bounds.x_max();
-                ymax = new_bounds.y_max().min(old_bounds.y_max());
-                zmax = old_bounds.z_max();
-            );
-            // Z- region (row)
-            unload_bounds!(old_bounds.z_min() < new_bounds.z_min() =>
-                xmin = old_bounds.x_min();
-                ymin = new_bounds.y_min().max(old_bounds.y_min());
-                zmin = old_bounds.z_min();
-                xmax = old_bounds.x_max();
-                ymax = new_bounds.y_max().min(old_bounds.y_max());
-                zmax = new_bounds.z_min();
-            );
-            // X+ region (cube)
-            unload_bounds!(old_bounds.x_max() > new_bounds.x_max() =>
-                xmin = new_bounds.x_max();
-                ymin = new_bounds.y_min().max(old_bounds.y_min());
-                zmin = new_bounds.z_min().max(old_bounds.z_min());
-                xmax = old_bounds.x_max();
-                ymax = new_bounds.y_max().min(old_bounds.y_max());
-                zmax = new_bounds.z_max().min(old_bounds.z_max());
-            );
-            // X- region (cube)
-            unload_bounds!(old_bounds.x_min() < new_bounds.x_min() =>
-                xmin = old_bounds.x_min();
-                ymin = new_bounds.y_min().max(old_bounds.y_min());
-                zmin = new_bounds.z_min().max(old_bounds.z_min());
-                xmax = new_bounds.x_min();
-                ymax = new_bounds.y_max().min(old_bounds.y_max());
-                zmax = new_bounds.z_max().min(old_bounds.z_max());
-            );
+            old_bounds.difference(new_bounds).try_for_each(|region| {
+                region.iter().try_for_each(|pos| {
+                    let index = self.offset_index(pos).expect(OUT_OF_BOUNDS);
+                    unsafe { manage.try_unload(pos, self.cells.read(index)) }
+                })
+            })?;
             let size = (width, height, depth);
             let new_grid = FixedArray::try_new_3d(size, new_position, |pos| {
                 if old_bounds.contains(pos) {
@@ -762,6 +716,36 @@ impl<T> RollGrid3D<T> {
         Ok(())
     }
 
+    /// Try to resize and reposition the grid using separate fallible managers for loading
+    /// and reloading.
+    ///
+    /// See [resize_split_manage](Self::resize_split_manage) and
+    /// [try_resize_and_reposition](Self::try_resize_and_reposition).
+    pub fn try_resize_split_manage<E, ML, MR>(
+        &mut self,
+        width: usize,
+        height: usize,
+        depth: usize,
+        new_position: (i32, i32, i32),
+        load_manager: ML,
+        reload_manager: MR,
+    ) -> Result<(), E>
+    where
+        ML: TryCellManage<(i32, i32, i32), T, E>,
+        MR: TryCellManage<(i32, i32, i32), T, E>,
+    {
+        self.try_resize_and_reposition(
+            width,
+            height,
+            depth,
+            new_position,
+            SplitCellManage {
+                load: load_manager,
+                reload: reload_manager,
+            },
+        )
+    }
+
     /// Translate the grid by offset amount using a reload function.
     ///
     /// The reload function takes the old position, the new position, and
@@ -775,17 +759,45 @@ impl<T> RollGrid3D<T> {
     ///     *cell_mut = new_position;
     /// })
     /// ```
-    pub fn translate<F>(&mut self, offset: (i32, i32, i32), reload: F)
+    pub fn translate<F>(&mut self, offset: impl GridPos3, reload: F)
     where
         F: FnMut((i32, i32, i32), (i32, i32, i32), &mut T),
     {
-        let (off_x, off_y, off_z) = offset;
-        let new_pos = (
-            self.grid_offset.0 + off_x,
-            self.grid_offset.1 + off_y,
-            self.grid_offset.2 + off_z,
-        );
-        self.reposition(new_pos, reload);
+        let offset = offset.into_tuple();
+        self.reposition(offset_position_3d(self.grid_offset, offset), reload);
+    }
+
+    /// Translate the grid by offset amount using a reload function, returning the
+    /// number of cells that were passed to the reload function.
+    ///
+    /// See [translate](Self::translate).
+    pub fn translate_counted<F>(&mut self, offset: impl GridPos3, mut reload: F) -> usize
+    where
+        F: FnMut((i32, i32, i32), (i32, i32, i32), &mut T),
+    {
+        let mut count = 0;
+        self.translate(offset, |old_position, new_position, cell| {
+            count += 1;
+            reload(old_position, new_position, cell);
+        });
+        count
+    }
+
+    /// Reposition the offset of the grid and reload the slots that are changed,
+    /// returning the number of cells that were passed to the reload function.
+    ///
+    /// This is `0` when the position doesn't change, and the number of cells in the grid
+    /// when the new bounds do not overlap the old bounds. See [reposition](Self::reposition).
+    pub fn reposition_counted<F>(&mut self, position: impl GridPos3, mut reload: F) -> usize
+    where
+        F: FnMut((i32, i32, i32), (i32, i32, i32), &mut T),
+    {
+        let mut count = 0;
+        self.reposition(position, |old_position, new_position, cell| {
+            count += 1;
+            reload(old_position, new_position, cell);
+        });
+        count
     }
 
     /// Try to translate the grid by offset amount using a fallible reload function.
@@ -797,17 +809,12 @@ impl<T> RollGrid3D<T> {
     ///     Ok(())
     /// })
     /// ```
-    pub fn try_translate<E, F>(&mut self, offset: (i32, i32, i32), reload: F) -> Result<(), E>
+    pub fn try_translate<E, F>(&mut self, offset: impl GridPos3, reload: F) -> Result<(), E>
     where
         F: FnMut((i32, i32, i32), (i32, i32, i32), &mut T) -> Result<(), E>,
     {
-        let (off_x, off_y, off_z) = offset;
-        let new_pos = (
-            self.grid_offset.0 + off_x,
-            self.grid_offset.1 + off_y,
-            self.grid_offset.2 + off_z,
-        );
-        self.try_reposition(new_pos, reload)
+        let offset = offset.into_tuple();
+        self.try_reposition(offset_position_3d(self.grid_offset, offset), reload)
     }
 
     /// Reposition the offset of the grid and reload the slots that are changed.
@@ -823,28 +830,82 @@ impl<T> RollGrid3D<T> {
     ///     *cell_mut = new_position;
     /// })
     /// ```
-    pub fn reposition<F>(&mut self, position: (i32, i32, i32), reload: F)
+    pub fn reposition<F>(&mut self, position: impl GridPos3, reload: F)
     where
         F: FnMut((i32, i32, i32), (i32, i32, i32), &mut T),
     {
+        let position = position.into_tuple();
         let mut reload = reload;
         if self.grid_offset == position {
             return;
         }
+        // Make sure that the new bounds fit in i32.
+        let new_max = add_size_3d(
+            position,
+            (self.size.0 as u32, self.size.1 as u32, self.size.2 as u32),
+        );
         let (old_x, old_y, old_z) = self.grid_offset;
         let (new_x, new_y, new_z) = position;
-        let offset = (new_x - old_x, new_y - old_y, new_z - old_z);
+        // Positions too far apart to subtract in i32 can't overlap, so they take the
+        // full reload path below.
+        let offset = position.checked_sub_coord(self.grid_offset);
         let width = self.size.0 as i32;
         let height = self.size.1 as i32;
         let depth = self.size.2 as i32;
-        let (offset_x, offset_y, offset_z) = offset;
         let old_bounds = self.bounds();
-        let new_bounds = Bounds3D::new(
-            (new_x, new_y, new_z),
-            (new_x + width, new_y + height, new_z + depth),
-        );
+        let new_bounds = Bounds3D::try_new(position, new_max).expect(INVERTED_BOUNDS);
         // A cool trick to test whether the translation moves out of bounds.
-        if offset_x.abs() < width && offset_y.abs() < height && offset_z.abs() < depth {
+        if let Some((offset_x, offset_y, offset_z)) =
+            offset.filter(|&(offset_x, offset_y, offset_z)| {
+                offset_x.unsigned_abs() < width as u32
+                    && offset_y.unsigned_abs() < height as u32
+                    && offset_z.unsigned_abs() < depth as u32
+            })
+        {
+            // Moves along a single axis only roll in a single slab, so reload it
+            // directly and skip the half/quarter/eighth partitioning.
+            let moved_axes = [offset_x, offset_y, offset_z]
+                .iter()
+                .filter(|&&axis| axis != 0)
+                .count();
+            if moved_axes == 1 {
+                let slab_range =
+                    |offset: i32, old_range: (i32, i32), new_range: (i32, i32)| match offset
+                        .signum()
+                    {
+                        1 => (old_range.1, new_range.1),
+                        -1 => (new_range.0, old_range.0),
+                        _ => new_range,
+                    };
+                let (x_min, x_max) = slab_range(
+                    offset_x,
+                    (old_bounds.x_min(), old_bounds.x_max()),
+                    (new_bounds.x_min(), new_bounds.x_max()),
+                );
+                let (y_min, y_max) = slab_range(
+                    offset_y,
+                    (old_bounds.y_min(), old_bounds.y_max()),
+                    (new_bounds.y_min(), new_bounds.y_max()),
+                );
+                let (z_min, z_max) = slab_range(
+                    offset_z,
+                    (old_bounds.z_min(), old_bounds.z_max()),
+                    (new_bounds.z_min(), new_bounds.z_max()),
+                );
+                let slab = Bounds3D::new((x_min, y_min, z_min), (x_max, y_max, z_max));
+                self.wrap_offset = (
+                    (self.wrap_offset.0 as i32 + offset_x).rem_euclid(width) as u32,
+                    (self.wrap_offset.1 as i32 + offset_y).rem_euclid(height) as u32,
+                    (self.wrap_offset.2 as i32 + offset_z).rem_euclid(depth) as u32,
+                );
+                self.grid_offset = (new_x, new_y, new_z);
+                slab.iter().for_each(|(x, y, z)| {
+                    let old_pos = old_bounds.wrap((x, y, z));
+                    let index = self.offset_index((x, y, z)).expect(OUT_OF_BOUNDS);
+                    reload(old_pos, (x, y, z), &mut self.cells[index]);
+                });
+                return;
+            }
             // translation in bounds, the hard part.
             // My plan is to subdivide the reload region into (upto) three parts.
             // It's very difficult to visualize this stuff, so I used Minecraft to create a rudimentary visualization.
@@ -1229,8 +1290,11 @@ impl<T> RollGrid3D<T> {
                 (half_region, quarter_region, None)
             };
             // Calculate new wrap_offset
-            let (wrap_x, wrap_y, wrap_z) =
-                (self.wrap_offset.0, self.wrap_offset.1, self.wrap_offset.2);
+            let (wrap_x, wrap_y, wrap_z) = (
+                self.wrap_offset.0 as i32,
+                self.wrap_offset.1 as i32,
+                self.wrap_offset.2 as i32,
+            );
             let (wrapped_offset_x, wrapped_offset_y, wrapped_offset_z) = (
                 offset_x.rem_euclid(width),
                 offset_y.rem_euclid(height),
@@ -1239,43 +1303,25 @@ impl<T> RollGrid3D<T> {
             let new_wrap_x = (wrap_x + wrapped_offset_x).rem_euclid(width);
             let new_wrap_y = (wrap_y + wrapped_offset_y).rem_euclid(height);
             let new_wrap_z = (wrap_z + wrapped_offset_z).rem_euclid(depth);
-            struct OffsetFix {
-                /// the old grid offset that we can use to
-                /// create a relational offset
-                offset: (i32, i32, i32),
-                size: (i32, i32, i32),
-            }
-            impl OffsetFix {
-                fn wrap(&self, pos: (i32, i32, i32)) -> (i32, i32, i32) {
-                    let x = (pos.0 - self.offset.0).rem_euclid(self.size.0) + self.offset.0;
-                    let y = (pos.1 - self.offset.1).rem_euclid(self.size.1) + self.offset.1;
-                    let z = (pos.2 - self.offset.2).rem_euclid(self.size.2) + self.offset.2;
-                    (x, y, z)
-                }
-            }
-            let fix = OffsetFix {
-                offset: self.grid_offset,
-                size: (width, height, depth),
-            };
-            self.wrap_offset = (new_wrap_x, new_wrap_y, new_wrap_z);
+            self.wrap_offset = (new_wrap_x as u32, new_wrap_y as u32, new_wrap_z as u32);
             self.grid_offset = (new_x, new_y, new_z);
             // Now that we have the regions, we can iterate over them to reload cells.
             // iterate regions and reload cells
             half_region.iter().for_each(|pos| {
-                let old_pos = fix.wrap(pos);
+                let old_pos = old_bounds.wrap(pos);
                 let index = self.offset_index(pos).expect(OUT_OF_BOUNDS);
                 reload(old_pos, pos, &mut self.cells[index]);
             });
             if let Some(quarter) = quarter_region {
                 quarter.iter().for_each(|pos| {
-                    let old_pos = fix.wrap(pos);
+                    let old_pos = old_bounds.wrap(pos);
                     let index = self.offset_index(pos).expect(OUT_OF_BOUNDS);
                     reload(old_pos, pos, &mut self.cells[index]);
                 });
             }
             if let Some(eighth) = eighth_region {
                 eighth.iter().for_each(|pos| {
-                    let old_pos = fix.wrap(pos);
+                    let old_pos = old_bounds.wrap(pos);
                     let index = self.offset_index(pos).expect(OUT_OF_BOUNDS);
                     reload(old_pos, pos, &mut self.cells[index]);
                 });
@@ -1315,28 +1361,38 @@ impl<T> RollGrid3D<T> {
     ///     Ok(())
     /// })
     /// ```
-    pub fn try_reposition<E, F>(&mut self, position: (i32, i32, i32), reload: F) -> Result<(), E>
+    pub fn try_reposition<E, F>(&mut self, position: impl GridPos3, reload: F) -> Result<(), E>
     where
         F: FnMut((i32, i32, i32), (i32, i32, i32), &mut T) -> Result<(), E>,
     {
+        let position = position.into_tuple();
         let mut reload = reload;
         if self.grid_offset == position {
             return Ok(());
         }
+        // Make sure that the new bounds fit in i32.
+        let new_max = add_size_3d(
+            position,
+            (self.size.0 as u32, self.size.1 as u32, self.size.2 as u32),
+        );
         let (old_x, old_y, old_z) = self.grid_offset;
         let (new_x, new_y, new_z) = position;
-        let offset = (new_x - old_x, new_y - old_y, new_z - old_z);
+        // Positions too far apart to subtract in i32 can't overlap, so they take the
+        // full reload path below.
+        let offset = position.checked_sub_coord(self.grid_offset);
         let width = self.size.0 as i32;
         let height = self.size.1 as i32;
         let depth = self.size.2 as i32;
-        let (offset_x, offset_y, offset_z) = offset;
         let old_bounds = self.bounds();
-        let new_bounds = Bounds3D::new(
-            (new_x, new_y, new_z),
-            (new_x + width, new_y + height, new_z + depth),
-        );
+        let new_bounds = Bounds3D::try_new(position, new_max).expect(INVERTED_BOUNDS);
         // A cool trick to test whether the translation moves out of bounds.
-        if offset_x.abs() < width && offset_y.abs() < height && offset_z.abs() < depth {
+        if let Some((offset_x, offset_y, offset_z)) =
+            offset.filter(|&(offset_x, offset_y, offset_z)| {
+                offset_x.unsigned_abs() < width as u32
+                    && offset_y.unsigned_abs() < height as u32
+                    && offset_z.unsigned_abs() < depth as u32
+            })
+        {
             // translation in bounds, the hard part.
             // My plan is to subdivide the reload region into (upto) three parts.
             // It's very difficult to visualize this stuff, so I used Minecraft to create a rudimentary visualization.
@@ -1721,8 +1777,11 @@ impl<T> RollGrid3D<T> {
                 (half_region, quarter_region, None)
             };
             // Calculate new wrap_offset
-            let (wrap_x, wrap_y, wrap_z) =
-                (self.wrap_offset.0, self.wrap_offset.1, self.wrap_offset.2);
+            let (wrap_x, wrap_y, wrap_z) = (
+                self.wrap_offset.0 as i32,
+                self.wrap_offset.1 as i32,
+                self.wrap_offset.2 as i32,
+            );
             let (wrapped_offset_x, wrapped_offset_y, wrapped_offset_z) = (
                 offset_x.rem_euclid(width),
                 offset_y.rem_euclid(height),
@@ -1731,37 +1790,19 @@ impl<T> RollGrid3D<T> {
             let new_wrap_x = (wrap_x + wrapped_offset_x).rem_euclid(width);
             let new_wrap_y = (wrap_y + wrapped_offset_y).rem_euclid(height);
             let new_wrap_z = (wrap_z + wrapped_offset_z).rem_euclid(depth);
-            struct OffsetFix {
-                /// the old grid offset that we can use to
-                /// create a relational offset
-                offset: (i32, i32, i32),
-                size: (i32, i32, i32),
-            }
-            impl OffsetFix {
-                fn wrap(&self, pos: (i32, i32, i32)) -> (i32, i32, i32) {
-                    let x = (pos.0 - self.offset.0).rem_euclid(self.size.0) + self.offset.0;
-                    let y = (pos.1 - self.offset.1).rem_euclid(self.size.1) + self.offset.1;
-                    let z = (pos.2 - self.offset.2).rem_euclid(self.size.2) + self.offset.2;
-                    (x, y, z)
-                }
-            }
-            let fix = OffsetFix {
-                offset: self.grid_offset,
-                size: (width, height, depth),
-            };
-            self.wrap_offset = (new_wrap_x, new_wrap_y, new_wrap_z);
+            self.wrap_offset = (new_wrap_x as u32, new_wrap_y as u32, new_wrap_z as u32);
             self.grid_offset = (new_x, new_y, new_z);
             // Now that we have the regions, we can iterate over them to reload cells.
             // iterate regions and reload cells
             half_region.iter().try_for_each(|pos| {
-                let old_pos = fix.wrap(pos);
+                let old_pos = old_bounds.wrap(pos);
                 let index = self.offset_index(pos).expect(OUT_OF_BOUNDS);
                 reload(old_pos, pos, &mut self.cells[index])?;
                 Ok(())
             })?;
             if let Some(quarter) = quarter_region {
                 quarter.iter().try_for_each(|pos| {
-                    let old_pos = fix.wrap(pos);
+                    let old_pos = old_bounds.wrap(pos);
                     let index = self.offset_index(pos).expect(OUT_OF_BOUNDS);
                     reload(old_pos, pos, &mut self.cells[index])?;
                     Ok(())
@@ -1769,7 +1810,7 @@ impl<T> RollGrid3D<T> {
             }
             if let Some(eighth) = eighth_region {
                 eighth.iter().try_for_each(|pos| {
-                    let old_pos = fix.wrap(pos);
+                    let old_pos = old_bounds.wrap(pos);
                     let index = self.offset_index(pos).expect(OUT_OF_BOUNDS);
                     reload(old_pos, pos, &mut self.cells[index])?;
                     Ok(())
@@ -1798,8 +1839,8 @@ impl<T> RollGrid3D<T> {
     }
 
     /// Get the offset relative to the grid's offset.
-    pub fn relative_offset(&self, coord: (i32, i32, i32)) -> (i32, i32, i32) {
-        let (x, y, z) = coord;
+    pub fn relative_offset(&self, coord: impl GridPos3) -> (i32, i32, i32) {
+        let (x, y, z) = coord.into_tuple();
         (
             x - self.grid_offset.0,
             y - self.grid_offset.1,
@@ -1811,7 +1852,8 @@ impl<T> RollGrid3D<T> {
     /// This method allows to find the index of a particular offset in the grid.
     /// Offsets are relative to the world origin `(0, 0, 0)`, and must account for
     /// the grid offset.
-    fn offset_index(&self, (x, y, z): (i32, i32, i32)) -> Option<usize> {
+    fn offset_index(&self, coord: impl GridPos3) -> Option<usize> {
+        let (x, y, z) = coord.into_tuple();
         let (mx, my, mz) = self.grid_offset;
         let width = self.size.0 as i32;
         let height = self.size.1 as i32;
@@ -1829,15 +1871,25 @@ impl<T> RollGrid3D<T> {
             self.wrap_offset.1 as i32,
             self.wrap_offset.2 as i32,
         );
+        // As in `RollGrid2D::offset_index`, only an internal bug in the roll logic can put
+        // the wrap offset out of range, so this check is left to debug builds.
+        debug_assert!(
+            (0..width).contains(&wx) && (0..height).contains(&wy) && (0..depth).contains(&wz),
+            "{WRAP_OFFSET_OUT_OF_RANGE}: {:?} is not within {:?}",
+            self.wrap_offset,
+            self.size,
+        );
         let wx = (nx + wx).rem_euclid(width);
         let wy = (ny + wy).rem_euclid(height);
         let wz = (nz + wz).rem_euclid(depth);
         let plane = self.size.0 * self.size.2;
-        Some(wy as usize * plane + wz as usize * self.size.0 + wx as usize)
+        let index = wy as usize * plane + wz as usize * self.size.0 + wx as usize;
+        debug_assert!(index < self.cells.len(), "{INDEX_EXCEEDS_CAPACITY}");
+        Some(index)
     }
 
     /// Reads the value from the cell without moving it. This leaves the memory in the cell unchanged.
-    pub unsafe fn read(&self, coord: (i32, i32, i32)) -> Option<T> {
+    pub unsafe fn read(&self, coord: impl GridPos3) -> Option<T> {
         let index = self.offset_index(coord)?;
         Some(self.cells.read(index))
     }
@@ -1849,30 +1901,206 @@ impl<T> RollGrid3D<T> {
     /// Additionally, it does not drop the contents of the cell. Semantically, `value` is moved into the cell at the given coordinate.
     ///
     /// This is appropriate for initializing uninitialized cells, or overwriting memory that has previously been [read] from.
-    pub unsafe fn write(&mut self, coord: (i32, i32, i32), value: T) {
+    pub unsafe fn write(&mut self, coord: impl GridPos3, value: T) {
         let index = self.offset_index(coord).expect(OUT_OF_BOUNDS);
         self.cells.write(index, value);
     }
 
     /// Get a reference to the cell's value if it exists and the coord is in bounds, otherwise return `None`.
-    pub fn get(&self, coord: (i32, i32, i32)) -> Option<&T> {
+    pub fn get(&self, coord: impl GridPos3) -> Option<&T> {
         let index = self.offset_index(coord)?;
         Some(&self.cells[index])
     }
 
     /// Get a mutable reference to the cell's value if it exists and the coord is in bounds, otherwise return `None`.
-    pub fn get_mut(&mut self, coord: (i32, i32, i32)) -> Option<&mut T> {
+    pub fn get_mut(&mut self, coord: impl GridPos3) -> Option<&mut T> {
         let index = self.offset_index(coord)?;
         Some(&mut self.cells[index])
     }
 
     /// Set the cell's value, returning the old value in the process.
-    pub fn set(&mut self, coord: (i32, i32, i32), value: T) -> Option<T> {
+    pub fn set(&mut self, coord: impl GridPos3, value: T) -> Option<T> {
         let index = self.offset_index(coord)?;
         let dest = &mut self.cells[index];
         Some(std::mem::replace(dest, value))
     }
 
+    /// Swap the values of the cells at `a` and `b`.
+    ///
+    /// Panics if either coordinate is out of bounds.
+    pub fn swap(&mut self, a: impl GridPos3, b: impl GridPos3) {
+        let ia = self.offset_index(a).expect(OUT_OF_BOUNDS);
+        let ib = self.offset_index(b).expect(OUT_OF_BOUNDS);
+        self.cells.as_mut_slice().swap(ia, ib);
+    }
+
+    /// Set every cell on the outer shell of the grid to `value`, leaving the interior untouched.
+    pub fn fill_shell(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.bounds().iter_shell().for_each(|coord| {
+            let index = self.offset_index(coord).expect(OUT_OF_BOUNDS);
+            self.cells[index] = value.clone();
+        });
+    }
+
+    /// Get a [Grid3D] of references to the cells within `bounds`.
+    ///
+    /// Panics if `bounds` is empty or not entirely within the grid.
+    pub fn subgrid(&self, bounds: Bounds3D) -> Grid3D<&T> {
+        if let Err(err) = self.check_subgrid(bounds) {
+            panic!("{err}");
+        }
+        Grid3D::new(
+            bounds.width() as usize,
+            bounds.height() as usize,
+            bounds.depth() as usize,
+            bounds.min,
+            |pos| &self.cells[self.offset_index(pos).expect(OUT_OF_BOUNDS)],
+        )
+    }
+
+    /// Get a [Grid3D] of mutable references to the cells within `bounds`.
+    ///
+    /// Panics if `bounds` is empty or not entirely within the grid.
+    pub fn subgrid_mut(&mut self, bounds: Bounds3D) -> Grid3D<&mut T> {
+        if let Err(err) = self.check_subgrid(bounds) {
+            panic!("{err}");
+        }
+        let cells_ptr = unsafe { self.cells.as_mut_ptr() };
+        let grid = &*self;
+        Grid3D::new(
+            bounds.width() as usize,
+            bounds.height() as usize,
+            bounds.depth() as usize,
+            bounds.min,
+            // Each coordinate maps to a unique index, so no cell is borrowed twice.
+            |pos| unsafe { &mut *cells_ptr.add(grid.offset_index(pos).expect(OUT_OF_BOUNDS)) },
+        )
+    }
+
+    fn check_subgrid(&self, bounds: Bounds3D) -> Result<(), RollGridError> {
+        if bounds.intersection(self.bounds()) != Some(bounds) {
+            Err(RollGridError::OutOfBounds)
+        } else if bounds.volume() == 0 {
+            Err(RollGridError::EmptyBounds)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Iterate the in-bounds face-adjacent neighbors of `coord` (up to 6) along with their coordinates.
+    ///
+    /// Neighbors are yielded in the order `-X`, `+X`, `-Y`, `+Y`, `-Z`, `+Z`.
+    pub fn neighbors6(
+        &self,
+        coord: (i32, i32, i32),
+    ) -> impl Iterator<Item = ((i32, i32, i32), &T)> {
+        const OFFSETS: [(i32, i32, i32); 6] = [
+            (-1, 0, 0),
+            (1, 0, 0),
+            (0, -1, 0),
+            (0, 1, 0),
+            (0, 0, -1),
+            (0, 0, 1),
+        ];
+        self.neighbors(coord, OFFSETS)
+    }
+
+    /// Iterate the in-bounds surrounding cells of `coord` (up to 26) along with their coordinates.
+    ///
+    /// Neighbors are yielded in x, then z, then y order, skipping `coord` itself.
+    pub fn neighbors26(
+        &self,
+        coord: (i32, i32, i32),
+    ) -> impl Iterator<Item = ((i32, i32, i32), &T)> {
+        const OFFSETS: [(i32, i32, i32); 26] = {
+            let mut offsets = [(0, 0, 0); 26];
+            let mut i = 0;
+            let mut y = -1;
+            while y <= 1 {
+                let mut z = -1;
+                while z <= 1 {
+                    let mut x = -1;
+                    while x <= 1 {
+                        if x != 0 || y != 0 || z != 0 {
+                            offsets[i] = (x, y, z);
+                            i += 1;
+                        }
+                        x += 1;
+                    }
+                    z += 1;
+                }
+                y += 1;
+            }
+            offsets
+        };
+        self.neighbors(coord, OFFSETS)
+    }
+
+    fn neighbors<const N: usize>(
+        &self,
+        coord: (i32, i32, i32),
+        offsets: [(i32, i32, i32); N],
+    ) -> impl Iterator<Item = ((i32, i32, i32), &T)> {
+        offsets.into_iter().filter_map(move |(x, y, z)| {
+            let pos = (
+                coord.0.checked_add(x)?,
+                coord.1.checked_add(y)?,
+                coord.2.checked_add(z)?,
+            );
+            Some((pos, self.get(pos)?))
+        })
+    }
+
+    /// Rearrange the cells in memory so that the wrap offset is zero on all axes.
+    /// After normalizing, the backing storage is in x, then z, then y coordinate order.
+    pub fn normalize(&mut self) {
+        self.normalize_axis(Axis::X);
+        self.normalize_axis(Axis::Z);
+        self.normalize_axis(Axis::Y);
+    }
+
+    /// Rearrange the cells in memory so that the wrap offset is zero on `axis`,
+    /// leaving the wrap offset of the other axes unchanged.
+    pub fn normalize_axis(&mut self, axis: Axis) {
+        let width = self.size.0;
+        let plane = self.size.0 * self.size.2;
+        match axis {
+            Axis::X => {
+                let wrap_x = self.wrap_offset.0 as usize;
+                if wrap_x == 0 {
+                    return;
+                }
+                self.cells
+                    .as_mut_slice()
+                    .chunks_exact_mut(width)
+                    .for_each(|row| row.rotate_left(wrap_x));
+                self.wrap_offset.0 = 0;
+            }
+            Axis::Y => {
+                let wrap_y = self.wrap_offset.1 as usize;
+                if wrap_y == 0 {
+                    return;
+                }
+                self.cells.as_mut_slice().rotate_left(wrap_y * plane);
+                self.wrap_offset.1 = 0;
+            }
+            Axis::Z => {
+                let wrap_z = self.wrap_offset.2 as usize;
+                if wrap_z == 0 {
+                    return;
+                }
+                self.cells
+                    .as_mut_slice()
+                    .chunks_exact_mut(plane)
+                    .for_each(|layer| layer.rotate_left(wrap_z * width));
+                self.wrap_offset.2 = 0;
+            }
+        }
+    }
+
     /// Get the dimensions of the grid.
     pub fn size(&self) -> (usize, usize, usize) {
         self.size
@@ -1898,6 +2126,14 @@ impl<T> RollGrid3D<T> {
         self.grid_offset
     }
 
+    /// Get the internal wrap offset of the grid, which is where the cell at the
+    /// grid offset is located in the backing storage on each axis.
+    ///
+    /// This is mostly useful for debugging.
+    pub fn wrap_offset(&self) -> (u32, u32, u32) {
+        self.wrap_offset
+    }
+
     /// Get the minimum bound on the `X` axis.
     pub fn x_min(&self) -> i32 {
         self.grid_offset.0
@@ -1905,7 +2141,7 @@ impl<T> RollGrid3D<T> {
 
     /// Get the maximum bound on the `X` axis.
     pub fn x_max(&self) -> i32 {
-        self.grid_offset.0 + self.size.0 as i32
+        checked_add_u32_to_i32(self.grid_offset.0, self.size.0 as u32).expect(X_MAX_EXCEEDS_MAXIMUM)
     }
 
     /// Get the minimum bound on the `Y` axis.
@@ -1915,7 +2151,7 @@ impl<T> RollGrid3D<T> {
 
     /// Get the maximum bound on the `Y` axis.
     pub fn y_max(&self) -> i32 {
-        self.grid_offset.1 + self.size.1 as i32
+        checked_add_u32_to_i32(self.grid_offset.1, self.size.1 as u32).expect(Y_MAX_EXCEEDS_MAXIMUM)
     }
 
     /// Get the minimum bound on the `Z` axis.
@@ -1925,7 +2161,7 @@ impl<T> RollGrid3D<T> {
 
     /// Get the maximum bound on the `Z` axis.
     pub fn z_max(&self) -> i32 {
-        self.grid_offset.2 + self.size.2 as i32
+        checked_add_u32_to_i32(self.grid_offset.2, self.size.2 as u32).expect(Z_MAX_EXCEEDS_MAXIMUM)
     }
 
     /// Get the bounds of the grid.
@@ -1941,6 +2177,43 @@ impl<T> RollGrid3D<T> {
         self.size.0 * self.size.1 * self.size.2
     }
 
+    /// The number of cells the backing storage has allocated space for.
+    ///
+    /// Resizing always allocates exactly the new size, so this is equal to [len](Self::len).
+    pub fn capacity(&self) -> usize {
+        self.cells.capacity()
+    }
+
+    /// Get the backing storage of the grid as a slice.
+    ///
+    /// Cells are in storage order, which only matches coordinate order
+    /// when the wrap offset is zero.
+    pub fn as_slice(&self) -> &[T] {
+        self.cells.as_slice()
+    }
+
+    /// Get the backing storage of the grid as a mutable slice.
+    ///
+    /// Cells are in storage order, which only matches coordinate order
+    /// when the wrap offset is zero.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.cells.as_mut_slice()
+    }
+
+    /// Split the backing storage into `n` contiguous index ranges of near-equal size
+    /// that together cover `0..len`. This is useful for distributing work across threads
+    /// with [as_mut_slice](Self::as_mut_slice).
+    ///
+    /// The ranges index the backing storage, not coordinates. Cells must be normalized
+    /// (the wrap offset must be zero) for index `i` to correspond to the `i`th coordinate
+    /// in iteration order.
+    ///
+    /// If `n` is greater than `len`, the trailing ranges are empty. If `n` is zero, no ranges
+    /// are returned.
+    pub fn work_ranges(&self, n: usize) -> Vec<std::ops::Range<usize>> {
+        self.cells.work_ranges(n)
+    }
+
     /// Get an iterator over the cells in the grid.
     pub fn iter<'a>(&'a self) -> RollGrid3DIterator<'a, T> {
         RollGrid3DIterator {
@@ -1956,11 +2229,41 @@ impl<T> RollGrid3D<T> {
             grid: self,
         }
     }
+
+    /// Get an iterator over the cells in the intersection of `bounds` and the grid's bounds,
+    /// in `x -> z -> y` order.
+    ///
+    /// If `bounds` does not intersect the grid, the iterator is empty.
+    pub fn iter_region<'a>(&'a self, bounds: Bounds3D) -> RollGrid3DIterator<'a, T> {
+        RollGrid3DIterator {
+            bounds_iter: self.region_bounds(bounds).iter(),
+            grid: self,
+        }
+    }
+
+    /// Get a mutable iterator over the cells in the intersection of `bounds` and the grid's
+    /// bounds, in `x -> z -> y` order.
+    ///
+    /// If `bounds` does not intersect the grid, the iterator is empty.
+    pub fn iter_region_mut<'a>(&'a mut self, bounds: Bounds3D) -> RollGrid3DMutIterator<'a, T> {
+        RollGrid3DMutIterator {
+            bounds_iter: self.region_bounds(bounds).iter(),
+            grid: self,
+        }
+    }
+
+    /// Clamp `bounds` to the grid's bounds, returning empty bounds if they don't intersect.
+    fn region_bounds(&self, bounds: Bounds3D) -> Bounds3D {
+        let grid_bounds = self.bounds();
+        bounds
+            .intersection(grid_bounds)
+            .unwrap_or(Bounds3D::new(grid_bounds.min, grid_bounds.min))
+    }
 }
 
 impl<T: Copy> RollGrid3D<T> {
     /// Get a copy of the grid value.
-    pub fn get_copy(&self, coord: (i32, i32, i32)) -> Option<T> {
+    pub fn get_copy(&self, coord: impl GridPos3) -> Option<T> {
         let index = self.offset_index(coord)?;
         Some(self.cells[index])
     }
@@ -1968,12 +2271,80 @@ impl<T: Copy> RollGrid3D<T> {
 
 impl<T: Clone> RollGrid3D<T> {
     /// Get a clone of the grid value.
-    pub fn get_clone(&self, coord: (i32, i32, i32)) -> Option<T> {
+    pub fn get_clone(&self, coord: impl GridPos3) -> Option<T> {
         let index = self.offset_index(coord)?;
         Some(self.cells[index].clone())
     }
 }
 
+impl<T> RollGrid for RollGrid3D<T> {
+    type Coord = (i32, i32, i32);
+    type Coords = Bounds3DIter;
+
+    fn coords(&self) -> Self::Coords {
+        self.bounds().iter()
+    }
+}
+
+/// Formats the size, offset, and cells of the grid, with the cells as a list of `Y`
+/// layers, each of which is a list of rows along the `X` axis in coordinate order
+/// (independent of the wrap offset).
+impl<T: std::fmt::Debug> std::fmt::Debug for RollGrid3D<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bounds = self.bounds();
+        let layers: Vec<Vec<Vec<&T>>> = (bounds.min.1..bounds.max.1)
+            .map(|y| {
+                (bounds.min.2..bounds.max.2)
+                    .map(|z| {
+                        (bounds.min.0..bounds.max.0)
+                            .map(|x| self.get((x, y, z)).expect(OUT_OF_BOUNDS))
+                            .collect()
+                    })
+                    .collect()
+            })
+            .collect();
+        f.debug_struct("RollGrid3D")
+            .field("size", &self.size)
+            .field("offset", &self.grid_offset)
+            .field("cells", &layers)
+            .finish()
+    }
+}
+
+/// Grids are equal when they have the same size, offset, and cells at each coordinate.
+/// The wrap offset is an internal detail, so it is not compared.
+impl<T: PartialEq> PartialEq for RollGrid3D<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size
+            && self.grid_offset == other.grid_offset
+            && self.iter().zip(other.iter()).all(|((_, a), (_, b))| a == b)
+    }
+}
+
+impl<T: Eq> Eq for RollGrid3D<T> {}
+
+impl<T, C: GridPos3> std::ops::Index<C> for RollGrid3D<T> {
+    type Output = T;
+
+    /// Get a reference to the cell at `index`, panicking if it is out of bounds.
+    fn index(&self, index: C) -> &Self::Output {
+        let index = index.into_tuple();
+        self.get(index)
+            .unwrap_or_else(|| panic!("{OUT_OF_BOUNDS}: {index:?} is not in {}", self.bounds()))
+    }
+}
+
+impl<T, C: GridPos3> std::ops::IndexMut<C> for RollGrid3D<T> {
+    /// Get a mutable reference to the cell at `index`, panicking if it is out of bounds.
+    fn index_mut(&mut self, index: C) -> &mut Self::Output {
+        let index = index.into_tuple();
+        let cell_index = self
+            .offset_index(index)
+            .unwrap_or_else(|| panic!("{OUT_OF_BOUNDS}: {index:?} is not in {}", self.bounds()));
+        &mut self.cells[cell_index]
+    }
+}
+
 /// Iterator over all cells in a [RollGrid3D].
 pub struct RollGrid3DIterator<'a, T> {
     grid: &'a RollGrid3D<T>,
@@ -2069,6 +2440,606 @@ mod tests {
         }
     }
 
+    #[test]
+    fn resize_and_reposition_disjoint_test() {
+        use std::collections::HashSet;
+        for (width, height, depth) in [(2, 2, 2), (3, 1, 4), (5, 3, 2)] {
+            for z in -2..4 {
+                for y in -2..4 {
+                    for x in -2..4 {
+                        let mut grid =
+                            RollGrid3D::new(3, 3, 3, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+                        grid.reposition((1, -1, 1), |_, new_pos, cell| *cell = new_pos);
+                        let old_bounds = grid.bounds();
+                        let mut loaded = HashSet::new();
+                        let mut unloaded = HashSet::new();
+                        grid.resize_and_reposition(
+                            width,
+                            height,
+                            depth,
+                            (x, y, z),
+                            crate::cell_manager(
+                                |pos| {
+                                    assert!(loaded.insert(pos));
+                                    pos
+                                },
+                                |pos, value| {
+                                    assert_eq!(pos, value);
+                                    assert!(unloaded.insert(pos));
+                                },
+                                |_, _, _| panic!("Unexpected reload"),
+                            ),
+                        );
+                        assert!(loaded.is_disjoint(&unloaded));
+                        let new_bounds = grid.bounds();
+                        for pos in new_bounds.iter() {
+                            assert_eq!(grid.get(pos), Some(&pos));
+                            assert_eq!(loaded.contains(&pos), !old_bounds.contains(pos));
+                        }
+                        for pos in old_bounds.iter() {
+                            assert_eq!(unloaded.contains(&pos), !new_bounds.contains(pos));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn resize_split_manage_test() {
+        let mut grid = RollGrid3D::new(2, 2, 2, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+        let mut reloads = 0;
+        grid.resize_split_manage(
+            2,
+            2,
+            2,
+            (0, 0, 1),
+            crate::cell_manager(
+                |_| panic!("Unexpected load"),
+                |_, _| panic!("Unexpected unload"),
+                |_, _, _| panic!("Reload on load manager"),
+            ),
+            crate::cell_manager(
+                |_| panic!("Load on reload manager"),
+                |_, _| panic!("Unload on reload manager"),
+                |_, new_pos, cell: &mut (i32, i32, i32)| {
+                    reloads += 1;
+                    *cell = new_pos;
+                },
+            ),
+        );
+        assert_eq!(reloads, 4);
+        let mut loads = 0;
+        let mut unloads = 0;
+        grid.resize_split_manage(
+            3,
+            2,
+            2,
+            (1, 0, 0),
+            crate::cell_manager(
+                |pos| {
+                    loads += 1;
+                    pos
+                },
+                |pos, value| {
+                    assert_eq!(pos, value);
+                    unloads += 1;
+                },
+                |_, _, _| panic!("Reload on load manager"),
+            ),
+            crate::cell_manager(
+                |_| panic!("Load on reload manager"),
+                |_, _| panic!("Unload on reload manager"),
+                |_, _, _| panic!("Unexpected reload"),
+            ),
+        );
+        // x: 0..2 -> 1..4, z: 1..3 -> 0..2
+        assert_eq!(unloads, 6);
+        assert_eq!(loads, 12 - 2);
+        grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+    }
+
+    #[test]
+    fn normalize_axis_test() {
+        let mut grid = RollGrid3D::new(4, 3, 5, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+        grid.translate((1, 2, 3), |_, new_pos, cell| *cell = new_pos);
+        assert_eq!(grid.wrap_offset, (1, 2, 3));
+        grid.normalize_axis(Axis::Z);
+        assert_eq!(grid.wrap_offset, (1, 2, 0));
+        grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+        grid.normalize_axis(Axis::X);
+        assert_eq!(grid.wrap_offset, (0, 2, 0));
+        grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+        grid.normalize_axis(Axis::Y);
+        assert_eq!(grid.wrap_offset, (0, 0, 0));
+        let expected = grid.bounds().iter().collect::<Vec<_>>();
+        assert_eq!(grid.as_slice(), expected.as_slice());
+        grid.translate((-3, -1, 2), |_, new_pos, cell| *cell = new_pos);
+        grid.normalize();
+        assert_eq!(grid.wrap_offset, (0, 0, 0));
+        let expected = grid.bounds().iter().collect::<Vec<_>>();
+        assert_eq!(grid.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn neighbors_test() {
+        let mut grid = RollGrid3D::new(3, 3, 3, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+        // move the grid so that neighbors are looked up across the wrap offset.
+        grid.reposition((1, -1, 2), |_, new_pos, cell| *cell = new_pos);
+        let center = (2, 0, 3);
+        let faces = grid.neighbors6(center).collect::<Vec<_>>();
+        assert_eq!(
+            faces.iter().map(|(pos, _)| *pos).collect::<Vec<_>>(),
+            vec![
+                (1, 0, 3),
+                (3, 0, 3),
+                (2, -1, 3),
+                (2, 1, 3),
+                (2, 0, 2),
+                (2, 0, 4),
+            ]
+        );
+        assert!(faces.iter().all(|(pos, cell)| pos == *cell));
+        let all = grid.neighbors26(center).collect::<Vec<_>>();
+        assert_eq!(all.len(), 26);
+        assert!(all.iter().all(|(pos, cell)| pos == *cell && *pos != center));
+        // edges and corners yield fewer neighbors.
+        assert_eq!(grid.neighbors6((1, -1, 2)).count(), 3);
+        assert_eq!(grid.neighbors26((1, -1, 2)).count(), 7);
+        assert_eq!(grid.neighbors6((2, -1, 3)).count(), 5);
+        assert_eq!(grid.neighbors26((2, -1, 3)).count(), 17);
+        assert_eq!(grid.neighbors26((3, 1, 4)).count(), 7);
+    }
+
+    #[test]
+    fn subgrid_test() {
+        let mut grid = RollGrid3D::new(4, 4, 4, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+        grid.reposition((1, -2, 3), |_, new_pos, cell| *cell = new_pos);
+        let bounds = Bounds3D::new((2, -1, 4), (5, 1, 6));
+        let subgrid = grid.subgrid(bounds);
+        assert_eq!(subgrid.bounds(), bounds);
+        assert_eq!(subgrid.len(), 12);
+        subgrid
+            .iter()
+            .for_each(|(pos, cell)| assert_eq!(pos, **cell));
+        drop(subgrid);
+        let mut subgrid = grid.subgrid_mut(bounds);
+        subgrid.iter_mut().for_each(|(_, cell)| **cell = (0, 0, 0));
+        *subgrid[(2, -1, 4)] = (1, 1, 1);
+        drop(subgrid);
+        for (pos, cell) in grid.iter() {
+            if pos == (2, -1, 4) {
+                assert_eq!(*cell, (1, 1, 1));
+            } else if bounds.contains(pos) {
+                assert_eq!(*cell, (0, 0, 0));
+            } else {
+                assert_eq!(pos, *cell);
+            }
+        }
+        assert_eq!(grid.subgrid(grid.bounds()).len(), grid.len());
+    }
+
+    #[test]
+    #[should_panic(expected = "Out of bounds")]
+    fn subgrid_out_of_bounds_test() {
+        let grid = RollGrid3D::new(4, 4, 4, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+        grid.subgrid(Bounds3D::new((1, 1, 1), (3, 5, 3)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Out of bounds")]
+    fn subgrid_mut_out_of_bounds_test() {
+        let mut grid = RollGrid3D::new(4, 4, 4, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+        grid.subgrid_mut(Bounds3D::new((-1, 0, 0), (2, 2, 2)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Bounds have no cells")]
+    fn subgrid_empty_test() {
+        let grid = RollGrid3D::new(4, 4, 4, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+        grid.subgrid(Bounds3D::new((1, 1, 1), (3, 3, 1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Bounds have no cells")]
+    fn subgrid_mut_empty_test() {
+        let mut grid = RollGrid3D::new(4, 4, 4, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+        grid.subgrid_mut(Bounds3D::new((2, 0, 0), (2, 4, 4)));
+    }
+
+    #[test]
+    fn fill_shell_test() {
+        let mut grid = RollGrid3D::new(4, 3, 5, (0, 0, 0), |_| 0);
+        grid.reposition((-2, 5, 1), |_, _, cell| *cell = 0);
+        grid.fill_shell(1);
+        for (pos, cell) in grid.iter() {
+            let on_shell =
+                pos.0 == -2 || pos.0 == 1 || pos.1 == 5 || pos.1 == 7 || pos.2 == 1 || pos.2 == 5;
+            assert_eq!(*cell, if on_shell { 1 } else { 0 }, "{pos:?}");
+        }
+        assert_eq!(grid.get((-1, 6, 3)), Some(&0));
+    }
+
+    #[test]
+    fn wrap_offset_test() {
+        let mut grid = RollGrid3D::new(3, 2, 4, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+        assert_eq!(grid.wrap_offset(), (0, 0, 0));
+        grid.translate((1, -1, 2), |_, new_pos, cell| *cell = new_pos);
+        assert_eq!(grid.wrap_offset(), (1, 1, 2));
+        grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+    }
+
+    #[test]
+    fn swap_test() {
+        let mut grid = RollGrid3D::new(3, 3, 3, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+        grid.translate((1, 2, -1), |_, new_pos, cell| *cell = new_pos);
+        grid.swap((1, 2, -1), (3, 4, 1));
+        assert_eq!(grid[(1, 2, -1)], (3, 4, 1));
+        assert_eq!(grid[(3, 4, 1)], (1, 2, -1));
+        grid.swap((2, 3, 0), (2, 3, 0));
+        assert_eq!(grid[(2, 3, 0)], (2, 3, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Out of bounds")]
+    fn swap_out_of_bounds_test() {
+        let mut grid = RollGrid3D::new(3, 3, 3, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+        grid.swap((-1, 0, 0), (0, 0, 0));
+    }
+
+    #[test]
+    fn reposition_counted_test() {
+        let mut grid = RollGrid3D::new(8, 6, 4, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+        assert_eq!(grid.reposition_counted((0, 0, 0), |_, _, _| ()), 0);
+        let count = grid.reposition_counted((2, 1, -1), |_, new_pos, cell| *cell = new_pos);
+        assert_eq!(count, grid.len() - (8 - 2) * (6 - 1) * (4 - 1));
+        grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+        let count = grid.translate_counted((0, -3, 0), |_, new_pos, cell| *cell = new_pos);
+        assert_eq!(count, 3 * 8 * 4);
+        assert_eq!(grid.translate_counted((0, 0, 4), |_, _, _| ()), grid.len());
+    }
+
+    #[test]
+    fn reposition_across_i32_range_test() {
+        let mut grid = RollGrid3D::new(4, 3, 2, (i32::MIN, 0, 0), |pos: (i32, i32, i32)| pos);
+        let count =
+            grid.reposition_counted((i32::MAX - 4, 0, 0), |_, new_pos, cell| *cell = new_pos);
+        assert_eq!(count, grid.len());
+        grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+        grid.try_reposition((i32::MIN, 0, i32::MAX - 2), |_, new_pos, cell| {
+            *cell = new_pos;
+            Ok::<(), ()>(())
+        })
+        .unwrap();
+        assert_eq!(grid.offset(), (i32::MIN, 0, i32::MAX - 2));
+        grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+    }
+
+    #[test]
+    fn translate_near_max_test() {
+        let mut grid = RollGrid3D::new(4, 4, 4, (0, 0, i32::MAX - 5), |pos: (i32, i32, i32)| pos);
+        grid.translate((0, 0, 1), |_, new_pos, cell| *cell = new_pos);
+        assert_eq!(grid.bounds().max, (4, 4, i32::MAX));
+    }
+
+    #[test]
+    #[should_panic(expected = "Z maximum bound exceeds i32::MAX")]
+    fn translate_past_max_test() {
+        let mut grid = RollGrid3D::new(4, 4, 4, (0, 0, i32::MAX - 5), |pos: (i32, i32, i32)| pos);
+        grid.translate((0, 0, 2), |_, new_pos, cell| *cell = new_pos);
+    }
+
+    #[test]
+    #[should_panic(expected = "X minimum bound exceeds i32::MIN")]
+    fn translate_past_min_test() {
+        let mut grid = RollGrid3D::new(4, 4, 4, (i32::MIN + 1, 0, 0), |pos: (i32, i32, i32)| pos);
+        grid.translate((-1, 0, 0), |_, new_pos, cell| *cell = new_pos);
+        assert_eq!(grid.offset(), (i32::MIN, 0, 0));
+        let _ = grid.try_translate((-1, 0, 0), |_, _, _| Ok::<(), ()>(()));
+    }
+
+    #[test]
+    fn grid_pos_test() {
+        let mut grid = RollGrid3D::new(2, 2, 2, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+        grid.translate((1, 0, 1), |_, new_pos, cell| *cell = new_pos);
+        let tuple = (2, 1, 2);
+        let array = [2, 1, 2];
+        let (tuple_ref, array_ref) = (&tuple, &array);
+        assert_eq!(grid.get(tuple), Some(&(2, 1, 2)));
+        assert_eq!(grid.get(array), Some(&(2, 1, 2)));
+        assert_eq!(grid.get(tuple_ref), Some(&(2, 1, 2)));
+        assert_eq!(grid.get(array_ref), Some(&(2, 1, 2)));
+        assert_eq!(grid.get([0, 0, 0]), None);
+        *grid.get_mut([1, 0, 1]).unwrap() = (0, 0, 0);
+        assert_eq!(grid[(1, 0, 1)], (0, 0, 0));
+        assert_eq!(grid.set(array_ref, (1, 0, 1)), Some((2, 1, 2)));
+        assert_eq!(grid[[2, 1, 2]], (1, 0, 1));
+        grid[array_ref] = (9, 9, 9);
+        assert_eq!(grid.get_copy(tuple_ref), Some((9, 9, 9)));
+        assert_eq!(grid.get_clone([2, 1, 2]), Some((9, 9, 9)));
+        grid.swap(array, (1, 1, 1));
+        assert_eq!(grid[[1, 1, 1]], (9, 9, 9));
+        assert_eq!(grid.relative_offset([3, 3, 3]), (2, 3, 2));
+    }
+
+    #[test]
+    fn iter_region_test() {
+        let mut grid = RollGrid3D::new(4, 4, 4, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+        grid.translate((2, -1, 1), |_, new_pos, cell| *cell = new_pos);
+        // clamped to the grid bounds (2, -1, 1)..(6, 3, 5)
+        let region = Bounds3D::new((4, -5, 4), (10, 0, 9));
+        let cells: Vec<_> = grid.iter_region(region).collect();
+        assert_eq!(
+            cells,
+            vec![((4, -1, 4), &(4, -1, 4)), ((5, -1, 4), &(5, -1, 4))]
+        );
+        let region = Bounds3D::new((3, 0, 2), (5, 2, 4));
+        let expected: Vec<(i32, i32, i32)> = region.iter().collect();
+        let visited: Vec<(i32, i32, i32)> = grid.iter_region(region).map(|(pos, _)| pos).collect();
+        assert_eq!(visited, expected);
+        grid.iter_region_mut(region)
+            .for_each(|(_, cell)| *cell = (0, 0, 0));
+        grid.iter().for_each(|(pos, cell)| {
+            if region.contains(pos) {
+                assert_eq!(*cell, (0, 0, 0));
+            } else {
+                assert_eq!(*cell, pos);
+            }
+        });
+        assert_eq!(grid.iter_region(grid.bounds()).count(), 64);
+        // bounds outside of the grid yield nothing
+        assert_eq!(
+            grid.iter_region(Bounds3D::new((6, 0, 1), (8, 2, 3)))
+                .count(),
+            0
+        );
+        assert_eq!(
+            grid.iter_region_mut(Bounds3D::new((0, 0, 0), (1, 9, 9)))
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn big_zst_grid_test() {
+        let mut grid = RollGrid3D::new_zst((1024, 256, 1024), (0, 0, 0));
+        assert_eq!(grid.len(), 1024 * 256 * 1024);
+        let mut reloads = 0usize;
+        grid.translate((3, 0, -2), |_, _, _| reloads += 1);
+        assert_eq!(reloads, 1024 * 256 * 1024 - 1021 * 256 * 1022);
+        assert_eq!(grid.wrap_offset(), (3, 0, 1022));
+        assert_eq!(grid.offset(), (3, 0, -2));
+        assert_eq!(grid.get((1026, 255, 1021)), Some(&()));
+        assert_eq!(grid.get((1027, 0, 0)), None);
+    }
+
+    #[test]
+    fn reposition_single_axis_test() {
+        let offsets = [
+            (3, 0, 0),
+            (-2, 0, 0),
+            (0, 4, 0),
+            (0, -1, 0),
+            (0, 0, 5),
+            (0, 0, -3),
+            (6, 0, 0),
+            (0, 0, -5),
+        ];
+        for offset in offsets {
+            let mut fast = RollGrid3D::new(7, 5, 6, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+            let mut general = RollGrid3D::new(7, 5, 6, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+            // give both grids a nonzero wrap offset first (a diagonal move)
+            fast.reposition((2, 3, 1), |_, new_pos, cell| *cell = new_pos);
+            general.reposition((2, 3, 1), |_, new_pos, cell| *cell = new_pos);
+            assert_eq!(fast.wrap_offset(), (2, 3, 1));
+            let new_position = (2 + offset.0, 3 + offset.1, 1 + offset.2);
+            let mut fast_reloads = Vec::new();
+            fast.reposition(new_position, |old_pos, new_pos, cell| {
+                assert_eq!(*cell, old_pos);
+                fast_reloads.push((old_pos, new_pos));
+                *cell = new_pos;
+            });
+            // try_reposition always takes the general path
+            let mut general_reloads = Vec::new();
+            general
+                .try_reposition(new_position, |old_pos, new_pos, cell| {
+                    general_reloads.push((old_pos, new_pos));
+                    *cell = new_pos;
+                    Ok::<(), ()>(())
+                })
+                .unwrap();
+            fast_reloads.sort();
+            general_reloads.sort();
+            assert_eq!(fast_reloads, general_reloads);
+            assert_eq!(fast.wrap_offset(), general.wrap_offset());
+            assert_eq!(fast.as_slice(), general.as_slice());
+            fast.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+        }
+    }
+
+    #[test]
+    fn resize_and_reposition_max_bound_test() {
+        let mut grid = RollGrid3D::new(2, 2, 2, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+        let offset = (i32::MAX - 2, i32::MAX - 3, i32::MAX - 4);
+        grid.resize_and_reposition(
+            2,
+            3,
+            4,
+            offset,
+            cell_manager(|pos| pos, |_, _| (), |_, _, _| ()),
+        );
+        assert_eq!(grid.bounds().max, (i32::MAX, i32::MAX, i32::MAX));
+        grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+    }
+
+    #[test]
+    #[should_panic(expected = "Z maximum bound exceeds i32::MAX")]
+    fn resize_and_reposition_past_max_test() {
+        let mut grid = RollGrid3D::new(2, 2, 2, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+        grid.resize_and_reposition(
+            2,
+            3,
+            4,
+            (0, 0, i32::MAX - 3),
+            cell_manager(
+                |pos| pos,
+                |_, _| panic!("Unloaded before validation"),
+                |_, _, _| (),
+            ),
+        );
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "Wrap offset is outside of the grid size")]
+    fn corrupt_wrap_offset_test() {
+        let mut grid = RollGrid3D::new(2, 2, 2, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+        grid.wrap_offset = (0, 0, 2);
+        grid.get((0, 0, 0));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "Wrapped index exceeds the capacity of the grid")]
+    fn corrupt_size_test() {
+        let mut grid = RollGrid3D::new(2, 2, 2, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+        grid.size = (2, 3, 2);
+        grid.get((1, 2, 1));
+    }
+
+    #[test]
+    fn resize_default_test() {
+        let mut grid = RollGrid3D::new(2, 2, 2, (0, 0, 0), |(x, y, z)| x + y * 10 + z * 100 + 1);
+        grid.resize_default((3, 1, 2));
+        assert_eq!(grid.size(), (3, 1, 2));
+        assert_eq!(grid.offset(), (0, 0, 0));
+        assert_eq!(grid.get((0, 0, 1)), Some(&101));
+        assert_eq!(grid.get((1, 0, 0)), Some(&2));
+        assert_eq!(grid.get((2, 0, 1)), Some(&0));
+        assert_eq!(grid.get((0, 1, 0)), None);
+    }
+
+    #[test]
+    fn eq_test() {
+        let mut a = RollGrid3D::new(4, 3, 2, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+        let b = RollGrid3D::new(4, 3, 2, (2, 1, -1), |pos: (i32, i32, i32)| pos);
+        assert_ne!(a, b);
+        a.reposition((2, 1, -1), |_, new_pos, cell| *cell = new_pos);
+        assert_ne!(a.wrap_offset(), b.wrap_offset());
+        assert_eq!(a, b);
+        a.set((3, 2, 0), (0, 0, 0));
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn debug_test() {
+        let mut grid = RollGrid3D::new(2, 2, 2, (0, 0, 0), |(x, y, z)| x + y * 100 + z * 10);
+        grid.translate((1, 0, 1), |_, (x, y, z), cell| *cell = x + y * 100 + z * 10);
+        assert_eq!(
+            format!("{grid:?}"),
+            "RollGrid3D { size: (2, 2, 2), offset: (1, 0, 1), cells: [[[11, 12], [21, 22]], [[111, 112], [121, 122]]] }"
+        );
+    }
+
+    #[test]
+    fn resize_shrinking_test() {
+        let mut grid = RollGrid3D::new(6, 6, 6, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+        assert_eq!(grid.capacity(), grid.len());
+        for (size, position) in [((4, 4, 4), (2, 2, 2)), ((3, 2, 1), (-5, 1, 0))] {
+            let old_bounds = grid.bounds();
+            let old_ptr = unsafe { grid.cells.as_ptr() };
+            let (mut loads, mut unloads) = (0, 0);
+            grid.resize_and_reposition_shrinking(
+                size.0,
+                size.1,
+                size.2,
+                position,
+                cell_manager(
+                    |pos| {
+                        loads += 1;
+                        pos
+                    },
+                    |_, _| unloads += 1,
+                    |_, new_pos, cell| *cell = new_pos,
+                ),
+            );
+            let new_bounds = grid.bounds();
+            assert_eq!(grid.size(), size);
+            assert_eq!(grid.capacity(), grid.len());
+            // the cells were moved into a fresh allocation of the new size
+            assert_ne!(unsafe { grid.cells.as_ptr() }, old_ptr);
+            let kept = old_bounds
+                .intersection(new_bounds)
+                .map_or(0, |kept| kept.volume());
+            assert_eq!(unloads, old_bounds.volume() - kept);
+            assert_eq!(loads, new_bounds.volume() - kept);
+            grid.iter().for_each(|(pos, cell)| assert_eq!(pos, *cell));
+        }
+    }
+
+    #[test]
+    fn work_ranges_test() {
+        for (w, h, d) in [(1, 1, 1), (3, 4, 1), (2, 3, 5), (7, 5, 3)] {
+            let mut grid = RollGrid3D::new(w, h, d, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+            let len = grid.len();
+            assert_eq!(len, w * h * d);
+            for n in [1, 2, 3, 7, len, len + 3] {
+                let ranges = grid.work_ranges(n);
+                assert_eq!(ranges.len(), n);
+                let mut next = 0;
+                for range in ranges.iter() {
+                    assert_eq!(range.start, next);
+                    assert!(range.len() <= len / n + 1);
+                    assert!(range.len() >= len / n);
+                    next = range.end;
+                }
+                assert_eq!(next, len);
+                let slice = grid.as_mut_slice();
+                for range in ranges {
+                    slice[range].iter_mut().for_each(|cell| cell.1 += 1);
+                }
+            }
+            assert!(grid.work_ranges(0).is_empty());
+        }
+    }
+
+    #[test]
+    fn resize_and_reposition_grow_move_count_test() {
+        use std::collections::HashSet;
+        let mut grid = RollGrid3D::new(4, 4, 4, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+        grid.reposition((1, 1, 1), |_, new_pos, cell| *cell = new_pos);
+        let old_bounds = grid.bounds();
+        let new_bounds = Bounds3D::new((-1, 2, 0), (5, 8, 6));
+        let overlap = old_bounds.intersection(new_bounds).unwrap();
+        let mut loaded = HashSet::new();
+        let mut unloaded = HashSet::new();
+        let mut reloads = 0;
+        grid.resize_and_reposition(
+            6,
+            6,
+            6,
+            (-1, 2, 0),
+            cell_manager(
+                |pos| {
+                    assert!(loaded.insert(pos), "{pos:?} was loaded twice");
+                    pos
+                },
+                |pos, _| assert!(unloaded.insert(pos), "{pos:?} was unloaded twice"),
+                |_, _, _| reloads += 1,
+            ),
+        );
+        assert_eq!(reloads, 0);
+        assert_eq!(loaded.len() as i128, new_bounds.volume() - overlap.volume());
+        assert_eq!(
+            unloaded.len() as i128,
+            old_bounds.volume() - overlap.volume()
+        );
+        assert!(loaded.iter().all(|&pos| !old_bounds.contains(pos)));
+        new_bounds
+            .iter()
+            .for_each(|pos| assert_eq!(grid.get(pos), Some(&pos)));
+    }
+
     #[test]
     fn resize_and_reposition_test() {
         struct DropCoord {
@@ -2149,30 +3120,6 @@ mod tests {
         }
     }
 
-    #[test]
-    fn offsetfix_test() {
-        struct OffsetFix {
-            /// the old grid offset that we can use to
-            /// create a relational offset
-            offset: (i32, i32, i32),
-            size: (i32, i32, i32),
-        }
-        impl OffsetFix {
-            fn wrap(&self, pos: (i32, i32, i32)) -> (i32, i32, i32) {
-                let x = (pos.0 - self.offset.0).rem_euclid(self.size.0) + self.offset.0;
-                let y = (pos.1 - self.offset.1).rem_euclid(self.size.1) + self.offset.1;
-                let z = (pos.2 - self.offset.2).rem_euclid(self.size.2) + self.offset.2;
-                (x, y, z)
-            }
-        }
-        let fix = OffsetFix {
-            offset: (5, 5, 5),
-            size: (4, 4, 4),
-        };
-        let (x, y, z) = fix.wrap((9, 9, 9));
-        println!("({x}, {y}, {z})");
-    }
-
     #[test]
     fn offset_index_test() {
         struct Grid {
@@ -2230,6 +3177,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn index_test() {
+        let mut grid = RollGrid3D::new(3, 3, 3, (-1, -1, -1), |pos: (i32, i32, i32)| pos);
+        grid.translate((1, 2, 1), |_, new_pos, cell| {
+            *cell = new_pos;
+        });
+        grid.bounds().iter().for_each(|pos| {
+            assert_eq!(grid[pos], pos);
+        });
+        grid[(1, 2, 1)] = (0, 0, 0);
+        assert_eq!(grid.get_copy((1, 2, 1)), Some((0, 0, 0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Out of bounds: (0, 2, 0) is not in (0, 0, 0)..(2, 2, 2)")]
+    fn index_out_of_bounds_test() {
+        let grid = RollGrid3D::new(2, 2, 2, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+        let _ = grid[(0, 2, 0)];
+    }
+
+    #[test]
+    fn offset_grid_get_test() {
+        let grid = RollGrid3D::new(4, 3, 2, (3, 5, 7), |pos: (i32, i32, i32)| pos);
+        for y in grid.y_min()..grid.y_max() {
+            for z in grid.z_min()..grid.z_max() {
+                for x in grid.x_min()..grid.x_max() {
+                    assert_eq!(grid.get((x, y, z)), Some(&(x, y, z)));
+                }
+            }
+        }
+        assert_eq!(grid.get((3, 4, 7)), None);
+        assert_eq!(grid.get((3, 8, 7)), None);
+    }
+
     #[test]
     fn bounds_test() {
         let max_bounds = Bounds3D::new(
@@ -2238,4 +3219,37 @@ mod tests {
         );
         println!("{}", max_bounds.volume());
     }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam_test() {
+        use glam::IVec3;
+        let mut grid = RollGrid3D::new(3, 3, 3, (0, 0, 0), |pos: (i32, i32, i32)| pos);
+        grid.translate(IVec3::new(1, -1, 2), |_, new_pos, cell| *cell = new_pos);
+        assert_eq!(grid.offset(), (1, -1, 2));
+        assert_eq!(grid[IVec3::new(3, 1, 4)], (3, 1, 4));
+        assert_eq!(grid.get(IVec3::new(0, 0, 0)), None);
+        grid.reposition(IVec3::new(2, 2, 2), |_, new_pos, cell| *cell = new_pos);
+        grid[IVec3::new(2, 2, 2)] = (0, 0, 0);
+        assert_eq!(grid.get(IVec3::new(2, 2, 2)), Some(&(0, 0, 0)));
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn mint_test() {
+        use crate::math::Convert;
+        use mint::{Point3, Vector3};
+        let offset = Point3 { x: -1, y: 2, z: 0 };
+        let mut grid = RollGrid3D::new(2, 2, 2, offset.convert(), |pos: (i32, i32, i32)| pos);
+        assert_eq!(grid.offset(), (-1, 2, 0));
+        assert_eq!(grid.get(Point3 { x: 0, y: 3, z: 1 }), Some(&(0, 3, 1)));
+        grid.reposition(Point3 { x: 0, y: 0, z: 0 }, |_, new_pos, cell| {
+            *cell = new_pos
+        });
+        grid.translate(Vector3 { x: 0, y: 0, z: 1 }, |_, new_pos, cell| {
+            *cell = new_pos
+        });
+        assert_eq!(grid[Point3 { x: 1, y: 1, z: 2 }], (1, 1, 2));
+        assert_eq!(grid.get(Point3 { x: 0, y: 0, z: 0 }), None);
+    }
 }